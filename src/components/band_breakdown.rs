@@ -0,0 +1,47 @@
+use crate::models::price_unit::PriceUnit;
+use crate::models::rates::BandStats;
+use crate::utils::format::format_price;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct BandBreakdownProps {
+    pub stats: Vec<BandStats>,
+    #[prop_or_default]
+    pub unit: PriceUnit,
+}
+
+/// Table of today's mean, cheapest and priciest price in each time band
+#[function_component(BandBreakdown)]
+pub fn band_breakdown(props: &BandBreakdownProps) -> Html {
+    if props.stats.is_empty() {
+        return html! {};
+    }
+
+    html! {
+        <div class="day-summary-card">
+            <table class="band-breakdown">
+                <caption>{"Today by time of day"}</caption>
+                <thead>
+                    <tr>
+                        <th scope="col">{"Band"}</th>
+                        <th scope="col">{"Average"}</th>
+                        <th scope="col">{"Min"}</th>
+                        <th scope="col">{"Max"}</th>
+                        <th scope="col">{"Slots"}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {for props.stats.iter().map(|band| html! {
+                        <tr key={band.label.clone()}>
+                            <th scope="row">{&band.label}</th>
+                            <td>{format_price(band.mean, props.unit)}</td>
+                            <td>{format_price(band.min, props.unit)}</td>
+                            <td>{format_price(band.max, props.unit)}</td>
+                            <td>{band.count}</td>
+                        </tr>
+                    })}
+                </tbody>
+            </table>
+        </div>
+    }
+}
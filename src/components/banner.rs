@@ -2,6 +2,23 @@ use crate::utils::debounce::create_debounced_resize_observer;
 use web_sys::HtmlElement;
 use yew::prelude::*;
 
+/// Mean of each slot across `rows` (e.g. one row per day, indexed by time of day), for a
+/// typical-day trace. Missing prices only leave out their own day; a slot missing on
+/// every day is skipped.
+#[allow(clippy::cast_precision_loss)]
+pub fn compute_means(rows: &[Vec<Option<f64>>]) -> Vec<f64> {
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    (0..width)
+        .filter_map(|slot| {
+            let values: Vec<f64> = rows
+                .iter()
+                .filter_map(|row| row.get(slot).copied().flatten())
+                .collect();
+            (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+        })
+        .collect()
+}
+
 /// Generates SVG path data from values
 #[allow(clippy::cast_precision_loss)]
 pub fn build_path(values: &[f64], width: f64, height: f64, padding: f64) -> String {
@@ -203,3 +220,21 @@ pub fn trace_banner(props: &TraceBannerProps) -> Html {
         </svg>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_means_skips_missing_prices() {
+        // The second day is missing its first slot, as on the spring clock change
+        let rows = vec![
+            vec![Some(10.0), Some(20.0)],
+            vec![None, Some(30.0)],
+            vec![None, None],
+        ];
+
+        assert_eq!(compute_means(&rows), vec![10.0, 25.0]);
+        assert_eq!(compute_means(&[vec![None, Some(4.0)]]), vec![4.0]);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::models::carbon::CarbonForecast;
+use crate::models::rates::Rates;
+use crate::models::score::best_combined_window;
+use crate::utils::time::format_slot_window;
+use std::rc::Rc;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct BestTimeProps {
+    pub rates: Rc<Rates>,
+    pub forecast: Rc<CarbonForecast>,
+}
+
+/// Suggests the upcoming half-hour that best balances price against carbon intensity,
+/// with a slider for how much weight carbon gets
+#[function_component(BestTime)]
+pub fn best_time(props: &BestTimeProps) -> Html {
+    // Share of the score given to carbon, in percent
+    let carbon_pct = use_state(|| 50_u8);
+    let best = use_memo(
+        (props.rates.clone(), props.forecast.clone(), *carbon_pct),
+        |(rates, forecast, carbon_pct)| {
+            best_combined_window(
+                rates,
+                forecast,
+                chrono::Duration::minutes(30),
+                f64::from(*carbon_pct) / 100.0,
+            )
+        },
+    );
+
+    let on_input = {
+        let carbon_pct = carbon_pct.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = target.value().parse::<u8>() {
+                carbon_pct.set(value.min(100));
+            }
+        })
+    };
+
+    html! {
+        <div class="best-time">
+            <h3>{"Best Time to Run"}</h3>
+            <p class="best-time-result">
+                {match *best {
+                    Some(best) => format!(
+                        "{} · {:.2}p · {:.0} gCO₂/kWh",
+                        format_slot_window(best.start, best.end),
+                        best.price,
+                        best.intensity
+                    ),
+                    None => "Not enough overlapping price and carbon data".to_string(),
+                }}
+            </p>
+            <label class="best-time-weight">
+                {"Price"}
+                <input
+                    type="range"
+                    min="0"
+                    max="100"
+                    step="10"
+                    value={carbon_pct.to_string()}
+                    oninput={on_input}
+                    aria-label="Weight given to carbon intensity versus price"
+                />
+                {"Carbon"}
+            </label>
+        </div>
+    }
+}
@@ -0,0 +1,60 @@
+use crate::hooks::use_settings::use_settings;
+use crate::models::rates::Rates;
+use crate::utils::time::format_slot_window;
+use chrono::Utc;
+use std::rc::Rc;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+/// Block lengths offered to the user, in hours
+const DURATIONS: [i64; 5] = [1, 2, 3, 4, 6];
+
+#[derive(Properties, PartialEq)]
+pub struct BlockPickerProps {
+    pub rates: Rc<Rates>,
+}
+
+/// Finds the cheapest contiguous block of a chosen length between now and the end of
+/// the published rates. The chosen length is remembered in the stored settings.
+#[function_component(BlockPicker)]
+pub fn block_picker(props: &BlockPickerProps) -> Html {
+    let settings = use_settings();
+    let hours = settings.get().block_hours;
+    let block = use_memo((props.rates.clone(), hours), |(rates, hours)| {
+        rates.cheapest_block(chrono::Duration::hours(*hours), Utc::now())
+    });
+
+    let on_change = Callback::from(move |e: Event| {
+        let target: HtmlSelectElement = e.target_unchecked_into();
+        if let Ok(value) = target.value().parse::<i64>() {
+            settings.update(move |s| s.block_hours = value);
+        }
+    });
+
+    html! {
+        <div class="block-picker">
+            <label class="block-picker-label">
+                {"Cheapest "}
+                <select onchange={on_change} aria-label="Select block length">
+                    {
+                        DURATIONS.iter().map(|h| {
+                            let selected = *h == hours;
+                            html! {
+                                <option value={h.to_string()} {selected}>{format!("{h}h")}</option>
+                            }
+                        }).collect::<Html>()
+                    }
+                </select>
+                {" block"}
+            </label>
+            <p class="block-picker-result">
+                {match *block {
+                    Some((start, end, mean)) => {
+                        format!("{} at {mean:.2}p average", format_slot_window(start, end))
+                    }
+                    None => "Not enough data".to_string(),
+                }}
+            </p>
+        </div>
+    }
+}
@@ -0,0 +1,141 @@
+use charming::{
+    Chart as CharmingChart,
+    component::{Axis, Grid, Title},
+    datatype::DataPointItem,
+    element::{
+        AxisLabel, AxisType, ItemStyle, LineStyle, LineStyleType, SplitLine, Symbol, TextStyle,
+        Tooltip, Trigger,
+    },
+    series::Line,
+};
+use std::rc::Rc;
+use yew::prelude::*;
+
+use crate::hooks::use_echarts::use_echarts;
+use crate::models::carbon::CarbonIntensity;
+use crate::models::palette::{ChartTheme, Palette};
+
+const CHART_ID: &str = "carbon-chart";
+
+/// Chart series plus the per-point index color
+type CarbonSeries = (Vec<String>, Vec<u32>, Vec<&'static str>);
+
+#[derive(Properties, PartialEq)]
+pub struct CarbonChartProps {
+    pub data: Rc<CarbonIntensity>,
+    pub dark_mode: bool,
+    /// Colors for the per-point intensity index
+    #[prop_or_default]
+    pub palette: Palette,
+}
+
+/// Line chart of forecast carbon intensity across the day, points colored by index
+#[function_component(CarbonChart)]
+pub fn carbon_chart(props: &CarbonChartProps) -> Html {
+    let container_ref = use_node_ref();
+    let series = use_memo((props.data.clone(), props.palette), |(data, palette)| {
+        let (x_data, y_data) = data.series_data();
+        let colors = data
+            .periods
+            .iter()
+            .map(|period| period.intensity.index.color_for(*palette))
+            .collect();
+        (x_data, y_data, colors)
+    });
+
+    use_echarts(
+        CHART_ID,
+        container_ref.clone(),
+        (series.clone(), props.dark_mode),
+        |(series, dark_mode)| (!series.1.is_empty()).then(|| build_chart(series, *dark_mode)),
+    );
+
+    let (min, max) = series
+        .1
+        .iter()
+        .fold(None, |acc: Option<(u32, u32)>, &v| {
+            Some(acc.map_or((v, v), |(lo, hi)| (lo.min(v), hi.max(v))))
+        })
+        .unwrap_or_default();
+
+    html! {
+        <div class="chart-container" ref={container_ref}>
+            <div
+                id={CHART_ID}
+                role="img"
+                aria-label="Carbon intensity chart showing half-hourly forecasts"
+            />
+            <div class="sr-only">
+                {format!(
+                    "Carbon intensity forecasts ranging from {} to {} grams CO2 per kilowatt hour",
+                    min, max
+                )}
+            </div>
+        </div>
+    }
+}
+
+fn build_chart(series: &CarbonSeries, dark_mode: bool) -> CharmingChart {
+    let (x_data, y_data, colors) = series;
+
+    let ChartTheme {
+        background,
+        text: title_color,
+        axis: axis_color,
+        grid: grid_color,
+        muted: line_color,
+        ..
+    } = ChartTheme::for_dark_mode(dark_mode);
+
+    let points: Vec<DataPointItem> = y_data
+        .iter()
+        .zip(colors)
+        .map(|(value, color)| {
+            DataPointItem::new(f64::from(*value)).item_style(ItemStyle::new().color(*color))
+        })
+        .collect();
+
+    CharmingChart::new()
+        .background_color(background)
+        .title(
+            Title::new()
+                .text("Carbon Intensity Forecast")
+                .left("center")
+                .text_style(TextStyle::new().font_size(16).color(title_color)),
+        )
+        .tooltip(Tooltip::new().trigger(Trigger::Axis))
+        .grid(
+            Grid::new()
+                .left("8%")
+                .right("4%")
+                .bottom("18%")
+                .contain_label(true),
+        )
+        .x_axis(
+            Axis::new()
+                .type_(AxisType::Category)
+                .data(x_data.clone())
+                .axis_label(AxisLabel::new().rotate(45).color(axis_color).interval(3)),
+        )
+        .y_axis(
+            Axis::new()
+                .type_(AxisType::Value)
+                .name("gCO₂/kWh")
+                .axis_label(AxisLabel::new().color(axis_color))
+                .split_line(
+                    SplitLine::new().line_style(
+                        LineStyle::new()
+                            .color(grid_color)
+                            .type_(LineStyleType::Dashed),
+                    ),
+                ),
+        )
+        .series(
+            Line::new()
+                .name("Forecast")
+                .data(points)
+                .symbol(Symbol::Circle)
+                .symbol_size(6)
+                .line_style(LineStyle::new().color(line_color).width(2)),
+        )
+}
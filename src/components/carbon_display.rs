@@ -1,12 +1,24 @@
-use crate::models::carbon::CarbonIntensity;
+use crate::models::carbon::{CarbonForecast, CarbonIntensity};
+use crate::models::palette::Palette;
+use crate::utils::time::format_slot_window;
+use chrono::Utc;
 use std::rc::Rc;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct CarbonDisplayProps {
     pub data: Rc<CarbonIntensity>,
+    /// Forward forecast used to suggest the greenest block, once loaded
+    #[prop_or_default]
+    pub forecast: Option<Rc<CarbonForecast>>,
+    /// Colors for the intensity badges
+    #[prop_or_default]
+    pub palette: Palette,
 }
 
+/// Length of the low-carbon block suggested from the forecast
+const GREENEST_WINDOW_HOURS: i64 = 2;
+
 #[function_component(CarbonDisplay)]
 pub fn carbon_display(props: &CarbonDisplayProps) -> Html {
     let data = &props.data;
@@ -56,8 +68,31 @@ pub fn carbon_display(props: &CarbonDisplayProps) -> Html {
         std::cmp::Ordering::Equal => "Stable",
     };
 
+    // Greenest slot still to come today
+    let greenest = data.greenest_upcoming(Utc::now()).map(|period| {
+        format!(
+            "{} ({} gCO₂/kWh)",
+            format_slot_window(period.from, period.to),
+            period.intensity.forecast
+        )
+    });
+
+    // Lowest-carbon block in the next 24 hours
+    let greenest_window = props.forecast.as_ref().and_then(|forecast| {
+        let (from, to, mean) =
+            forecast.greenest_window(chrono::Duration::hours(GREENEST_WINDOW_HOURS))?;
+        Some(format!(
+            "{} ({mean:.0} gCO₂/kWh)",
+            format_slot_window(from, to)
+        ))
+    });
+
     html! {
-        <div class="carbon-display" role="region" aria-label="Carbon intensity information">
+        <div
+            class={classes!("carbon-display", props.palette.css_class())}
+            role="region"
+            aria-label="Carbon intensity information"
+        >
             <div class="carbon-grid">
                 // Current period - prominent display
                 <div
@@ -111,6 +146,23 @@ pub fn carbon_display(props: &CarbonDisplayProps) -> Html {
                     </div>
                 </div>
             </div>
+            <p class="carbon-region">
+                {format!("Figures for {}", data.region.as_deref().unwrap_or("Great Britain"))}
+            </p>
+            if let Some(greenest) = greenest {
+                <p class="carbon-greenest">
+                    <span class="carbon-greenest-label">{"Greenest upcoming: "}</span>
+                    {greenest}
+                </p>
+            }
+            if let Some(window) = greenest_window {
+                <p class="carbon-greenest">
+                    <span class="carbon-greenest-label">
+                        {format!("Greenest {GREENEST_WINDOW_HOURS}h window: ")}
+                    </span>
+                    {window}
+                </p>
+            }
         </div>
     }
 }
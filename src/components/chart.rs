@@ -1,51 +1,194 @@
 use crate::utils::debounce::create_debounced_resize_observer;
 use charming::{
     Chart as CharmingChart,
-    component::{Axis, Grid, Title, VisualMap, VisualMapPiece},
+    component::{Axis, Grid, Legend, Title},
+    datatype::DataPointItem,
     element::{
-        AxisLabel, AxisPointer, AxisPointerType, AxisType, LineStyle, LineStyleType, SplitLine,
-        TextStyle, Tooltip, Trigger,
+        AxisLabel, AxisPointer, AxisPointerType, AxisType, ItemStyle, Label, LabelPosition,
+        LineStyle, LineStyleType, MarkLine, MarkLineData, MarkLineVariant, MarkPoint,
+        MarkPointData, SplitLine, TextStyle, Tooltip, Trigger,
     },
     renderer::{ChartResize, Echarts, WasmRenderer},
-    series::Bar,
+    series::{Bar, Line, Series},
 };
 use std::rc::Rc;
 use web_sys::HtmlElement;
 use yew::prelude::*;
 
-use crate::models::rates::Rates;
+use crate::components::direction_toggle::TariffDirection;
+use crate::models::chart_type::ChartType;
+use crate::models::palette::{ChartTheme, Palette};
+use crate::models::rates::{Granularity, Rates};
+use crate::models::thresholds::{PriceThresholds, ThresholdBand};
+use crate::utils::time::london_today;
 
 const CHART_ID: &str = "energy-chart";
 
+/// Lower bounds (p/kWh) of each positive price band after the first
+pub(crate) const PRICE_THRESHOLDS: [f64; 5] = [7.5, 11.25, 15.0, 22.5, 30.0];
+
+// Bar colors per band - slightly brighter for dark mode
+pub(crate) const LIGHT_PALETTE: [&str; 6] = [
+    "#00b4a0", // original teal
+    "#648fff", // original blue
+    "#785ef0", // original purple
+    "#dc267f", // original magenta
+    "#fe6100", // original orange
+    "#ffb000", // original yellow
+];
+pub(crate) const DARK_PALETTE: [&str; 6] = [
+    "#22d3b3", // brighter teal
+    "#7ba3ff", // brighter blue
+    "#9b7ef5", // brighter purple
+    "#ff4d9f", // brighter magenta
+    "#ff8033", // brighter orange
+    "#ffc733", // brighter yellow
+];
+
+/// Plunge (negative) prices, and slots under the user's cheap threshold, stand out in green
+pub(crate) const LIGHT_NEGATIVE: &str = "#16a34a";
+pub(crate) const DARK_NEGATIVE: &str = "#4ade80";
+
+/// Slots over the user's expensive threshold are drawn in red
+const LIGHT_EXPENSIVE: &str = "#dc2626";
+const DARK_EXPENSIVE: &str = "#f87171";
+
+/// Color-blind-safe stand-ins for the green and red: Okabe-Ito blue and vermillion
+const LIGHT_CB_CHEAP: &str = "#0072b2";
+const DARK_CB_CHEAP: &str = "#56b4e9";
+const LIGHT_CB_EXPENSIVE: &str = "#d55e00";
+const DARK_CB_EXPENSIVE: &str = "#f07f3c";
+
 #[derive(Properties, PartialEq)]
 pub struct ChartProps {
     pub rates: Rc<Rates>,
     pub dark_mode: bool,
+    /// Number of slots before today to include, for continuity early in the day
+    #[prop_or_default]
+    pub yesterday_tail: usize,
+    /// Slots priced below this are shaded green, with a reference line at the threshold
+    #[prop_or_default]
+    pub cheap_threshold: Option<f64>,
+    /// Slots priced above this are shaded red, with a reference line at the threshold
+    #[prop_or_default]
+    pub expensive_threshold: Option<f64>,
+    #[prop_or_default]
+    pub chart_type: ChartType,
+    /// Colors for the cheap/negative and expensive slots
+    #[prop_or_default]
+    pub palette: Palette,
+    /// Time covered by each point; coarser points plot the mean price
+    #[prop_or_default]
+    pub granularity: Granularity,
+    /// Agile Outgoing prices, drawn as a line over the import prices when set
+    #[prop_or_default]
+    pub export_rates: Option<Rc<Rates>>,
+    /// Whether `rates` are import or export prices; export colors run the other way
+    #[prop_or_default]
+    pub direction: TariffDirection,
+}
+
+/// How the chart is drawn, independent of the data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ChartStyle {
+    dark_mode: bool,
+    chart_type: ChartType,
+    palette: Palette,
+    granularity: Granularity,
+    direction: TariffDirection,
+}
+
+/// Horizontal reference lines drawn across today's prices
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct ReferenceLines {
+    thresholds: PriceThresholds,
+    /// Today's average price, if today's stats could be computed
+    average: Option<f64>,
 }
 
 #[function_component(Chart)]
 pub fn chart(props: &ChartProps) -> Html {
     let container_ref = use_node_ref();
     let chart_instance = use_mut_ref(|| None::<Echarts>);
-    let series_data = use_memo(props.rates.clone(), |rates| rates.series_data());
+    let series_data = use_memo(
+        (props.rates.clone(), props.yesterday_tail, props.granularity),
+        |(rates, tail, granularity)| rates.series_at(*granularity, *tail),
+    );
+    // Export prices at each of the import series' points
+    let overlay = use_memo(
+        (
+            series_data.clone(),
+            props.export_rates.clone(),
+            props.granularity,
+        ),
+        |(series_data, export_rates, granularity)| {
+            let (x_data, _) = (**series_data).as_ref().ok()?;
+            Some(export_rates.as_ref()?.overlay_values(x_data, *granularity))
+        },
+    );
+    let today_series = use_memo(props.rates.clone(), |rates| rates.series_data());
+    // Tomorrow's slots sit at the end of the series once published
+    let tomorrow_len = *use_memo(
+        (props.rates.clone(), props.granularity),
+        |(rates, granularity)| {
+            rates.points_on(*granularity, london_today() + chrono::Duration::days(1))
+        },
+    );
+
+    let today_avg = *use_memo(props.rates.clone(), |rates| {
+        rates.stats_for_date(london_today()).map(|stats| stats.avg)
+    });
+
+    let lines = ReferenceLines {
+        thresholds: PriceThresholds {
+            cheap: props.cheap_threshold,
+            expensive: props.expensive_threshold,
+        },
+        average: today_avg,
+    };
 
     {
         let container_ref = container_ref.clone();
         let chart_instance = chart_instance.clone();
-        let dark_mode = props.dark_mode;
+        let style = ChartStyle {
+            dark_mode: props.dark_mode,
+            chart_type: props.chart_type,
+            palette: props.palette,
+            granularity: props.granularity,
+            direction: props.direction,
+        };
         let series_data_for_effect = series_data.clone();
+        let overlay = overlay.clone();
 
         use_effect_with(
-            (series_data_for_effect, container_ref, dark_mode),
-            move |(series_data, container_ref, dark_mode)| {
+            (
+                series_data_for_effect,
+                tomorrow_len,
+                container_ref,
+                style,
+                lines,
+                overlay,
+            ),
+            move |(series_data, tomorrow_len, container_ref, style, lines, overlay)| {
                 let observer = container_ref.cast::<HtmlElement>().and_then(|container| {
                     {
                         let mut chart_instance = chart_instance.borrow_mut();
-                        render_chart(&container, series_data, *dark_mode, &mut chart_instance);
+                        render_chart(
+                            &container,
+                            series_data,
+                            *tomorrow_len,
+                            *style,
+                            *lines,
+                            overlay.as_deref(),
+                            &mut chart_instance,
+                        );
                     }
 
                     let series_data = series_data.clone();
-                    let dark_mode = *dark_mode;
+                    let overlay = overlay.clone();
+                    let tomorrow_len = *tomorrow_len;
+                    let style = *style;
+                    let lines = *lines;
                     let callback_container = container.clone();
                     let chart_instance = chart_instance.clone();
                     create_debounced_resize_observer(
@@ -55,7 +198,10 @@ pub fn chart(props: &ChartProps) -> Html {
                             render_chart(
                                 &callback_container,
                                 &series_data,
-                                dark_mode,
+                                tomorrow_len,
+                                style,
+                                lines,
+                                overlay.as_deref(),
                                 &mut chart_instance,
                             );
                         },
@@ -74,14 +220,16 @@ pub fn chart(props: &ChartProps) -> Html {
         );
     }
 
-    // Calculate min/max for accessibility description
-    let (min_price, max_price) = match &*series_data {
-        Ok((_, y_data)) if !y_data.is_empty() => {
-            let min = y_data.iter().copied().fold(f64::INFINITY, f64::min);
-            let max = y_data.iter().copied().fold(f64::NEG_INFINITY, f64::max);
-            (min, max)
+    // Describe the extremes for screen readers (today only, excluding any tail)
+    let description = match &*today_series {
+        Ok((x_data, y_data)) if !y_data.is_empty() => {
+            let (min, max) = extrema_indices(y_data);
+            format!(
+                "Energy prices ranging from {:.2}p at {} to {:.2}p at {} per kilowatt hour",
+                y_data[min], x_data[min], y_data[max], x_data[max]
+            )
         }
-        _ => (0.0, 0.0),
+        _ => "No energy prices for today".to_string(),
     };
 
     html! {
@@ -91,12 +239,7 @@ pub fn chart(props: &ChartProps) -> Html {
                 role="img"
                 aria-label="Energy price chart showing half-hourly electricity rates"
             />
-            <div class="sr-only">
-                {format!(
-                    "Energy prices ranging from {:.2}p to {:.2}p per kilowatt hour",
-                    min_price, max_price
-                )}
-            </div>
+            <div class="sr-only">{description}</div>
         </div>
     }
 }
@@ -104,7 +247,10 @@ pub fn chart(props: &ChartProps) -> Html {
 fn render_chart(
     container: &HtmlElement,
     series_data: &Result<(Vec<String>, Vec<f64>), crate::models::error::AppError>,
-    dark_mode: bool,
+    tomorrow_len: usize,
+    style: ChartStyle,
+    lines: ReferenceLines,
+    overlay: Option<&[Option<f64>]>,
     chart_instance: &mut Option<Echarts>,
 ) {
     let width = container.client_width().cast_unsigned();
@@ -116,7 +262,7 @@ fn render_chart(
 
     match series_data {
         Ok(data) => {
-            let chart = build_chart(data, dark_mode);
+            let chart = build_chart(data, tomorrow_len, style, lines, overlay);
             if let Some(existing_chart) = chart_instance.as_ref() {
                 WasmRenderer::resize_chart(
                     existing_chart,
@@ -136,38 +282,65 @@ fn render_chart(
     }
 }
 
-fn build_chart(series_data: &(Vec<String>, Vec<f64>), dark_mode: bool) -> CharmingChart {
+/// Builds the bar or line chart. The last `tomorrow_len` slots are drawn as a separate,
+/// fainter "Tomorrow" series, and any `overlay` (export prices per point) as a dashed
+/// "Export" line; a legend is shown once there is more than one series. Today's average
+/// and any set thresholds are drawn as horizontal reference lines.
+fn build_chart(
+    series_data: &(Vec<String>, Vec<f64>),
+    tomorrow_len: usize,
+    style: ChartStyle,
+    lines: ReferenceLines,
+    overlay: Option<&[Option<f64>]>,
+) -> CharmingChart {
+    let ChartStyle {
+        dark_mode,
+        chart_type,
+        palette,
+        granularity,
+        direction,
+    } = style;
     let (x_data, y_data) = series_data;
+    let split = y_data.len().saturating_sub(tomorrow_len);
 
-    // Theme-aware colors
-    let (title_color, axis_color, grid_color) = if dark_mode {
-        ("#e4e4e7", "#a1a1aa", "#404040")
-    } else {
-        ("#1f2937", "#6b7280", "#e5e7eb")
-    };
+    let ChartTheme {
+        background,
+        text: title_color,
+        axis: axis_color,
+        grid: grid_color,
+        muted,
+        ..
+    } = ChartTheme::for_dark_mode(dark_mode);
 
-    // Bar colors - slightly brighter for dark mode
-    let bar_colors = if dark_mode {
-        vec![
-            "#22d3b3", // brighter teal
-            "#7ba3ff", // brighter blue
-            "#9b7ef5", // brighter purple
-            "#ff4d9f", // brighter magenta
-            "#ff8033", // brighter orange
-            "#ffc733", // brighter yellow
-        ]
-    } else {
-        vec![
-            "#00b4a0", // original teal
-            "#648fff", // original blue
-            "#785ef0", // original purple
-            "#dc267f", // original magenta
-            "#fe6100", // original orange
-            "#ffb000", // original yellow
-        ]
-    };
+    let mut bars: Vec<DataPointItem> = y_data
+        .iter()
+        .zip(bar_colors(
+            y_data,
+            dark_mode,
+            palette,
+            lines.thresholds,
+            direction,
+        ))
+        .enumerate()
+        .map(|(i, (value, color))| {
+            let style = ItemStyle::new().color(color);
+            let style = if i < split {
+                style
+            } else {
+                style.opacity(0.55)
+            };
+            DataPointItem::new(*value).item_style(style)
+        })
+        .collect();
 
-    CharmingChart::new()
+    // Tomorrow's series is padded with empty ("-") points so both share the category axis
+    let tomorrow_bars: Vec<DataPointItem> = std::iter::repeat_with(|| DataPointItem::new("-"))
+        .take(split)
+        .chain(bars.drain(split..))
+        .collect();
+
+    let mut chart = CharmingChart::new()
+        .background_color(background)
         .title(
             Title::new()
                 .text("Energy Prices")
@@ -177,16 +350,11 @@ fn build_chart(series_data: &(Vec<String>, Vec<f64>), dark_mode: bool) -> Charmi
         .tooltip(
             Tooltip::new()
                 .trigger(Trigger::Axis)
-                .axis_pointer(AxisPointer::new().type_(AxisPointerType::Shadow)),
+                .axis_pointer(AxisPointer::new().type_(match chart_type {
+                    ChartType::Bar => AxisPointerType::Shadow,
+                    ChartType::Line => AxisPointerType::Line,
+                })),
         )
-        .visual_map(VisualMap::new().show(false).pieces(vec![
-            VisualMapPiece::new().lt(7.5).color(bar_colors[0]),
-            VisualMapPiece::new().gte(7.5).lt(11.25).color(bar_colors[1]),
-            VisualMapPiece::new().gte(11.25).lt(15.0).color(bar_colors[2]),
-            VisualMapPiece::new().gte(15.0).lt(22.5).color(bar_colors[3]),
-            VisualMapPiece::new().gte(22.5).lt(30.0).color(bar_colors[4]),
-            VisualMapPiece::new().gte(30.0).color(bar_colors[5]),
-        ]))
         .grid(
             Grid::new()
                 .left("8%")
@@ -198,7 +366,17 @@ fn build_chart(series_data: &(Vec<String>, Vec<f64>), dark_mode: bool) -> Charmi
             Axis::new()
                 .type_(AxisType::Category)
                 .data(x_data.clone())
-                .axis_label(AxisLabel::new().rotate(45).color(axis_color).interval(5)),
+                .axis_label(
+                    AxisLabel::new()
+                        .rotate(45)
+                        .color(axis_color)
+                        // Labels skipped between shown ones: every third hour, or every day
+                        .interval(match granularity {
+                            Granularity::HalfHour => 5,
+                            Granularity::Hour => 2,
+                            Granularity::Day => 0,
+                        }),
+                ),
         )
         .y_axis(
             Axis::new()
@@ -213,5 +391,463 @@ fn build_chart(series_data: &(Vec<String>, Vec<f64>), dark_mode: bool) -> Charmi
                     ),
                 ),
         )
-        .series(Bar::new().data(y_data.clone()).bar_width("70%"))
+        .series(price_series(
+            chart_type,
+            "Today",
+            bars,
+            extrema_marks(&x_data[..split], &y_data[..split]),
+            Some(reference_lines(lines, dark_mode, palette, muted)),
+        ));
+
+    let mut names = vec!["Today"];
+    if tomorrow_len > 0 {
+        names.push("Tomorrow");
+        chart = chart.series(price_series(
+            chart_type,
+            "Tomorrow",
+            tomorrow_bars,
+            extrema_marks(&x_data[split..], &y_data[split..]),
+            None,
+        ));
+    }
+    if let Some(overlay) = overlay {
+        names.push("Export");
+        chart = chart.series(export_series(overlay, title_color));
+    }
+
+    if names.len() == 1 {
+        return chart;
+    }
+
+    chart.legend(
+        Legend::new()
+            .data(names)
+            .right("4%")
+            .text_style(TextStyle::new().color(axis_color)),
+    )
+}
+
+/// Export prices as a dashed line, with gaps where there is no export point
+fn export_series(overlay: &[Option<f64>], color: &str) -> Series {
+    let data: Vec<DataPointItem> = overlay
+        .iter()
+        .map(|value| value.map_or_else(|| DataPointItem::new("-"), DataPointItem::new))
+        .collect();
+
+    Line::new()
+        .name("Export")
+        .data(data)
+        .item_style(ItemStyle::new().color(color))
+        .line_style(LineStyle::new().color(color).type_(LineStyleType::Dashed))
+        .into()
+}
+
+/// Indices of the lowest and highest values, taking the first of any ties; `(0, 0)` when
+/// `y` is empty
+fn extrema_indices(y: &[f64]) -> (usize, usize) {
+    y.iter()
+        .enumerate()
+        .skip(1)
+        .fold((0, 0), |(min, max), (i, &value)| {
+            (
+                if value < y[min] { i } else { min },
+                if value > y[max] { i } else { max },
+            )
+        })
+}
+
+/// Pins on the cheapest and most expensive of the points `(x, y)`, labelled with the
+/// price and slot, e.g. "4.50p at 03:30". A flat series gets a single pin.
+fn extrema_marks(x: &[String], y: &[f64]) -> MarkPoint {
+    let pin = |i: usize| {
+        MarkPointData::new()
+            .name(format!("{:.2}p at {}", y[i], x[i]))
+            .x_axis(x[i].clone())
+            .y_axis(y[i])
+    };
+    let (min, max) = extrema_indices(y);
+    let data = match y.len() {
+        0 => vec![],
+        _ if min == max => vec![pin(min)],
+        _ => vec![pin(min), pin(max)],
+    };
+
+    MarkPoint::new()
+        .label(Label::new().position(LabelPosition::Top).formatter("{b}"))
+        .data(data)
+}
+
+/// One named price series drawn as `chart_type`, with its extremes pinned by `marks`
+fn price_series(
+    chart_type: ChartType,
+    name: &str,
+    data: Vec<DataPointItem>,
+    marks: MarkPoint,
+    mark_line: Option<MarkLine>,
+) -> Series {
+    match chart_type {
+        ChartType::Bar => {
+            let bar = Bar::new()
+                .name(name)
+                .stack("price")
+                .data(data)
+                .bar_width("70%")
+                .mark_point(marks);
+            match mark_line {
+                Some(mark_line) => bar.mark_line(mark_line),
+                None => bar,
+            }
+            .into()
+        }
+        ChartType::Line => {
+            let line = Line::new().name(name).data(data).mark_point(marks);
+            match mark_line {
+                Some(mark_line) => line.mark_line(mark_line),
+                None => line,
+            }
+            .into()
+        }
+    }
+}
+
+/// Dashed horizontal lines at today's average (in `avg_color`) and the user's cheap and
+/// expensive thresholds, each if set
+fn reference_lines(
+    lines: ReferenceLines,
+    dark_mode: bool,
+    palette: Palette,
+    avg_color: &str,
+) -> MarkLine {
+    let ReferenceLines {
+        thresholds,
+        average,
+    } = lines;
+    let (cheap_color, expensive_color) = threshold_colors(dark_mode, palette);
+
+    let line = |value: f64, name: &str, color: &str| {
+        MarkLineVariant::Simple(
+            MarkLineData::new()
+                .name(name)
+                .y_axis(value)
+                .line_style(LineStyle::new().color(color).type_(LineStyleType::Dashed))
+                .label(
+                    Label::new()
+                        .formatter(format!("{name} {value:.2}p"))
+                        .color(color),
+                ),
+        )
+    };
+
+    let data: Vec<MarkLineVariant> = [
+        average.map(|v| line(v, "avg", avg_color)),
+        thresholds.cheap.map(|v| line(v, "Cheap", cheap_color)),
+        thresholds
+            .expensive
+            .map(|v| line(v, "Expensive", expensive_color)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    MarkLine::new().silent(true).data(data)
+}
+
+/// `(cheap, expensive)` colors; cheap also marks negative prices
+const fn threshold_colors(dark_mode: bool, palette: Palette) -> (&'static str, &'static str) {
+    match (palette, dark_mode) {
+        (Palette::Default, false) => (LIGHT_NEGATIVE, LIGHT_EXPENSIVE),
+        (Palette::Default, true) => (DARK_NEGATIVE, DARK_EXPENSIVE),
+        (Palette::ColorBlindSafe, false) => (LIGHT_CB_CHEAP, LIGHT_CB_EXPENSIVE),
+        (Palette::ColorBlindSafe, true) => (DARK_CB_CHEAP, DARK_CB_EXPENSIVE),
+    }
+}
+
+/// Per-bar colors: slots under the cheap threshold or negative in green, slots over the
+/// expensive threshold in red, everything else (including zero) by price band.
+/// Export prices are earned, so negative ones are red and the bands run in reverse.
+fn bar_colors(
+    y_data: &[f64],
+    dark_mode: bool,
+    palette: Palette,
+    thresholds: PriceThresholds,
+    direction: TariffDirection,
+) -> Vec<String> {
+    let (cheap, expensive) = threshold_colors(dark_mode, palette);
+    let bands = if dark_mode {
+        DARK_PALETTE
+    } else {
+        LIGHT_PALETTE
+    };
+    let negative = match direction {
+        TariffDirection::Import => cheap,
+        TariffDirection::Export => expensive,
+    };
+
+    y_data
+        .iter()
+        .map(|&value| {
+            let band = thresholds.classify(value);
+            if value < 0.0 {
+                negative.to_string()
+            } else if band == ThresholdBand::Cheap {
+                cheap.to_string()
+            } else if band == ThresholdBand::Expensive {
+                expensive.to_string()
+            } else {
+                let band = PRICE_THRESHOLDS.iter().take_while(|t| value >= **t).count();
+                let band = match direction {
+                    TariffDirection::Import => band,
+                    TariffDirection::Export => bands.len() - 1 - band,
+                };
+                bands[band].to_string()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_chart_uses_requested_series_type() {
+        let series = (
+            vec!["00:00".to_string(), "00:30".to_string()],
+            vec![12.0, 15.0],
+        );
+
+        let style = |chart_type| ChartStyle {
+            dark_mode: false,
+            chart_type,
+            palette: Palette::Default,
+            granularity: Granularity::HalfHour,
+            direction: TariffDirection::Import,
+        };
+
+        let bar = build_chart(
+            &series,
+            1,
+            style(ChartType::Bar),
+            ReferenceLines::default(),
+            None,
+        );
+        let line = build_chart(
+            &series,
+            1,
+            style(ChartType::Line),
+            ReferenceLines::default(),
+            None,
+        );
+
+        let bar = bar.to_string();
+        let line = line.to_string();
+        assert!(bar.contains(r#""type": "bar""#) && !bar.contains(r#""type": "line""#));
+        assert!(line.contains(r#""type": "line""#) && !line.contains(r#""type": "bar""#));
+    }
+
+    #[test]
+    fn test_build_chart_overlays_export_line() {
+        let series = (
+            vec!["00:00".to_string(), "00:30".to_string()],
+            vec![12.0, 15.0],
+        );
+        let style = ChartStyle {
+            dark_mode: false,
+            chart_type: ChartType::Bar,
+            palette: Palette::Default,
+            granularity: Granularity::HalfHour,
+            direction: TariffDirection::Import,
+        };
+
+        let chart = build_chart(
+            &series,
+            0,
+            style,
+            ReferenceLines::default(),
+            Some(&[None, Some(4.5)]),
+        )
+        .to_string();
+
+        assert!(chart.contains(r#""name": "Export""#));
+        assert!(chart.contains(r#""type": "line""#));
+    }
+
+    #[test]
+    fn test_build_chart_marks_average_only_when_known() {
+        let series = (
+            vec!["00:00".to_string(), "00:30".to_string()],
+            vec![12.0, 15.0],
+        );
+        let style = ChartStyle {
+            dark_mode: false,
+            chart_type: ChartType::Bar,
+            palette: Palette::Default,
+            granularity: Granularity::HalfHour,
+            direction: TariffDirection::Import,
+        };
+        let lines = |average| ReferenceLines {
+            average,
+            ..ReferenceLines::default()
+        };
+
+        let with_avg = build_chart(&series, 0, style, lines(Some(13.5)), None).to_string();
+        let without = build_chart(&series, 0, style, lines(None), None).to_string();
+
+        assert!(with_avg.contains("avg 13.50p"));
+        assert!(!without.contains("avg "));
+    }
+
+    #[test]
+    fn test_extrema_indices() {
+        assert_eq!(extrema_indices(&[12.0, 4.5, 30.0, 9.0]), (1, 2));
+        assert_eq!(extrema_indices(&[-2.0]), (0, 0));
+        assert_eq!(extrema_indices(&[]), (0, 0));
+    }
+
+    #[test]
+    fn test_extrema_indices_ties_pick_first() {
+        assert_eq!(extrema_indices(&[5.0, 3.0, 8.0, 3.0, 8.0]), (1, 2));
+        assert_eq!(extrema_indices(&[7.0, 7.0, 7.0]), (0, 0));
+    }
+
+    #[test]
+    fn test_price_series_pins_extremes() {
+        let x = [
+            "03:00".to_string(),
+            "03:30".to_string(),
+            "04:00".to_string(),
+        ];
+        let chart = CharmingChart::new()
+            .series(price_series(
+                ChartType::Bar,
+                "Today",
+                vec![DataPointItem::new(3.0)],
+                extrema_marks(&x, &[12.0, 4.5, 30.0]),
+                None,
+            ))
+            .to_string();
+
+        assert!(chart.contains("4.50p at 03:30") && chart.contains("30.00p at 04:00"));
+        assert!(!chart.contains("12.00p"));
+    }
+
+    #[test]
+    fn test_extrema_marks_pin_flat_series_once() {
+        let x = ["03:00".to_string(), "03:30".to_string()];
+        let flat = CharmingChart::new()
+            .series(price_series(
+                ChartType::Line,
+                "Today",
+                vec![],
+                extrema_marks(&x, &[7.0, 7.0]),
+                None,
+            ))
+            .to_string();
+
+        assert_eq!(flat.matches("7.00p at 03:00").count(), 1);
+    }
+
+    #[test]
+    fn test_bar_colors_split_at_zero() {
+        let colors = bar_colors(
+            &[-2.1, -0.01, 0.0, 0.01],
+            false,
+            Palette::Default,
+            PriceThresholds::default(),
+            TariffDirection::Import,
+        );
+
+        assert_eq!(colors[0], LIGHT_NEGATIVE);
+        assert_eq!(colors[1], LIGHT_NEGATIVE);
+        // Exactly zero is not a plunge price
+        assert_eq!(colors[2], LIGHT_PALETTE[0]);
+        assert_eq!(colors[3], LIGHT_PALETTE[0]);
+    }
+
+    #[test]
+    fn test_export_bar_colors_run_the_other_way() {
+        let colors = bar_colors(
+            &[-1.0, 0.0, 30.0],
+            false,
+            Palette::Default,
+            PriceThresholds::default(),
+            TariffDirection::Export,
+        );
+
+        assert_eq!(
+            colors,
+            vec![LIGHT_EXPENSIVE, LIGHT_PALETTE[5], LIGHT_PALETTE[0]]
+        );
+    }
+
+    #[test]
+    fn test_bar_colors_follow_price_bands() {
+        let colors = bar_colors(
+            &[7.5, 14.99, 30.0, -1.0],
+            true,
+            Palette::Default,
+            PriceThresholds::default(),
+            TariffDirection::Import,
+        );
+
+        assert_eq!(
+            colors,
+            vec![
+                DARK_PALETTE[1],
+                DARK_PALETTE[2],
+                DARK_PALETTE[5],
+                DARK_NEGATIVE
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bar_colors_threshold_bands_override_palette() {
+        let thresholds = PriceThresholds {
+            cheap: Some(10.0),
+            expensive: Some(30.0),
+        };
+        let colors = bar_colors(
+            &[9.99, 10.0, 30.0, 30.01],
+            false,
+            Palette::Default,
+            thresholds,
+            TariffDirection::Import,
+        );
+
+        assert_eq!(
+            colors,
+            vec![
+                LIGHT_NEGATIVE,
+                LIGHT_PALETTE[1],
+                LIGHT_PALETTE[5],
+                LIGHT_EXPENSIVE
+            ]
+        );
+    }
+
+    #[test]
+    fn test_color_blind_palette_replaces_green_and_red() {
+        let thresholds = PriceThresholds {
+            cheap: Some(10.0),
+            expensive: Some(30.0),
+        };
+        let colors = bar_colors(
+            &[-1.0, 9.99, 20.0, 30.01],
+            true,
+            Palette::ColorBlindSafe,
+            thresholds,
+            TariffDirection::Import,
+        );
+
+        assert_eq!(
+            colors,
+            vec![
+                DARK_CB_CHEAP,
+                DARK_CB_CHEAP,
+                DARK_PALETTE[3],
+                DARK_CB_EXPENSIVE
+            ]
+        );
+    }
 }
@@ -0,0 +1,37 @@
+use yew::prelude::*;
+
+use crate::models::chart_type::ChartType;
+
+#[derive(Properties, PartialEq)]
+pub struct ChartTypeToggleProps {
+    pub chart_type: ChartType,
+    pub on_change: Callback<ChartType>,
+}
+
+/// Two-button switch between bar and line charts
+#[function_component(ChartTypeToggle)]
+pub fn chart_type_toggle(props: &ChartTypeToggleProps) -> Html {
+    let button = |chart_type: ChartType| {
+        let pressed = chart_type == props.chart_type;
+        let onclick = {
+            let callback = props.on_change.clone();
+            Callback::from(move |_| callback.emit(chart_type))
+        };
+        html! {
+            <button
+                class={classes!("direction-option", pressed.then_some("active"))}
+                {onclick}
+                aria-pressed={if pressed { "true" } else { "false" }}
+            >
+                {chart_type.label()}
+            </button>
+        }
+    };
+
+    html! {
+        <div class="direction-toggle" role="group" aria-label="Draw the chart as bars or a line">
+            {button(ChartType::Bar)}
+            {button(ChartType::Line)}
+        </div>
+    }
+}
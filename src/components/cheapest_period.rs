@@ -1,15 +1,13 @@
 use chrono::{Duration, DurationRound, Utc};
 use yew::prelude::*;
 
-use crate::hooks::use_rates::{DataState, use_rates};
-use crate::hooks::use_region::use_region;
+use crate::hooks::use_rates::{DataState, use_rates_context};
 use crate::utils::time::london_time;
 
 /// Displays the cheapest electricity period in the next 3 hours
 #[function_component(CheapestPeriod)]
 pub fn cheapest_period() -> Html {
-    let region_handle = use_region();
-    let state = use_rates(region_handle.region);
+    let state = use_rates_context().rates.state;
 
     let cheapest_time = match &*state {
         DataState::Loaded(rates) => {
@@ -20,16 +18,11 @@ pub fn cheapest_period() -> Html {
             let three_hours_later = now + Duration::hours(3);
 
             // Find the cheapest rate in the next 3 hours (including current window)
-            let cheapest = rates
-                .filter_from(window_start) // Use window_start instead of now
-                .take_while(|r| r.valid_from < three_hours_later)
-                .min_by(|a, b| {
-                    a.value_inc_vat
-                        .partial_cmp(&b.value_inc_vat)
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
+            let cheapest = rates.cheapest_between(window_start, three_hours_later);
 
-            cheapest.map(|rate| london_time(rate.valid_from).format("%H:%M").to_string())
+            cheapest
+                .first()
+                .map(|rate| london_time(rate.valid_from).format("%H:%M").to_string())
         }
         _ => None,
     };
@@ -0,0 +1,119 @@
+use gloo::file::{File, callbacks::FileReader};
+use std::rc::Rc;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::models::consumption::ConsumptionProfile;
+use crate::models::rates::Rates;
+use crate::services::api::Region;
+
+#[derive(Properties, PartialEq)]
+pub struct CostCalculatorProps {
+    /// Rates to price the uploaded usage against
+    pub rates: Rc<Rates>,
+    /// Region `rates` and `standing_charge` were fetched for
+    pub region: Region,
+    /// Daily standing charge in pence, added per day of usage
+    #[prop_or_default]
+    pub standing_charge: Option<f64>,
+}
+
+/// Upload a half-hourly consumption CSV and see what it would have cost on Agile
+#[function_component(CostCalculator)]
+pub fn cost_calculator(props: &CostCalculatorProps) -> Html {
+    let profile = use_state(|| None::<Result<Rc<ConsumptionProfile>, String>>);
+    // Dropping the reader cancels the read, so it lives until the next upload
+    let reader = use_mut_ref(|| None::<FileReader>);
+
+    let breakdown = use_memo(
+        (props.rates.clone(), (*profile).clone()),
+        |(rates, profile)| {
+            profile.clone().map(|profile| {
+                profile.and_then(|profile| rates.cost_for(&profile).map_err(|e| e.to_string()))
+            })
+        },
+    );
+
+    let on_change = {
+        let profile = profile.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let Some(file) = input.files().and_then(|files| files.get(0)) else {
+                return;
+            };
+
+            let profile = profile.clone();
+            *reader.borrow_mut() = Some(gloo::file::callbacks::read_as_text(
+                &File::from(file),
+                move |text| {
+                    let parsed = text.map_err(|e| e.to_string()).and_then(|text| {
+                        ConsumptionProfile::from_csv(&text)
+                            .map(Rc::new)
+                            .map_err(|e| e.to_string())
+                    });
+                    profile.set(Some(parsed));
+                },
+            ));
+        })
+    };
+
+    html! {
+        <div class="cost-calculator">
+            <label>
+                {"Consumption CSV "}
+                <input
+                    type="file"
+                    accept=".csv,text/csv"
+                    onchange={on_change}
+                    aria-label="Half-hourly consumption CSV"
+                />
+            </label>
+            <p class="cost-region">
+                {format!("Priced on {} Agile rates", props.region.description())}
+            </p>
+            {
+                match &*breakdown {
+                    None => html! {},
+                    Some(Err(err)) => html! { <p class="error">{err}</p> },
+                    Some(Ok(cost)) => html! {
+                        <div class="summary-grid">
+                            <div class="summary-item">
+                                <h3>{"Total Usage"}</h3>
+                                <p class="summary-value">{format!("{:.2} kWh", cost.total_kwh)}</p>
+                            </div>
+                            <div class="summary-item">
+                                <h3>{"Total Cost"}</h3>
+                                <p class="summary-value">{format!("£{:.2}", cost.total_cost)}</p>
+                            </div>
+                            if let Some(standing) = props.standing_charge {
+                                <div class="summary-item">
+                                    <h3>{"Incl. Standing Charge"}</h3>
+                                    <p class="summary-value">
+                                        {format!("£{:.2}", cost.total_with_standing(standing))}
+                                    </p>
+                                    <p class="cost-standing">
+                                        {format!("{} days at {standing:.2}p/day", cost.days)}
+                                    </p>
+                                </div>
+                            }
+                            if let Some(avg) = cost.avg_p_per_kwh() {
+                                <div class="summary-item">
+                                    <h3>{"Average Price"}</h3>
+                                    <p class="summary-value">{format!("{avg:.2}p/kWh")}</p>
+                                </div>
+                            }
+                            if cost.unmatched > 0 {
+                                <p class="cost-unmatched">
+                                    {format!(
+                                        "{} readings fall outside the loaded rates and are not counted",
+                                        cost.unmatched
+                                    )}
+                                </p>
+                            }
+                        </div>
+                    },
+                }
+            }
+        </div>
+    }
+}
@@ -0,0 +1,223 @@
+use std::rc::Rc;
+use yew::prelude::*;
+
+use crate::components::FavouriteToggle;
+use crate::hooks::use_favourite_regions::favourites_first;
+use crate::services::api::Region;
+
+const LISTBOX_ID: &str = "region-listbox";
+
+#[derive(Properties, PartialEq)]
+pub struct CustomRegionSelectorProps {
+    pub region: Region,
+    pub on_change: Callback<Region>,
+    /// Listed first, separated from the rest
+    #[prop_or_default]
+    pub favourites: Vec<Region>,
+    /// Shows a star to add or remove the selected region from the favourites
+    #[prop_or_default]
+    pub on_toggle_favourite: Option<Callback<Region>>,
+}
+
+/// Region where a listbox key press moves the highlight from `current`, through the
+/// regions in listed `order`. Up/Down stop at the ends rather than wrapping; keys without
+/// a meaning leave it put.
+pub fn next_region_on_key(current: Region, key: &str, order: &[Region]) -> Region {
+    let index = order.iter().position(|r| *r == current).unwrap_or(0);
+    match key {
+        "ArrowDown" => order[(index + 1).min(order.len() - 1)],
+        "ArrowUp" => order[index.saturating_sub(1)],
+        "Home" => order[0],
+        "End" => order[order.len() - 1],
+        _ => current,
+    }
+}
+
+fn option_id(region: Region) -> String {
+    format!("region-option-{}", region.code())
+}
+
+fn option_label(region: Region) -> String {
+    format!("{} ({})", region.description(), region.code())
+}
+
+/// Styled region dropdown following the ARIA combobox pattern: Enter, Space or an arrow
+/// key opens it, Up/Down/Home/End move the highlight, Enter or Space selects and Escape
+/// closes without changing the region
+#[function_component(CustomRegionSelector)]
+pub fn custom_region_selector(props: &CustomRegionSelectorProps) -> Html {
+    let open = use_state(|| false);
+    let active = use_state(|| props.region);
+    let (regions, favourite_count) = favourites_first(&props.favourites);
+    let regions = Rc::new(regions);
+
+    let onkeydown = {
+        let open = open.clone();
+        let active = active.clone();
+        let region = props.region;
+        let on_change = props.on_change.clone();
+        let regions = regions.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            let key = e.key();
+            if !*open {
+                if matches!(key.as_str(), "Enter" | " " | "ArrowDown" | "ArrowUp") {
+                    e.prevent_default();
+                    active.set(region);
+                    open.set(true);
+                }
+                return;
+            }
+
+            match key.as_str() {
+                "Enter" | " " => {
+                    e.prevent_default();
+                    open.set(false);
+                    if *active != region {
+                        on_change.emit(*active);
+                    }
+                }
+                "Escape" => {
+                    e.prevent_default();
+                    open.set(false);
+                }
+                "Tab" => open.set(false),
+                _ => {
+                    let next = next_region_on_key(*active, &key, &regions);
+                    if next != *active {
+                        e.prevent_default();
+                        active.set(next);
+                    }
+                }
+            }
+        })
+    };
+
+    let onclick = {
+        let open = open.clone();
+        let active = active.clone();
+        let region = props.region;
+        Callback::from(move |_: MouseEvent| {
+            active.set(region);
+            open.set(!*open);
+        })
+    };
+
+    let onblur = {
+        let open = open.clone();
+        Callback::from(move |_: FocusEvent| open.set(false))
+    };
+
+    html! {
+        <div class="custom-region-selector">
+            if let Some(on_toggle) = props.on_toggle_favourite.clone() {
+                <FavouriteToggle
+                    region={props.region}
+                    favourite={props.favourites.contains(&props.region)}
+                    {on_toggle}
+                />
+            }
+            <button
+                type="button"
+                class="region-selector"
+                role="combobox"
+                aria-haspopup="listbox"
+                aria-expanded={open.to_string()}
+                aria-controls={LISTBOX_ID}
+                aria-activedescendant={open.then(|| option_id(*active))}
+                aria-label="Select electricity region"
+                title="Select electricity region"
+                {onclick}
+                {onkeydown}
+                {onblur}
+            >
+                {option_label(props.region)}
+            </button>
+            if *open {
+                <ul id={LISTBOX_ID} class="region-listbox" role="listbox">
+                    {
+                        regions.iter().enumerate().map(|(i, &r)| {
+                            // Mouse down rather than click, so it lands before the button blurs
+                            let onmousedown = {
+                                let open = open.clone();
+                                let on_change = props.on_change.clone();
+                                Callback::from(move |e: MouseEvent| {
+                                    e.prevent_default();
+                                    open.set(false);
+                                    on_change.emit(r);
+                                })
+                            };
+                            html! {
+                                <>
+                                    if i == favourite_count && i > 0 {
+                                        <li class="region-separator" role="separator"></li>
+                                    }
+                                    <li
+                                        id={option_id(r)}
+                                        role="option"
+                                        aria-selected={(r == props.region).to_string()}
+                                        class={classes!("region-option", (r == *active).then_some("active"))}
+                                        {onmousedown}
+                                    >
+                                        {option_label(r)}
+                                    </li>
+                                </>
+                            }
+                        }).collect::<Html>()
+                    }
+                </ul>
+            }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arrow_keys_step_through_regions() {
+        assert_eq!(
+            next_region_on_key(Region::C, "ArrowDown", Region::all()),
+            Region::D
+        );
+        assert_eq!(
+            next_region_on_key(Region::C, "ArrowUp", Region::all()),
+            Region::B
+        );
+    }
+
+    #[test]
+    fn test_arrow_keys_stop_at_the_ends() {
+        let first = Region::all()[0];
+        let last = *Region::all().last().unwrap();
+
+        assert_eq!(next_region_on_key(first, "ArrowUp", Region::all()), first);
+        assert_eq!(next_region_on_key(last, "ArrowDown", Region::all()), last);
+        assert_eq!(next_region_on_key(Region::C, "Home", Region::all()), first);
+        assert_eq!(next_region_on_key(Region::C, "End", Region::all()), last);
+    }
+
+    #[test]
+    fn test_arrow_keys_follow_listed_order() {
+        let (order, _) = favourites_first(&[Region::M, Region::C]);
+
+        assert_eq!(
+            next_region_on_key(Region::M, "ArrowDown", &order),
+            Region::C
+        );
+        assert_eq!(
+            next_region_on_key(Region::C, "ArrowDown", &order),
+            Region::A
+        );
+        assert_eq!(next_region_on_key(Region::A, "Home", &order), Region::M);
+    }
+
+    #[test]
+    fn test_other_keys_keep_the_highlight() {
+        assert_eq!(next_region_on_key(Region::C, "a", Region::all()), Region::C);
+        assert_eq!(
+            next_region_on_key(Region::C, "Enter", Region::all()),
+            Region::C
+        );
+    }
+}
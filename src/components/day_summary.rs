@@ -1,4 +1,10 @@
-use crate::models::rates::DayStats;
+use crate::components::SlotProgress;
+use crate::components::direction_toggle::TariffDirection;
+use crate::config::Config;
+use crate::models::price_unit::PriceUnit;
+use crate::models::rates::{DayStats, Rate, price_emoji};
+use crate::utils::format::format_price;
+use crate::utils::time::london_time;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -6,13 +12,47 @@ pub struct DaySummaryProps {
     pub stats: DayStats,
     pub title: String,
     pub current_price: Option<f64>,
+    /// London time window of the current slot, shown after its price
+    #[prop_or_default]
+    pub current_window: Option<String>,
+    /// The slot in effect now, drawn as a progress bar under the current price
+    #[prop_or_default]
+    pub current_rate: Option<Rate>,
     pub next_price: Option<f64>,
+    /// Where the current price sits in today's distribution (0–100)
+    #[prop_or_default]
+    pub current_percentile: Option<f64>,
+    /// Current price relative to the day's average, in percent
+    #[prop_or_default]
+    pub current_vs_avg_pct: Option<f64>,
+    /// Mean price over the next `Config::UPCOMING_AVG_HOURS`, from the current slot
+    #[prop_or_default]
+    pub upcoming_avg: Option<f64>,
+    /// Mean price over the `Config` peak window
+    #[prop_or_default]
+    pub peak_avg: Option<f64>,
+    /// Mean price outside the peak window
+    #[prop_or_default]
+    pub off_peak_avg: Option<f64>,
+    /// Agile Outgoing price for the current slot, when export is being compared
+    #[prop_or_default]
+    pub export_price: Option<f64>,
+    /// Daily standing charge in pence, shown alongside the unit rates
+    #[prop_or_default]
+    pub standing_charge: Option<f64>,
     #[prop_or(false)]
     pub is_tomorrow: bool,
+    #[prop_or_default]
+    pub unit: PriceUnit,
+    /// Export prices are earned, so a high one is good news
+    #[prop_or_default]
+    pub direction: TariffDirection,
 }
 
 #[function_component(DaySummary)]
 pub fn day_summary(props: &DaySummaryProps) -> Html {
+    let unit = props.unit;
+    let price = |value_p: f64| format_price(value_p, unit);
     let card_class = if props.is_tomorrow {
         "day-summary-card tomorrow"
     } else {
@@ -25,25 +65,147 @@ pub fn day_summary(props: &DaySummaryProps) -> Html {
             <div class="summary-grid">
                 <div class="summary-item">
                     <h3>{"Price Range"}</h3>
-                    <p class="summary-value">{&props.stats.price_range}</p>
+                    <p class="summary-value">{format!("{} - {}", price(props.stats.min), price(props.stats.max))}</p>
+                </div>
+                <div class="summary-item">
+                    <h3>{"Cheapest"}</h3>
+                    <p class="summary-value">{extreme_label(&props.stats.cheapest, unit)}</p>
+                </div>
+                <div class="summary-item">
+                    <h3>{"Most Expensive"}</h3>
+                    <p class="summary-value">{extreme_label(&props.stats.priciest, unit)}</p>
                 </div>
                 <div class="summary-item">
                     <h3>{"Average Price"}</h3>
-                    <p class="summary-value">{format!("{:.2}p", props.stats.avg)}</p>
+                    <p class="summary-value">{price(props.stats.avg)}</p>
+                </div>
+                <div class="summary-item">
+                    <h3>{"Median Price"}</h3>
+                    <p class="summary-value">{price(props.stats.median)}</p>
+                </div>
+                <div class="summary-item">
+                    <h3>{"90th Percentile"}</h3>
+                    <p class="summary-value">{price(props.stats.p90)}</p>
                 </div>
                 if let Some(current) = props.current_price {
                     <div class="summary-item">
                         <h3>{"Current Price"}</h3>
-                        <p class="summary-value">{format!("{:.2}p", current)}</p>
+                        <p class="summary-value">
+                            {match &props.current_window {
+                                Some(window) => format!("{} · {window}", price(current)),
+                                None => price(current),
+                            }}
+                            if Config::SHOW_PRICE_EMOJI {
+                                <span
+                                    class="price-emoji"
+                                    role="img"
+                                    aria-label={props.stats.band(current).label()}
+                                >
+                                    {band_emoji(current, &props.stats, props.direction)}
+                                </span>
+                            }
+                        </p>
+                        <SlotProgress rate={props.current_rate.clone()} />
+                        if let Some(pct) = props.current_vs_avg_pct {
+                            <p class={classes!("avg-delta", delta_class(pct, props.direction))}>
+                                {format!("{} {:.0}% vs average", delta_arrow(pct), pct.abs())}
+                            </p>
+                        }
+                    </div>
+                }
+                if let Some(percentile) = props.current_percentile {
+                    <div class="summary-item">
+                        <h3>{"Current Percentile"}</h3>
+                        <p class="summary-value">{format!("{percentile:.0}%")}</p>
                     </div>
                 }
                 if let Some(next) = props.next_price {
                     <div class="summary-item">
                         <h3>{"Next Price"}</h3>
-                        <p class="summary-value">{format!("{:.2}p", next)}</p>
+                        <p class="summary-value">{price(next)}</p>
+                    </div>
+                }
+                if let Some(avg) = props.upcoming_avg {
+                    <div class="summary-item">
+                        <h3>{format!("Next {}h Avg", Config::UPCOMING_AVG_HOURS)}</h3>
+                        <p class="summary-value">{price(avg)}</p>
+                    </div>
+                }
+                if let Some(avg) = props.peak_avg {
+                    <div class="summary-item">
+                        <h3>
+                            {format!(
+                                "Peak Avg ({:02}:00–{:02}:00)",
+                                Config::PEAK_START_HOUR,
+                                Config::PEAK_END_HOUR
+                            )}
+                        </h3>
+                        <p class="summary-value">{price(avg)}</p>
+                    </div>
+                }
+                if let Some(avg) = props.off_peak_avg {
+                    <div class="summary-item">
+                        <h3>{"Off-Peak Avg"}</h3>
+                        <p class="summary-value">{price(avg)}</p>
+                    </div>
+                }
+                if let Some(export) = props.export_price {
+                    <div class="summary-item">
+                        <h3>{"Export Price Now"}</h3>
+                        <p class="summary-value">{price(export)}</p>
+                    </div>
+                }
+                if let Some(standing) = props.standing_charge {
+                    <div class="summary-item">
+                        <h3>{"Standing Charge"}</h3>
+                        <p class="summary-value">{format!("{}/day", price(standing))}</p>
                     </div>
                 }
             </div>
         </div>
     }
 }
+
+/// Price and London start time of a slot, e.g. "11.20p at 03:30"
+fn extreme_label(rate: &Rate, unit: PriceUnit) -> String {
+    format!(
+        "{} at {}",
+        format_price(rate.value_inc_vat, unit),
+        london_time(rate.valid_from).format("%H:%M")
+    )
+}
+
+/// Arrow pointing the way the current price differs from the average
+fn delta_arrow(pct: f64) -> &'static str {
+    if pct > 0.0 {
+        "▲"
+    } else if pct < 0.0 {
+        "▼"
+    } else {
+        "■"
+    }
+}
+
+/// Emoji for how good `value` is for the day: cheap is good when importing, dear is good
+/// when exporting
+fn band_emoji(value: f64, stats: &DayStats, direction: TariffDirection) -> &'static str {
+    match direction {
+        TariffDirection::Import => price_emoji(value, stats),
+        TariffDirection::Export => stats.band(value).mirrored().emoji(),
+    }
+}
+
+/// Above average is bad news when importing and good news when exporting
+fn delta_class(pct: f64, direction: TariffDirection) -> &'static str {
+    let pct = match direction {
+        TariffDirection::Import => pct,
+        TariffDirection::Export => -pct,
+    };
+    if pct > 0.0 {
+        "delta-bad"
+    } else if pct < 0.0 {
+        "delta-good"
+    } else {
+        "delta-flat"
+    }
+}
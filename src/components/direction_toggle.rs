@@ -0,0 +1,55 @@
+use yew::prelude::*;
+
+/// Which side of the meter the shown prices are for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TariffDirection {
+    /// Agile import prices paid for electricity used
+    #[default]
+    Import,
+    /// Agile Outgoing prices earned for electricity exported
+    Export,
+}
+
+impl TariffDirection {
+    /// Returns human-readable label
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Import => "Import",
+            Self::Export => "Export",
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct DirectionToggleProps {
+    pub direction: TariffDirection,
+    pub on_change: Callback<TariffDirection>,
+}
+
+/// Two-button switch between import and export prices
+#[function_component(DirectionToggle)]
+pub fn direction_toggle(props: &DirectionToggleProps) -> Html {
+    let button = |direction: TariffDirection| {
+        let pressed = direction == props.direction;
+        let onclick = {
+            let callback = props.on_change.clone();
+            Callback::from(move |_| callback.emit(direction))
+        };
+        html! {
+            <button
+                class={classes!("direction-option", pressed.then_some("active"))}
+                {onclick}
+                aria-pressed={if pressed { "true" } else { "false" }}
+            >
+                {direction.label()}
+            </button>
+        }
+    };
+
+    html! {
+        <div class="direction-toggle" role="group" aria-label="Show import or export prices">
+            {button(TariffDirection::Import)}
+            {button(TariffDirection::Export)}
+        </div>
+    }
+}
@@ -0,0 +1,34 @@
+use crate::models::error::AppError;
+use std::rc::Rc;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ErrorNoticeProps {
+    pub error: Rc<AppError>,
+    /// What failed, e.g. "Error loading carbon data"
+    pub context: AttrValue,
+    /// Offered as a retry button when the error is worth retrying by hand
+    #[prop_or_default]
+    pub on_retry: Option<Callback<()>>,
+}
+
+/// A failed load, with advice suited to the kind of failure
+#[function_component(ErrorNotice)]
+pub fn error_notice(props: &ErrorNoticeProps) -> Html {
+    let retry = props
+        .on_retry
+        .clone()
+        .filter(|_| props.error.offers_retry());
+
+    html! {
+        <>
+            <p class="error">{format!("{}: {}", props.context, props.error)}</p>
+            if let Some(guidance) = props.error.guidance() {
+                <p class="error-guidance">{guidance}</p>
+            }
+            if let Some(retry) = retry {
+                <button class="error-retry" onclick={move |_| retry.emit(())}>{"Retry"}</button>
+            }
+        </>
+    }
+}
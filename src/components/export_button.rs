@@ -0,0 +1,52 @@
+use crate::models::rates::Rates;
+use crate::services::api::Region;
+use crate::utils::time::london_today;
+use std::rc::Rc;
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ExportButtonProps {
+    pub rates: Rc<Rates>,
+    pub region: Region,
+}
+
+/// Downloads the loaded rates as `agile-rates-<region>-<date>.csv`
+#[function_component(ExportButton)]
+pub fn export_button(props: &ExportButtonProps) -> Html {
+    let on_click = {
+        let rates = props.rates.clone();
+        let filename = format!("agile-rates-{}-{}.csv", props.region.code(), london_today());
+        Callback::from(move |_| {
+            if let Err(e) = download_csv(&filename, &rates.to_csv()) {
+                web_sys::console::error_1(&format!("CSV export failed: {e:?}").into());
+            }
+        })
+    };
+
+    html! {
+        <button class="export-button" onclick={on_click} disabled={props.rates.is_empty()}>
+            {"Export CSV"}
+        </button>
+    }
+}
+
+/// Saves `contents` through a temporary object URL and a clicked `<a download>`
+fn download_csv(filename: &str, contents: &str) -> Result<(), JsValue> {
+    let parts = web_sys::js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = BlobPropertyBag::new();
+    options.set_type("text/csv");
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or("document unavailable")?;
+    let anchor: HtmlAnchorElement = document.create_element("a")?.dyn_into()?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+
+    Url::revoke_object_url(&url)
+}
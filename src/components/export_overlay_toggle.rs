@@ -0,0 +1,28 @@
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ExportOverlayToggleProps {
+    pub enabled: bool,
+    pub on_change: Callback<bool>,
+}
+
+/// Button turning the export price comparison on the import view on and off
+#[function_component(ExportOverlayToggle)]
+pub fn export_overlay_toggle(props: &ExportOverlayToggleProps) -> Html {
+    let onclick = {
+        let enabled = props.enabled;
+        let callback = props.on_change.clone();
+        Callback::from(move |_| callback.emit(!enabled))
+    };
+
+    html! {
+        <button
+            class={classes!("direction-option", "export-overlay-toggle", props.enabled.then_some("active"))}
+            {onclick}
+            aria-pressed={if props.enabled { "true" } else { "false" }}
+            title="Fetch Agile Outgoing prices and show them alongside import"
+        >
+            {"Compare export"}
+        </button>
+    }
+}
@@ -0,0 +1,38 @@
+use yew::prelude::*;
+
+use crate::services::api::Region;
+
+#[derive(Properties, PartialEq)]
+pub struct FavouriteToggleProps {
+    pub region: Region,
+    pub favourite: bool,
+    pub on_toggle: Callback<Region>,
+}
+
+/// Star button adding the selected region to, or removing it from, the favourites
+#[function_component(FavouriteToggle)]
+pub fn favourite_toggle(props: &FavouriteToggleProps) -> Html {
+    let label = if props.favourite {
+        format!("Remove {} from favourites", props.region.description())
+    } else {
+        format!("Add {} to favourites", props.region.description())
+    };
+
+    let onclick = {
+        let region = props.region;
+        let callback = props.on_toggle.clone();
+        Callback::from(move |_| callback.emit(region))
+    };
+
+    html! {
+        <button
+            class="favourite-toggle"
+            {onclick}
+            aria-label={label.clone()}
+            aria-pressed={if props.favourite { "true" } else { "false" }}
+            title={label}
+        >
+            <span aria-hidden="true">{if props.favourite { "★" } else { "☆" }}</span>
+        </button>
+    }
+}
@@ -0,0 +1,48 @@
+use crate::models::rates::Rates;
+use crate::utils::time::format_slot_window;
+use std::rc::Rc;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct GapBannerProps {
+    pub rates: Rc<Rates>,
+}
+
+/// Dismissible warning listing intervals missing from the published rates
+#[function_component(GapBanner)]
+pub fn gap_banner(props: &GapBannerProps) -> Html {
+    let dismissed = use_state(|| false);
+    let gaps = use_memo(props.rates.clone(), |rates| rates.gaps());
+
+    if *dismissed || gaps.is_empty() {
+        return html! {};
+    }
+
+    let on_dismiss = {
+        let dismissed = dismissed.clone();
+        Callback::from(move |_| dismissed.set(true))
+    };
+
+    let text = gaps
+        .iter()
+        .map(|(from, to)| format_slot_window(*from, *to))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    html! {
+        <div class="gap-banner" role="alert">
+            <p>
+                <strong>{"Prices missing "}</strong>
+                {text}
+                {"; charts and averages skip these times"}
+            </p>
+            <button
+                class="plunge-banner-dismiss"
+                onclick={on_dismiss}
+                aria-label="Dismiss missing prices warning"
+            >
+                {"×"}
+            </button>
+        </div>
+    }
+}
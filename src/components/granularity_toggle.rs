@@ -0,0 +1,38 @@
+use yew::prelude::*;
+
+use crate::models::rates::Granularity;
+
+#[derive(Properties, PartialEq)]
+pub struct GranularityToggleProps {
+    pub granularity: Granularity,
+    pub on_change: Callback<Granularity>,
+}
+
+/// Three-way switch for how much time each point of the price chart covers
+#[function_component(GranularityToggle)]
+pub fn granularity_toggle(props: &GranularityToggleProps) -> Html {
+    let button = |granularity: Granularity| {
+        let pressed = granularity == props.granularity;
+        let onclick = {
+            let callback = props.on_change.clone();
+            Callback::from(move |_| callback.emit(granularity))
+        };
+        html! {
+            <button
+                class={classes!("direction-option", pressed.then_some("active"))}
+                {onclick}
+                aria-pressed={if pressed { "true" } else { "false" }}
+            >
+                {granularity.label()}
+            </button>
+        }
+    };
+
+    html! {
+        <div class="direction-toggle" role="group" aria-label="Time covered by each chart point">
+            {button(Granularity::HalfHour)}
+            {button(Granularity::Hour)}
+            {button(Granularity::Day)}
+        </div>
+    }
+}
@@ -0,0 +1,152 @@
+use crate::components::chart::{
+    DARK_NEGATIVE, DARK_PALETTE, LIGHT_NEGATIVE, LIGHT_PALETTE, PRICE_THRESHOLDS,
+};
+use charming::{
+    Chart as CharmingChart,
+    component::{Axis, Grid, Title, VisualMap, VisualMapPiece, VisualMapType},
+    element::{AxisLabel, AxisType, Orient, TextStyle, Tooltip},
+    series::Heatmap,
+};
+use std::rc::Rc;
+use yew::prelude::*;
+
+use crate::hooks::use_echarts::use_echarts;
+use crate::models::palette::ChartTheme;
+use crate::models::rates::{Rates, SLOTS_PER_DAY};
+
+const CHART_ID: &str = "price-heatmap";
+
+/// Date labels plus `[slot, day, price]` cells; empty slots have no cell
+type HeatmapSeries = (Vec<String>, Vec<Vec<f64>>);
+
+#[derive(Properties, PartialEq)]
+pub struct PriceHeatmapProps {
+    pub rates: Rc<Rates>,
+    pub dark_mode: bool,
+}
+
+/// Day × half-hour grid of historical prices, colored by the price chart's bands
+#[function_component(PriceHeatmap)]
+pub fn price_heatmap(props: &PriceHeatmapProps) -> Html {
+    let container_ref = use_node_ref();
+    let series = use_memo(props.rates.clone(), |rates| {
+        let matrix = rates.to_daily_matrix();
+        let dates = matrix
+            .iter()
+            .map(|(date, _)| date.format("%a %d %b").to_string())
+            .collect();
+        let cells = matrix
+            .iter()
+            .enumerate()
+            .flat_map(|(day, (_, row))| {
+                row.iter().enumerate().filter_map(move |(slot, price)| {
+                    price.map(|price| vec![slot as f64, day as f64, price])
+                })
+            })
+            .collect();
+        (dates, cells)
+    });
+
+    use_echarts(
+        CHART_ID,
+        container_ref.clone(),
+        (series.clone(), props.dark_mode),
+        |(series, dark_mode)| (!series.1.is_empty()).then(|| build_chart(series, *dark_mode)),
+    );
+
+    html! {
+        <div class="chart-container heatmap-container" ref={container_ref}>
+            <div
+                id={CHART_ID}
+                role="img"
+                aria-label="Heatmap of half-hourly prices by day"
+            />
+            <div class="sr-only">
+                {format!("Half-hourly prices for the last {} days", series.0.len())}
+            </div>
+        </div>
+    }
+}
+
+/// `HH:MM` label for each half-hour column
+fn slot_labels() -> Vec<String> {
+    (0..SLOTS_PER_DAY)
+        .map(|slot| format!("{:02}:{:02}", slot / 2, slot % 2 * 30))
+        .collect()
+}
+
+/// One piece per price chart band: negative prices, then each `PRICE_THRESHOLDS` step
+fn band_pieces(dark_mode: bool) -> Vec<VisualMapPiece> {
+    let (palette, negative) = if dark_mode {
+        (DARK_PALETTE, DARK_NEGATIVE)
+    } else {
+        (LIGHT_PALETTE, LIGHT_NEGATIVE)
+    };
+
+    let lower_bounds = std::iter::once(0.0).chain(PRICE_THRESHOLDS);
+    let upper_bounds = PRICE_THRESHOLDS.map(Some).into_iter().chain([None]);
+
+    std::iter::once(VisualMapPiece::new().lt(0.0).color(negative))
+        .chain(
+            lower_bounds
+                .zip(upper_bounds)
+                .zip(palette)
+                .map(|((lower, upper), color)| {
+                    let piece = VisualMapPiece::new().gte(lower).color(color);
+                    match upper {
+                        Some(upper) => piece.lt(upper),
+                        None => piece,
+                    }
+                }),
+        )
+        .collect()
+}
+
+fn build_chart(series: &HeatmapSeries, dark_mode: bool) -> CharmingChart {
+    let (dates, cells) = series;
+
+    let ChartTheme {
+        background,
+        text: title_color,
+        axis: axis_color,
+        ..
+    } = ChartTheme::for_dark_mode(dark_mode);
+
+    CharmingChart::new()
+        .background_color(background)
+        .title(
+            Title::new()
+                .text("Price Heatmap")
+                .left("center")
+                .text_style(TextStyle::new().font_size(16).color(title_color)),
+        )
+        .tooltip(Tooltip::new().position("top"))
+        .grid(
+            Grid::new()
+                .left("8%")
+                .right("4%")
+                .bottom("22%")
+                .contain_label(true),
+        )
+        .x_axis(
+            Axis::new()
+                .type_(AxisType::Category)
+                .data(slot_labels())
+                .axis_label(AxisLabel::new().color(axis_color).interval(3)),
+        )
+        .y_axis(
+            Axis::new()
+                .type_(AxisType::Category)
+                .data(dates.clone())
+                .axis_label(AxisLabel::new().color(axis_color)),
+        )
+        .visual_map(
+            VisualMap::new()
+                .type_(VisualMapType::Piecewise)
+                .pieces(band_pieces(dark_mode))
+                .orient(Orient::Horizontal)
+                .left("center")
+                .bottom("2%"),
+        )
+        .series(Heatmap::new().name("Price").data(cells.clone()))
+}
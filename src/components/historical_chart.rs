@@ -0,0 +1,116 @@
+use charming::{
+    Chart as CharmingChart,
+    component::{Axis, Grid, Title},
+    element::{
+        AxisLabel, AxisType, LineStyle, LineStyleType, SplitLine, Symbol, TextStyle, Tooltip,
+        Trigger,
+    },
+    series::Line,
+};
+use std::rc::Rc;
+use yew::prelude::*;
+
+use crate::hooks::use_echarts::use_echarts;
+use crate::models::palette::ChartTheme;
+use crate::models::rates::Rates;
+
+const CHART_ID: &str = "historical-chart";
+
+/// Day labels and mean prices
+type DailySeries = (Vec<String>, Vec<f64>);
+
+#[derive(Properties, PartialEq)]
+pub struct HistoricalChartProps {
+    pub rates: Rc<Rates>,
+    pub dark_mode: bool,
+}
+
+/// Line chart of the mean Agile price per day across the historical window
+#[function_component(HistoricalChart)]
+pub fn historical_chart(props: &HistoricalChartProps) -> Html {
+    let container_ref = use_node_ref();
+    let series = use_memo(props.rates.clone(), |rates| {
+        rates
+            .daily_averages()
+            .into_iter()
+            .map(|(date, avg)| (date.format("%d %b").to_string(), avg))
+            .unzip::<_, _, Vec<_>, Vec<_>>()
+    });
+
+    use_echarts(
+        CHART_ID,
+        container_ref.clone(),
+        (series.clone(), props.dark_mode),
+        |(series, dark_mode)| (!series.1.is_empty()).then(|| build_chart(series, *dark_mode)),
+    );
+
+    html! {
+        <div class="chart-container" ref={container_ref}>
+            <div
+                id={CHART_ID}
+                role="img"
+                aria-label="Daily average Agile price over recent weeks"
+            />
+            <div class="sr-only">
+                {format!("Daily average prices over the last {} days", series.0.len())}
+            </div>
+        </div>
+    }
+}
+
+fn build_chart(series: &DailySeries, dark_mode: bool) -> CharmingChart {
+    let (x_data, y_data) = series;
+
+    let ChartTheme {
+        background,
+        text: title_color,
+        axis: axis_color,
+        grid: grid_color,
+        accent: line_color,
+        ..
+    } = ChartTheme::for_dark_mode(dark_mode);
+
+    CharmingChart::new()
+        .background_color(background)
+        .title(
+            Title::new()
+                .text("Daily Average Price")
+                .left("center")
+                .text_style(TextStyle::new().font_size(16).color(title_color)),
+        )
+        .tooltip(Tooltip::new().trigger(Trigger::Axis))
+        .grid(
+            Grid::new()
+                .left("8%")
+                .right("4%")
+                .bottom("18%")
+                .contain_label(true),
+        )
+        .x_axis(
+            Axis::new()
+                .type_(AxisType::Category)
+                .data(x_data.clone())
+                .axis_label(AxisLabel::new().rotate(45).color(axis_color)),
+        )
+        .y_axis(
+            Axis::new()
+                .type_(AxisType::Value)
+                .name("p/kWh")
+                .axis_label(AxisLabel::new().color(axis_color))
+                .split_line(
+                    SplitLine::new().line_style(
+                        LineStyle::new()
+                            .color(grid_color)
+                            .type_(LineStyleType::Dashed),
+                    ),
+                ),
+        )
+        .series(
+            Line::new()
+                .name("Daily average")
+                .data(y_data.clone())
+                .symbol(Symbol::Circle)
+                .symbol_size(5)
+                .line_style(LineStyle::new().color(line_color).width(2)),
+        )
+}
@@ -1,17 +1,87 @@
+pub mod band_breakdown;
 pub mod banner;
+pub mod best_time;
+pub mod block_picker;
+pub mod carbon_chart;
 pub mod carbon_display;
 pub mod chart;
+pub mod chart_type_toggle;
 pub mod cheapest_period;
+pub mod cost_calculator;
+pub mod custom_region_selector;
 pub mod day_summary;
+pub mod direction_toggle;
+pub mod error_notice;
+pub mod export_button;
+pub mod export_overlay_toggle;
+pub mod favourite_toggle;
+pub mod gap_banner;
+pub mod granularity_toggle;
+pub mod heatmap;
+pub mod historical_chart;
+pub mod offline_banner;
+pub mod palette_toggle;
+pub mod plunge_banner;
+pub mod postcode_lookup;
+pub mod price_alert_toggle;
+pub mod price_unit_toggle;
+pub mod product_selector;
+pub mod rates_import;
+pub mod rates_provider;
+pub mod records;
+pub mod refresh_indicator;
+pub mod refresh_settings;
+pub mod region_comparison;
 pub mod region_selector;
+pub mod savings_estimate;
+pub mod settings_provider;
+pub mod slot_countdown;
+pub mod slot_progress;
 pub mod status;
 pub mod summary;
 pub mod theme_toggle;
+pub mod threshold_settings;
 pub mod tracker_display;
+pub mod weekday_insight;
 
+pub use band_breakdown::BandBreakdown;
 pub use banner::TraceBanner;
+pub use best_time::BestTime;
+pub use block_picker::BlockPicker;
+pub use carbon_chart::CarbonChart;
 pub use carbon_display::CarbonDisplay;
+pub use chart_type_toggle::ChartTypeToggle;
 pub use cheapest_period::CheapestPeriod;
+pub use cost_calculator::CostCalculator;
+pub use custom_region_selector::CustomRegionSelector;
 pub use day_summary::DaySummary;
+pub use direction_toggle::{DirectionToggle, TariffDirection};
+pub use error_notice::ErrorNotice;
+pub use export_button::ExportButton;
+pub use export_overlay_toggle::ExportOverlayToggle;
+pub use favourite_toggle::FavouriteToggle;
+pub use gap_banner::GapBanner;
+pub use granularity_toggle::GranularityToggle;
+pub use heatmap::PriceHeatmap;
+pub use historical_chart::HistoricalChart;
+pub use offline_banner::OfflineBanner;
+pub use palette_toggle::PaletteToggle;
+pub use plunge_banner::PlungeBanner;
+pub use postcode_lookup::PostcodeLookup;
+pub use price_alert_toggle::PriceAlertToggle;
+pub use price_unit_toggle::PriceUnitToggle;
+pub use product_selector::ProductSelector;
+pub use rates_import::RatesImport;
+pub use rates_provider::RatesProvider;
+pub use records::Records;
+pub use refresh_indicator::RefreshIndicator;
+pub use refresh_settings::RefreshSettings;
+pub use region_comparison::RegionComparison;
 pub use region_selector::RegionSelector;
+pub use savings_estimate::SavingsEstimate;
+pub use settings_provider::SettingsProvider;
+pub use slot_countdown::SlotCountdown;
+pub use slot_progress::SlotProgress;
 pub use theme_toggle::ThemeToggle;
+pub use threshold_settings::ThresholdSettings;
+pub use weekday_insight::WeekdayInsight;
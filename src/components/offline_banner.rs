@@ -0,0 +1,34 @@
+use crate::hooks::use_online::use_online;
+use crate::utils::time::london_time;
+use chrono::{DateTime, Utc};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct OfflineBannerProps {
+    /// When the rates on screen were fetched, if any are
+    pub last_updated: Option<DateTime<Utc>>,
+    pub has_data: bool,
+}
+
+/// Notice shown while the browser is offline, saying how old the prices on screen are
+#[function_component(OfflineBanner)]
+pub fn offline_banner(props: &OfflineBannerProps) -> Html {
+    if use_online() {
+        return html! {};
+    }
+
+    let text = match (props.has_data, props.last_updated) {
+        (true, Some(at)) => format!(
+            "Offline — showing cached data from {}",
+            london_time(at).format("%H:%M")
+        ),
+        (true, None) => "Offline — showing cached data".to_string(),
+        (false, _) => "Offline — prices will load when you reconnect".to_string(),
+    };
+
+    html! {
+        <div class="offline-banner" role="status">
+            <p>{text}</p>
+        </div>
+    }
+}
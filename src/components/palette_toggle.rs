@@ -0,0 +1,39 @@
+use yew::prelude::*;
+
+use crate::models::palette::Palette;
+
+#[derive(Properties, PartialEq)]
+pub struct PaletteToggleProps {
+    pub palette: Palette,
+    pub on_change: Callback<Palette>,
+}
+
+/// Header button switching between the default and color-blind-safe palettes
+#[function_component(PaletteToggle)]
+pub fn palette_toggle(props: &PaletteToggleProps) -> Html {
+    let color_blind = props.palette == Palette::ColorBlindSafe;
+    let label = if color_blind {
+        "Use default colors"
+    } else {
+        "Use color-blind-safe colors"
+    };
+
+    let onclick = {
+        let palette = props.palette;
+        let callback = props.on_change.clone();
+        Callback::from(move |_| callback.emit(palette.toggled()))
+    };
+
+    html! {
+        <button
+            class="theme-toggle palette-toggle"
+            {onclick}
+            aria-label={label}
+            aria-pressed={if color_blind { "true" } else { "false" }}
+            title={label}
+        >
+            <span aria-hidden="true">{"👁️"}</span>
+            <span class="sr-only">{label}</span>
+        </button>
+    }
+}
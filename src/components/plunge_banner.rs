@@ -0,0 +1,74 @@
+use crate::models::rates::{Rate, Rates};
+use crate::utils::time::{format_slot_window, london_date, london_today};
+use chrono::{DateTime, Utc};
+use std::rc::Rc;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct PlungeBannerProps {
+    pub rates: Rc<Rates>,
+}
+
+/// A contiguous run of negative slots: start, end and lowest price
+type PlungeRun = (DateTime<Utc>, DateTime<Utc>, f64);
+
+/// Dismissible alert listing upcoming negative-price slots for today and tomorrow
+#[function_component(PlungeBanner)]
+pub fn plunge_banner(props: &PlungeBannerProps) -> Html {
+    let dismissed = use_state(|| false);
+    let runs = use_memo(props.rates.clone(), |rates| {
+        let now = Utc::now();
+        let tomorrow = london_today() + chrono::Duration::days(1);
+        group_runs(
+            rates
+                .negative_periods()
+                .into_iter()
+                .filter(|r| r.valid_to > now && london_date(r.valid_from) <= tomorrow),
+        )
+    });
+
+    if *dismissed || runs.is_empty() {
+        return html! {};
+    }
+
+    let on_dismiss = {
+        let dismissed = dismissed.clone();
+        Callback::from(move |_| dismissed.set(true))
+    };
+
+    let text = runs
+        .iter()
+        .map(|(from, to, min)| format!("{}, down to {min:.1}p", format_slot_window(*from, *to)))
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    html! {
+        <div class="plunge-banner" role="alert">
+            <p>
+                <strong>{"Paid to use power "}</strong>
+                {text}
+            </p>
+            <button
+                class="plunge-banner-dismiss"
+                onclick={on_dismiss}
+                aria-label="Dismiss negative price alert"
+            >
+                {"×"}
+            </button>
+        </div>
+    }
+}
+
+/// Merges back-to-back slots into runs
+fn group_runs<'a>(rates: impl Iterator<Item = &'a Rate>) -> Vec<PlungeRun> {
+    rates.fold(Vec::new(), |mut runs: Vec<PlungeRun>, rate| {
+        match runs.last_mut() {
+            Some((_, end, min)) if *end == rate.valid_from => {
+                *end = rate.valid_to;
+                *min = min.min(rate.value_inc_vat);
+            }
+            _ => runs.push((rate.valid_from, rate.valid_to, rate.value_inc_vat)),
+        }
+        runs
+    })
+}
@@ -0,0 +1,104 @@
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::models::error::AppError;
+use crate::services::api::{Region, lookup_region_for_postcode, normalize_postcode};
+
+#[derive(Properties, PartialEq)]
+pub struct PostcodeLookupProps {
+    pub on_change: Callback<Region>,
+}
+
+/// Outcome of the last lookup, shown under the input
+#[derive(Clone, PartialEq)]
+enum LookupStatus {
+    Idle,
+    Searching,
+    Found(Region),
+    Failed(&'static str),
+}
+
+/// Inline wording for a failed lookup; never surfaces the raw error
+const fn lookup_error_message(error: &AppError) -> &'static str {
+    match error {
+        AppError::ConfigError(_) => "Enter a full UK postcode, e.g. SW1A 1AA",
+        AppError::NotFound(_) => "We couldn't find that postcode",
+        AppError::DataError(_) => "That postcode isn't in a supported region",
+        _ => "Lookup failed, please try again",
+    }
+}
+
+/// Postcode box that finds the electricity region and selects it
+#[function_component(PostcodeLookup)]
+pub fn postcode_lookup(props: &PostcodeLookupProps) -> Html {
+    let postcode = use_state(String::new);
+    let status = use_state(|| LookupStatus::Idle);
+
+    let on_input = {
+        let postcode = postcode.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: HtmlInputElement = e.target_unchecked_into();
+            postcode.set(target.value());
+        })
+    };
+
+    let on_submit = {
+        let postcode = postcode.clone();
+        let status = status.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if normalize_postcode(&postcode).is_none() {
+                status.set(LookupStatus::Failed(lookup_error_message(
+                    &AppError::ConfigError(String::new()),
+                )));
+                return;
+            }
+
+            status.set(LookupStatus::Searching);
+            let postcode = (*postcode).clone();
+            let status = status.clone();
+            let on_change = on_change.clone();
+            spawn_local(async move {
+                match lookup_region_for_postcode(&postcode).await {
+                    Ok(region) => {
+                        status.set(LookupStatus::Found(region));
+                        on_change.emit(region);
+                    }
+                    Err(e) => status.set(LookupStatus::Failed(lookup_error_message(&e))),
+                }
+            });
+        })
+    };
+
+    html! {
+        <form class="postcode-lookup" onsubmit={on_submit}>
+            <label>
+                {"Postcode "}
+                <input
+                    type="text"
+                    value={(*postcode).clone()}
+                    oninput={on_input}
+                    placeholder="SW1A 1AA"
+                    maxlength="8"
+                    autocomplete="postal-code"
+                    aria-label="Postcode to find your electricity region"
+                />
+            </label>
+            <button type="submit" disabled={*status == LookupStatus::Searching}>
+                {"Find region"}
+            </button>
+            {
+                match &*status {
+                    LookupStatus::Idle => html! {},
+                    LookupStatus::Searching => html! { <span>{"Searching..."}</span> },
+                    LookupStatus::Found(region) => html! { <span>{format!("Found {region}")}</span> },
+                    LookupStatus::Failed(message) => html! {
+                        <span class="error" role="alert">{*message}</span>
+                    },
+                }
+            }
+        </form>
+    }
+}
@@ -0,0 +1,103 @@
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, NotificationPermission};
+use yew::prelude::*;
+
+use crate::config::Config;
+use crate::services::notifications;
+
+#[derive(Properties, PartialEq)]
+pub struct PriceAlertToggleProps {
+    /// Alert threshold in pence, or `None` when alerts are off
+    pub threshold: Option<f64>,
+    pub on_change: Callback<Option<f64>>,
+}
+
+/// Bell button turning price-drop notifications on and off, with the threshold beside it.
+///
+/// Turning alerts on asks for notification permission first; if it is refused the bell
+/// stays off and says notifications are blocked.
+#[function_component(PriceAlertToggle)]
+pub fn price_alert_toggle(props: &PriceAlertToggleProps) -> Html {
+    let permission = use_state(notifications::permission);
+
+    if !notifications::supported() {
+        return html! {};
+    }
+
+    let enabled = props.threshold.is_some() && *permission == NotificationPermission::Granted;
+    let blocked = *permission == NotificationPermission::Denied;
+    let label = if blocked {
+        "Price alerts are blocked in browser settings"
+    } else if enabled {
+        "Turn off price alerts"
+    } else {
+        "Turn on price alerts"
+    };
+
+    let onclick = {
+        let callback = props.on_change.clone();
+        let permission = permission.clone();
+        Callback::from(move |_| {
+            if enabled {
+                callback.emit(None);
+                return;
+            }
+            let callback = callback.clone();
+            let permission = permission.clone();
+            spawn_local(async move {
+                let granted = notifications::request_permission().await;
+                permission.set(granted);
+                if granted == NotificationPermission::Granted {
+                    callback.emit(Some(Config::CHEAP_SLOT_ALERT_P));
+                }
+            });
+        })
+    };
+
+    let oninput = {
+        let callback = props.on_change.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: HtmlInputElement = e.target_unchecked_into();
+            // Ignore half-typed or cleared input rather than turning alerts off
+            if let Some(value) = target
+                .value()
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|v| v.is_finite())
+            {
+                callback.emit(Some(value));
+            }
+        })
+    };
+
+    html! {
+        <div class="price-alert-toggle">
+            <button
+                class={classes!("price-alert-bell", enabled.then_some("active"))}
+                {onclick}
+                disabled={blocked}
+                aria-pressed={if enabled { "true" } else { "false" }}
+                aria-label={label}
+                title={label}
+            >
+                <span aria-hidden="true">{if enabled { "🔔" } else { "🔕" }}</span>
+            </button>
+            if enabled {
+                <label>
+                    {"Alert below "}
+                    <input
+                        type="number"
+                        step="0.5"
+                        value={props.threshold.map(|v| v.to_string()).unwrap_or_default()}
+                        {oninput}
+                        aria-label="Price alert threshold in pence per kWh"
+                    />
+                    {"p"}
+                </label>
+            } else if blocked {
+                <span class="price-alert-status">{"Notifications blocked"}</span>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,37 @@
+use yew::prelude::*;
+
+use crate::models::price_unit::PriceUnit;
+
+#[derive(Properties, PartialEq)]
+pub struct PriceUnitToggleProps {
+    pub unit: PriceUnit,
+    pub on_change: Callback<PriceUnit>,
+}
+
+/// Two-button switch between pence and pounds
+#[function_component(PriceUnitToggle)]
+pub fn price_unit_toggle(props: &PriceUnitToggleProps) -> Html {
+    let button = |unit: PriceUnit| {
+        let pressed = unit == props.unit;
+        let onclick = {
+            let callback = props.on_change.clone();
+            Callback::from(move |_| callback.emit(unit))
+        };
+        html! {
+            <button
+                class={classes!("unit-option", pressed.then_some("active"))}
+                {onclick}
+                aria-pressed={if pressed { "true" } else { "false" }}
+            >
+                {unit.label()}
+            </button>
+        }
+    };
+
+    html! {
+        <div class="unit-toggle" role="group" aria-label="Show prices in pence or pounds">
+            {button(PriceUnit::Pence)}
+            {button(PriceUnit::Pounds)}
+        </div>
+    }
+}
@@ -0,0 +1,40 @@
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+use crate::services::api::Product;
+
+#[derive(Properties, PartialEq)]
+pub struct ProductSelectorProps {
+    pub product: Product,
+    pub on_change: Callback<Product>,
+}
+
+/// Agile product version selector dropdown component
+#[function_component(ProductSelector)]
+pub fn product_selector(props: &ProductSelectorProps) -> Html {
+    let on_change = {
+        let callback = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let target: HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(product) = target.value().parse::<Product>() {
+                callback.emit(product);
+            }
+        })
+    };
+
+    html! {
+        <label class="product-selector">
+            {"Tariff "}
+            <select onchange={on_change} aria-label="Select Agile tariff version">
+                {
+                    Product::all().iter().map(|p| {
+                        let selected = *p == props.product;
+                        html! {
+                            <option value={p.code()} {selected}>{p.description()}</option>
+                        }
+                    }).collect::<Html>()
+                }
+            </select>
+        </label>
+    }
+}
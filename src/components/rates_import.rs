@@ -0,0 +1,55 @@
+use crate::models::rates::Rates;
+use web_sys::HtmlTextAreaElement;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct RatesImportProps {
+    pub on_import: Callback<Rates>,
+}
+
+/// Collapsible text area for loading pasted rates JSON (an export or a raw API response)
+#[function_component(RatesImport)]
+pub fn rates_import(props: &RatesImportProps) -> Html {
+    let text = use_state(String::new);
+    let error = use_state(|| None::<String>);
+
+    let on_input = {
+        let text = text.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: HtmlTextAreaElement = e.target_unchecked_into();
+            text.set(target.value());
+        })
+    };
+
+    let on_load = {
+        let text = text.clone();
+        let error = error.clone();
+        let on_import = props.on_import.clone();
+        Callback::from(move |_| match Rates::from_json(&text) {
+            Ok(rates) => {
+                error.set(None);
+                on_import.emit(rates);
+            }
+            Err(e) => error.set(Some(e.to_string())),
+        })
+    };
+
+    html! {
+        <details class="rates-import">
+            <summary>{"Import rates JSON"}</summary>
+            <textarea
+                class="rates-import-input"
+                value={(*text).clone()}
+                oninput={on_input}
+                rows="6"
+                aria-label="Rates JSON"
+            />
+            <button class="rates-import-load" onclick={on_load} disabled={text.trim().is_empty()}>
+                {"Load"}
+            </button>
+            if let Some(err) = &*error {
+                <p class="error">{err}</p>
+            }
+        </details>
+    }
+}
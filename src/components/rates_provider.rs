@@ -0,0 +1,27 @@
+use yew::prelude::*;
+
+use crate::hooks::use_product::use_product;
+use crate::hooks::use_rates::{RatesContext, use_rates};
+use crate::hooks::use_region::use_region;
+
+#[derive(Properties, PartialEq)]
+pub struct RatesProviderProps {
+    pub interval_ms: u32,
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Owns the one `use_rates` subscription and the region and product selections, so every
+/// consumer of `use_rates_context` shares a single request per poll
+#[function_component(RatesProvider)]
+pub fn rates_provider(props: &RatesProviderProps) -> Html {
+    let region = use_region();
+    let product = use_product();
+    let rates = use_rates(region.region, product.product, props.interval_ms);
+
+    html! {
+        <ContextProvider<RatesContext> context={RatesContext { rates, region, product }}>
+            {props.children.clone()}
+        </ContextProvider<RatesContext>>
+    }
+}
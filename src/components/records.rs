@@ -0,0 +1,68 @@
+use crate::config::Config;
+use crate::models::rates::{Rate, Rates};
+use crate::utils::time::{london_time, london_today};
+use chrono::Datelike;
+use std::rc::Rc;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct RecordsProps {
+    pub historical: Rc<Rates>,
+}
+
+/// Lowest and highest prices over the fetched history (`Config::HISTORY_DAYS` days) and
+/// within the current month
+#[function_component(Records)]
+pub fn records(props: &RecordsProps) -> Html {
+    let records = use_memo(props.historical.clone(), |rates| {
+        let today = london_today();
+        let month = rates
+            .records_by_month()
+            .remove(&(today.year(), today.month()));
+        let all_time = rates
+            .record_low()
+            .cloned()
+            .zip(rates.record_high().cloned());
+        (all_time, month)
+    });
+
+    let (all_time, month) = &*records;
+    let Some((low, high)) = all_time else {
+        return html! {};
+    };
+
+    html! {
+        <div class="day-summary-card records">
+            <h2>{"Price Records"}</h2>
+            <div class="summary-grid">
+                <div class="summary-item">
+                    <h3>{format!("{}-Day Low", Config::HISTORY_DAYS)}</h3>
+                    <p class="summary-value">{record_label(low)}</p>
+                </div>
+                <div class="summary-item">
+                    <h3>{format!("{}-Day High", Config::HISTORY_DAYS)}</h3>
+                    <p class="summary-value">{record_label(high)}</p>
+                </div>
+                if let Some(month) = month {
+                    <div class="summary-item">
+                        <h3>{"Lowest This Month"}</h3>
+                        <p class="summary-value">{record_label(&month.low)}</p>
+                    </div>
+                    <div class="summary-item">
+                        <h3>{"Highest This Month"}</h3>
+                        <p class="summary-value">{record_label(&month.high)}</p>
+                    </div>
+                }
+            </div>
+        </div>
+    }
+}
+
+/// Price and London date of a slot, e.g. "-4.30p on 2 Mar"
+fn record_label(rate: &Rate) -> String {
+    format!(
+        "{:.2}p on {}",
+        rate.value_inc_vat,
+        london_time(rate.valid_from).format("%-d %b")
+    )
+}
@@ -0,0 +1,20 @@
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct RefreshIndicatorProps {
+    pub active: bool,
+}
+
+/// Small corner spinner shown on a panel while its data re-polls in the background
+#[function_component(RefreshIndicator)]
+pub fn refresh_indicator(props: &RefreshIndicatorProps) -> Html {
+    if !props.active {
+        return html! {};
+    }
+
+    html! {
+        <div class="refresh-indicator" role="status" aria-label="Refreshing data" title="Refreshing data">
+            <div class="spinner" aria-hidden="true"></div>
+        </div>
+    }
+}
@@ -0,0 +1,46 @@
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+/// Polling presets offered to the user: (interval in ms, label)
+const PRESETS: [(u32, &str); 4] = [
+    (120_000, "Every 2 minutes"),
+    (600_000, "Every 10 minutes"),
+    (1_800_000, "Every 30 minutes"),
+    (3_600_000, "Every hour"),
+];
+
+#[derive(Properties, PartialEq)]
+pub struct RefreshSettingsProps {
+    pub interval_ms: u32,
+    pub on_change: Callback<u32>,
+}
+
+/// Dropdown for choosing how often data is re-polled
+#[function_component(RefreshSettings)]
+pub fn refresh_settings(props: &RefreshSettingsProps) -> Html {
+    let on_change = {
+        let callback = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let target: HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(interval_ms) = target.value().parse::<u32>() {
+                callback.emit(interval_ms);
+            }
+        })
+    };
+
+    html! {
+        <label class="refresh-settings">
+            {"Auto-refresh "}
+            <select onchange={on_change} aria-label="Select refresh interval">
+                {
+                    PRESETS.iter().map(|(ms, label)| {
+                        let selected = *ms == props.interval_ms;
+                        html! {
+                            <option value={ms.to_string()} {selected}>{*label}</option>
+                        }
+                    }).collect::<Html>()
+                }
+            </select>
+        </label>
+    }
+}
@@ -0,0 +1,127 @@
+use crate::components::ErrorNotice;
+use crate::hooks::use_region_prices::use_region_prices;
+use crate::services::api::{Product, Region};
+use yew::prelude::*;
+
+/// Column the comparison table is ordered by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortKey {
+    Region,
+    Price,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct RegionComparisonProps {
+    pub interval_ms: u32,
+    /// Agile product version to price every region on
+    pub product: Product,
+    /// Highlighted as the user's own region
+    pub selected: Region,
+}
+
+/// Table of the current Agile price in every region, sortable by clicking a header
+#[function_component(RegionComparison)]
+pub fn region_comparison(props: &RegionComparisonProps) -> Html {
+    let state = use_region_prices(props.product, props.interval_ms);
+    let sort = use_state(|| (SortKey::Price, true));
+
+    let header = |key: SortKey, label: &str| {
+        let (current, ascending) = *sort;
+        let onclick = {
+            let sort = sort.clone();
+            // Clicking the active column flips its direction
+            Callback::from(move |_| sort.set((key, !(current == key && ascending))))
+        };
+        let aria_sort = match (current == key, ascending) {
+            (false, _) => "none",
+            (true, true) => "ascending",
+            (true, false) => "descending",
+        };
+        html! {
+            <th aria-sort={aria_sort}>
+                <button class="sort-header" {onclick}>{label}</button>
+            </th>
+        }
+    };
+
+    let body = match state.data() {
+        Some(prices) => {
+            let (key, ascending) = *sort;
+            let mut rows = (**prices).clone();
+            sort_prices(&mut rows, key, ascending);
+            rows.into_iter()
+                .map(|(region, price)| {
+                    let class = (region == props.selected).then_some("selected-region");
+                    html! {
+                        <tr {class}>
+                            <td>{format!("{} ({})", region.description(), region.code())}</td>
+                            <td>{format!("{price:.2}p")}</td>
+                        </tr>
+                    }
+                })
+                .collect::<Html>()
+        }
+        None => html! {},
+    };
+
+    html! {
+        <div class="region-comparison">
+            if let crate::hooks::use_polled_resource::ResourceState::Error(err) = &*state {
+                <ErrorNotice error={err.clone()} context="Error loading regional prices" />
+            } else if state.data().is_none() {
+                <p>{"Loading regional prices..."}</p>
+            } else {
+                <table>
+                    <thead>
+                        <tr>
+                            {header(SortKey::Region, "Region")}
+                            {header(SortKey::Price, "Price now")}
+                        </tr>
+                    </thead>
+                    <tbody>{body}</tbody>
+                </table>
+            }
+        </div>
+    }
+}
+
+/// Orders rows by `key`; equal prices fall back to region order
+fn sort_prices(rows: &mut [(Region, f64)], key: SortKey, ascending: bool) {
+    let position = |region: &Region| Region::all().iter().position(|r| r == region);
+    rows.sort_by(|a, b| {
+        let ordering = match key {
+            SortKey::Region => position(&a.0).cmp(&position(&b.0)),
+            SortKey::Price => {
+                a.1.total_cmp(&b.1)
+                    .then(position(&a.0).cmp(&position(&b.0)))
+            }
+        };
+        if ascending {
+            ordering
+        } else {
+            ordering.reverse()
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_prices_by_price_and_region() {
+        let mut rows = vec![(Region::C, 20.0), (Region::A, 15.0), (Region::B, 20.0)];
+
+        sort_prices(&mut rows, SortKey::Price, true);
+        assert_eq!(
+            rows,
+            vec![(Region::A, 15.0), (Region::B, 20.0), (Region::C, 20.0)]
+        );
+
+        sort_prices(&mut rows, SortKey::Region, false);
+        assert_eq!(
+            rows,
+            vec![(Region::C, 20.0), (Region::B, 20.0), (Region::A, 15.0)]
+        );
+    }
+}
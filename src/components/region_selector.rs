@@ -1,12 +1,16 @@
 use web_sys::HtmlSelectElement;
 use yew::prelude::*;
 
+use crate::hooks::use_favourite_regions::favourites_first;
 use crate::services::api::Region;
 
 #[derive(Properties, PartialEq)]
 pub struct RegionSelectorProps {
     pub region: Region,
     pub on_change: Callback<Region>,
+    /// Listed first, in their own group
+    #[prop_or_default]
+    pub favourites: Vec<Region>,
 }
 
 /// Region selector dropdown component
@@ -23,6 +27,17 @@ pub fn region_selector(props: &RegionSelectorProps) -> Html {
         })
     };
 
+    let option = |r: &Region| {
+        let code = r.code();
+        let label = format!("{} ({})", r.description(), code);
+        let selected = *r == props.region;
+        html! {
+            <option value={code} {selected}>{label}</option>
+        }
+    };
+    let (regions, favourite_count) = favourites_first(&props.favourites);
+    let (favourites, rest) = regions.split_at(favourite_count);
+
     html! {
         <select
             class="region-selector"
@@ -30,15 +45,15 @@ pub fn region_selector(props: &RegionSelectorProps) -> Html {
             aria-label="Select electricity region"
             title="Select electricity region"
         >
-            {
-                Region::all().iter().map(|r| {
-                    let code = r.code();
-                    let label = format!("{} ({})", r.description(), code);
-                    let selected = *r == props.region;
-                    html! {
-                        <option value={code} {selected}>{label}</option>
-                    }
-                }).collect::<Html>()
+            if favourites.is_empty() {
+                {rest.iter().map(option).collect::<Html>()}
+            } else {
+                <optgroup label="Favourites">
+                    {favourites.iter().map(option).collect::<Html>()}
+                </optgroup>
+                <optgroup label="All regions">
+                    {rest.iter().map(option).collect::<Html>()}
+                </optgroup>
             }
         </select>
     }
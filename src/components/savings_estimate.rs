@@ -0,0 +1,101 @@
+use crate::config::Config;
+use crate::models::rates::Rates;
+use std::rc::Rc;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct SavingsEstimateProps {
+    pub rates: Rc<Rates>,
+    /// Daily standing charge in pence, included in today's estimated cost
+    #[prop_or_default]
+    pub standing_charge: Option<f64>,
+}
+
+/// How much today on Agile saves, or costs, against a flat-rate tariff
+#[function_component(SavingsEstimate)]
+pub fn savings_estimate(props: &SavingsEstimateProps) -> Html {
+    let flat_rate = use_state(|| Config::DEFAULT_FLAT_RATE_P);
+    let daily_kwh = use_state(|| Config::DEFAULT_DAILY_KWH);
+    let savings = use_memo(
+        (props.rates.clone(), *flat_rate, *daily_kwh),
+        |(rates, flat_rate, daily_kwh)| rates.savings_vs_flat(*flat_rate, *daily_kwh),
+    );
+    let daily_cost = use_memo(
+        (props.rates.clone(), props.standing_charge, *daily_kwh),
+        |(rates, standing, daily_kwh)| {
+            standing.and_then(|standing| rates.daily_cost(*daily_kwh, standing).ok())
+        },
+    );
+
+    // Half-typed or negative input leaves the last good value in place
+    let on_input = |state: &UseStateHandle<f64>| {
+        let state = state.clone();
+        Callback::from(move |e: InputEvent| {
+            let target: HtmlInputElement = e.target_unchecked_into();
+            if let Some(value) = target
+                .value()
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|v| v.is_finite() && *v >= 0.0)
+            {
+                state.set(value);
+            }
+        })
+    };
+
+    html! {
+        <div class="savings-estimate">
+            <h3>{"Agile vs Flat Rate Today"}</h3>
+            {
+                match &*savings {
+                    Ok(pence) => html! {
+                        <p class={classes!(
+                            "savings-value",
+                            if *pence >= 0.0 { "savings-positive" } else { "savings-negative" }
+                        )}>
+                            {format!(
+                                "{} £{:.2}",
+                                if *pence >= 0.0 { "Saving" } else { "Costing an extra" },
+                                pence.abs() / 100.0
+                            )}
+                        </p>
+                    },
+                    Err(e) => html! { <p class="savings-value">{e.to_string()}</p> },
+                }
+            }
+            if let Some(pence) = *daily_cost {
+                <p class="savings-daily-cost">
+                    {format!("Estimated cost today £{:.2} incl. standing charge", pence / 100.0)}
+                </p>
+            }
+            <div class="savings-inputs">
+                <label>
+                    {"Flat rate "}
+                    <input
+                        type="number"
+                        min="0"
+                        step="0.1"
+                        value={flat_rate.to_string()}
+                        oninput={on_input(&flat_rate)}
+                        aria-label="Flat tariff unit rate in pence per kWh"
+                    />
+                    {"p"}
+                </label>
+                <label>
+                    {"Usage "}
+                    <input
+                        type="number"
+                        min="0"
+                        step="0.5"
+                        value={daily_kwh.to_string()}
+                        oninput={on_input(&daily_kwh)}
+                        aria-label="Daily usage in kWh"
+                    />
+                    {"kWh/day"}
+                </label>
+            </div>
+        </div>
+    }
+}
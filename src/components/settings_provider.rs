@@ -0,0 +1,22 @@
+use yew::prelude::*;
+
+use crate::hooks::use_settings::{SettingsHandle, SettingsState};
+
+#[derive(Properties, PartialEq)]
+pub struct SettingsProviderProps {
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Loads the stored settings once and shares them, so every preference hook beneath it
+/// reads and updates the same copy
+#[function_component(SettingsProvider)]
+pub fn settings_provider(props: &SettingsProviderProps) -> Html {
+    let state = use_reducer(SettingsState::load);
+
+    html! {
+        <ContextProvider<SettingsHandle> context={SettingsHandle::new(state)}>
+            {props.children.clone()}
+        </ContextProvider<SettingsHandle>>
+    }
+}
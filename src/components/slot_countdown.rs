@@ -0,0 +1,37 @@
+use crate::hooks::use_countdown::use_countdown;
+use crate::models::rates::Rates;
+use crate::utils::time::format_countdown;
+use chrono::{Duration, Utc};
+use std::rc::Rc;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct SlotCountdownProps {
+    pub rates: Rc<Rates>,
+}
+
+/// Live "MM:SS until next price" countdown to the end of the current slot.
+/// The slot is looked up on every tick, so it rolls over without a refetch.
+#[function_component(SlotCountdown)]
+pub fn slot_countdown(props: &SlotCountdownProps) -> Html {
+    let now = Utc::now();
+    let left = props.rates.time_until_next_change(now);
+    let remaining = use_countdown(now + left.unwrap_or_else(Duration::zero));
+
+    if left.is_none() {
+        return html! {};
+    }
+
+    let next_price = props
+        .rates
+        .next_rate(now)
+        .map(|rate| format!(" ({:.2}p)", rate.value_inc_vat));
+
+    html! {
+        <p class="slot-countdown" aria-live="off">
+            <span class="slot-countdown-time">{format_countdown(remaining)}</span>
+            {" until next price"}
+            {next_price.unwrap_or_default()}
+        </p>
+    }
+}
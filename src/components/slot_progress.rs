@@ -0,0 +1,91 @@
+use crate::models::rates::Rate;
+use chrono::{DateTime, Utc};
+use gloo_timers::callback::Interval;
+use yew::prelude::*;
+
+/// How often the bar advances
+const TICK_INTERVAL_MS: u32 = 15_000;
+
+#[derive(Properties, PartialEq)]
+pub struct SlotProgressProps {
+    /// The slot in effect now; nothing is shown without one
+    pub rate: Option<Rate>,
+}
+
+/// Thin bar showing how far through the current half-hour slot we are
+#[function_component(SlotProgress)]
+pub fn slot_progress(props: &SlotProgressProps) -> Html {
+    let now = use_state(Utc::now);
+
+    {
+        let now = now.setter();
+        // Restart on a new slot so the bar resets straight away rather than on the next tick
+        let slot_start = props.rate.as_ref().map(|r| r.valid_from);
+        use_effect_with(slot_start, move |_| {
+            now.set(Utc::now());
+            let interval = Interval::new(TICK_INTERVAL_MS, move || now.set(Utc::now()));
+            move || drop(interval)
+        });
+    }
+
+    let Some(rate) = &props.rate else {
+        return html! {};
+    };
+    let pct = progress_pct(rate.valid_from, rate.valid_to, *now);
+
+    html! {
+        <div
+            class="slot-progress"
+            role="progressbar"
+            aria-label="Progress through the current slot"
+            aria-valuemin="0"
+            aria-valuemax="100"
+            aria-valuenow={format!("{pct:.0}")}
+        >
+            <div class="slot-progress-fill" style={format!("width: {pct:.1}%")}></div>
+        </div>
+    }
+}
+
+/// Percentage of the slot from `from` to `to` elapsed at `now`, clamped to 0–100
+#[allow(clippy::cast_precision_loss)] // slot lengths in milliseconds are far below 2^52
+fn progress_pct(from: DateTime<Utc>, to: DateTime<Utc>, now: DateTime<Utc>) -> f64 {
+    let length = (to - from).num_milliseconds();
+    if length <= 0 {
+        return 100.0;
+    }
+    let elapsed = (now - from).num_milliseconds() as f64;
+    (elapsed / length as f64 * 100.0).clamp(0.0, 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn slot() -> (DateTime<Utc>, DateTime<Utc>) {
+        let from = Utc.with_ymd_and_hms(2026, 1, 12, 14, 0, 0).unwrap();
+        (from, from + chrono::Duration::minutes(30))
+    }
+
+    #[test]
+    fn test_progress_through_slot() {
+        let (from, to) = slot();
+
+        assert!(progress_pct(from, to, from).abs() < f64::EPSILON);
+        assert!((progress_pct(from, to, from + chrono::Duration::minutes(15)) - 50.0).abs() < 1e-9);
+        assert!((progress_pct(from, to, from + chrono::Duration::seconds(90)) - 5.0).abs() < 1e-9);
+        assert!((progress_pct(from, to, to) - 100.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_progress_clamps_outside_slot() {
+        let (from, to) = slot();
+
+        assert!(progress_pct(from, to, from - chrono::Duration::minutes(5)).abs() < f64::EPSILON);
+        assert!(
+            (progress_pct(from, to, to + chrono::Duration::hours(1)) - 100.0).abs() < f64::EPSILON
+        );
+        assert!((progress_pct(to, from, from) - 100.0).abs() < f64::EPSILON);
+    }
+}
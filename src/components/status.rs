@@ -1,29 +1,61 @@
-use crate::hooks::use_rates::DataState;
+use crate::components::ErrorNotice;
+use crate::hooks::use_rates::{DataState, use_rates_context};
+use crate::utils::time::format_updated_ago;
+use chrono::Utc;
 use yew::prelude::*;
 
-#[derive(Properties, PartialEq)]
-pub struct StatusProps {
-    pub state: DataState,
-}
-
+/// Load state of the shared rates subscription, with a manual refresh button
 #[function_component(Status)]
-pub fn status(props: &StatusProps) -> Html {
-    match &props.state {
+pub fn status() -> Html {
+    let rates = use_rates_context().rates;
+    let state = &*rates.state;
+    let on_refresh = rates.refresh.clone();
+    let on_retry = rates.refresh.clone();
+
+    let controls = html! {
+        <div class="status-controls">
+            if let Some(updated) = rates.last_updated {
+                <span class="status-updated">{format_updated_ago(updated, Utc::now())}</span>
+            }
+            <button
+                class="status-refresh"
+                onclick={move |_| on_refresh.emit(())}
+                disabled={matches!(state, DataState::Loading | DataState::Refreshing(_))}
+            >
+                {"Refresh"}
+            </button>
+        </div>
+    };
+
+    let status = match state {
         DataState::Loading => html! {
             <div class="status loading" role="status" aria-live="polite" aria-label="Loading data">
                 <div class="spinner" aria-hidden="true"></div>
                 <p>{"Loading data..."}</p>
             </div>
         },
+        DataState::Refreshing(_) => html! {
+            <div class="status loading" role="status" aria-live="polite" aria-label="Refreshing data">
+                <div class="spinner" aria-hidden="true"></div>
+                <p>{"Refreshing data..."}</p>
+            </div>
+        },
         DataState::Loaded(_) => html! {
             <div class="status success" role="status" aria-live="polite">
                 <p>{"✅ Data loaded successfully"}</p>
             </div>
         },
-        DataState::Error(msg) => html! {
+        DataState::Error(err) => html! {
             <div class="status error" role="alert" aria-live="assertive">
-                <p>{"❌ Error: "}{msg}</p>
+                <ErrorNotice error={err.clone()} context="❌ Error" {on_retry} />
             </div>
         },
+    };
+
+    html! {
+        <>
+            {status}
+            {controls}
+        </>
     }
 }
@@ -1,16 +1,60 @@
-use crate::components::DaySummary;
-use crate::models::rates::Rates;
+use crate::components::{BandBreakdown, DaySummary, TariffDirection};
+use crate::config::Config;
+use crate::hooks::use_cheap_slot_alert::use_cheap_slot_alert;
+use crate::hooks::use_current_slot::use_current_slot;
+use crate::models::price_unit::PriceUnit;
+use crate::models::rates::{Rates, TomorrowStatus, default_bands};
+use crate::utils::time::{london_date, london_time};
+use chrono::{DateTime, Duration, Utc};
 use std::rc::Rc;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct SummaryProps {
     pub rates: Rc<Rates>,
+    /// Daily standing charge in pence, once loaded
+    #[prop_or_default]
+    pub standing_charge: Option<f64>,
+    /// Agile Outgoing price for the current slot, shown on today's card when set
+    #[prop_or_default]
+    pub export_price: Option<f64>,
+    /// When the rates will next be re-polled, for the awaiting-tomorrow message
+    #[prop_or_default]
+    pub next_check: Option<DateTime<Utc>>,
+    /// Price (pence) below which a slot about to start raises a browser notification;
+    /// shares the price-alert bell's threshold, and `None` turns it off
+    #[prop_or_default]
+    pub cheap_alert_threshold: Option<f64>,
+    #[prop_or_default]
+    pub unit: PriceUnit,
+    /// Whether `rates` are import or export prices
+    #[prop_or_default]
+    pub direction: TariffDirection,
 }
 
 #[function_component(Summary)]
 pub fn summary(props: &SummaryProps) -> Html {
-    let daily_stats = use_memo(props.rates.clone(), |rates| rates.daily_stats());
+    let slot = use_current_slot(props.rates.clone());
+    let today = london_date(slot.slot_start);
+    let daily_stats = use_memo((props.rates.clone(), today), |(rates, _)| {
+        rates.daily_stats()
+    });
+    let upcoming_avg = use_memo((props.rates.clone(), slot.slot_start), |(rates, now)| {
+        let from = rates.rate_at(*now).map_or(*now, |r| r.valid_from);
+        rates.avg_over(from, Duration::hours(Config::UPCOMING_AVG_HOURS))
+    });
+    let peak_split = *use_memo(props.rates.clone(), |rates| {
+        rates.peak_offpeak_averages(Config::PEAK_START_HOUR, Config::PEAK_END_HOUR)
+    });
+    // Keyed on the London date so the bands move on to the new day after midnight
+    let bands = use_memo((props.rates.clone(), today), |(rates, _)| {
+        rates.banded_stats(&default_bands())
+    });
+    // Keyed on the slot too so "pending" turns "late" without waiting for a new poll
+    let tomorrow_status = use_memo((props.rates.clone(), slot.slot_start), |(rates, _)| {
+        rates.tomorrow_status(Utc::now(), Config::TOMORROW_LATE_HOUR)
+    });
+    use_cheap_slot_alert(props.rates.clone(), props.cheap_alert_threshold);
 
     match &*daily_stats {
         Ok(stats) => html! {
@@ -19,12 +63,24 @@ pub fn summary(props: &SummaryProps) -> Html {
                 <DaySummary
                     stats={stats.today.clone()}
                     title={"Today's Statistics"}
-                    current_price={Some(stats.current)}
-                    next_price={Some(stats.next)}
+                    current_price={slot.current_price}
+                    current_window={slot.current_window.clone()}
+                    current_rate={slot.current_rate.clone()}
+                    next_price={slot.next_price}
+                    current_percentile={slot.current_price.map(|p| props.rates.percentile_on(today, p))}
+                    current_vs_avg_pct={slot.current_price.map(|p| stats.today.vs_avg_pct(p))}
+                    upcoming_avg={*upcoming_avg}
+                    peak_avg={peak_split.0}
+                    off_peak_avg={peak_split.1}
+                    export_price={props.export_price}
+                    standing_charge={props.standing_charge}
                     is_tomorrow={false}
+                    unit={props.unit}
+                    direction={props.direction}
                 />
+                <BandBreakdown stats={(*bands).clone()} unit={props.unit} />
 
-                // Tomorrow's card (conditional)
+                // Tomorrow's card, or why it is missing
                 if let Some(tomorrow) = &stats.tomorrow {
                     <DaySummary
                         stats={tomorrow.clone()}
@@ -32,7 +88,11 @@ pub fn summary(props: &SummaryProps) -> Html {
                         current_price={None}
                         next_price={None}
                         is_tomorrow={true}
+                        unit={props.unit}
+                        direction={props.direction}
                     />
+                } else {
+                    {tomorrow_placeholder(*tomorrow_status, props.next_check)}
                 }
             </div>
         },
@@ -43,3 +103,31 @@ pub fn summary(props: &SummaryProps) -> Html {
         },
     }
 }
+
+/// Stand-in for tomorrow's card before its prices are published
+fn tomorrow_placeholder(status: TomorrowStatus, next_check: Option<DateTime<Utc>>) -> Html {
+    let publish = format!(
+        "Tomorrow's prices usually publish around {:02}:00",
+        Config::TOMORROW_PUBLISH_HOUR
+    );
+    match status {
+        TomorrowStatus::Available => html! {},
+        TomorrowStatus::Pending => html! {
+            <div class="day-summary-card tomorrow tomorrow-pending">
+                <h2>{"Tomorrow's Statistics"}</h2>
+                <p>
+                    {match next_check {
+                        Some(at) => format!("{publish}, checking again at {}", london_time(at).format("%H:%M")),
+                        None => publish,
+                    }}
+                </p>
+            </div>
+        },
+        TomorrowStatus::Late => html! {
+            <div class="day-summary-card tomorrow tomorrow-late" role="status">
+                <h2>{"Tomorrow's Statistics"}</h2>
+                <p>{format!("{publish} but haven't appeared yet; they may be delayed")}</p>
+            </div>
+        },
+    }
+}
@@ -1,6 +1,7 @@
 use yew::prelude::*;
 
-use crate::hooks::use_theme::{Theme, use_theme};
+use crate::hooks::use_theme::use_theme;
+use crate::models::theme::Theme;
 
 /// Theme toggle button component
 #[function_component(ThemeToggle)]
@@ -0,0 +1,61 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::models::thresholds::PriceThresholds;
+
+#[derive(Properties, PartialEq)]
+pub struct ThresholdSettingsProps {
+    pub thresholds: PriceThresholds,
+    pub on_change: Callback<PriceThresholds>,
+}
+
+/// Number inputs for the chart's cheap and expensive price thresholds.
+/// Clearing an input removes that threshold.
+#[function_component(ThresholdSettings)]
+pub fn threshold_settings(props: &ThresholdSettingsProps) -> Html {
+    let on_input = |update: fn(&mut PriceThresholds, Option<f64>)| {
+        let callback = props.on_change.clone();
+        let thresholds = props.thresholds;
+        Callback::from(move |e: InputEvent| {
+            let target: HtmlInputElement = e.target_unchecked_into();
+            let value = target.value();
+            let parsed = value.trim().parse::<f64>().ok().filter(|v| v.is_finite());
+            // Ignore half-typed input such as "-" rather than clearing the threshold
+            if parsed.is_none() && !value.trim().is_empty() {
+                return;
+            }
+            let mut thresholds = thresholds;
+            update(&mut thresholds, parsed);
+            callback.emit(thresholds);
+        })
+    };
+
+    let display = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+
+    html! {
+        <div class="threshold-settings">
+            <label>
+                {"Cheap below "}
+                <input
+                    type="number"
+                    step="0.5"
+                    value={display(props.thresholds.cheap)}
+                    oninput={on_input(|t, v| t.cheap = v)}
+                    aria-label="Cheap price threshold in pence per kWh"
+                />
+                {"p"}
+            </label>
+            <label>
+                {"Expensive above "}
+                <input
+                    type="number"
+                    step="0.5"
+                    value={display(props.thresholds.expensive)}
+                    oninput={on_input(|t, v| t.expensive = v)}
+                    aria-label="Expensive price threshold in pence per kWh"
+                />
+                {"p"}
+            </label>
+        </div>
+    }
+}
@@ -1,14 +1,47 @@
+use crate::models::price_unit::PriceUnit;
 use crate::models::rates::TrackerRates;
+use crate::utils::format::format_price;
 use std::rc::Rc;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct TrackerDisplayProps {
     pub rates: Rc<TrackerRates>,
+    /// Gas Tracker rates, shown as a second column when loaded
+    #[prop_or_default]
+    pub gas: Option<Rc<TrackerRates>>,
+    #[prop_or_default]
+    pub unit: PriceUnit,
 }
 
 #[function_component(TrackerDisplay)]
 pub fn tracker_display(props: &TrackerDisplayProps) -> Html {
+    html! {
+        <div class="tracker-display">
+            <div class="tracker-grid">
+                <TrackerColumn
+                    rates={props.rates.clone()}
+                    labelled={props.gas.is_some()}
+                    unit={props.unit}
+                />
+                if let Some(gas) = &props.gas {
+                    <TrackerColumn rates={gas.clone()} labelled=true unit={props.unit} />
+                }
+            </div>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct TrackerColumnProps {
+    rates: Rc<TrackerRates>,
+    /// Prefix headings with the fuel so side-by-side columns can be told apart
+    labelled: bool,
+    unit: PriceUnit,
+}
+
+#[function_component(TrackerColumn)]
+fn tracker_column(props: &TrackerColumnProps) -> Html {
     // Single memoized computation for all three values
     let prices = use_memo(props.rates.clone(), |rates| {
         (
@@ -19,45 +52,51 @@ pub fn tracker_display(props: &TrackerDisplayProps) -> Html {
     });
 
     let (current, next_day, diff) = &*prices;
+    let per_kwh = |price: f64| format!("{}/kWh", format_price(price, props.unit));
+    let heading = |title: &str| {
+        if props.labelled {
+            format!("{} {title}", props.rates.fuel().label())
+        } else {
+            title.to_string()
+        }
+    };
 
     html! {
-        <div class="tracker-display">
-            <div class="tracker-grid">
-                <div class="tracker-item">
-                    <h3>{"Current Price"}</h3>
-                    <p class="tracker-value">
-                        {
-                            if let Some(price) = current {
-                                format!("{price:.2}p/kWh")
-                            } else {
-                                "N/A".to_string()
-                            }
+        <div class="tracker-column">
+            <div class="tracker-item">
+                <h3>{heading("Current Price")}</h3>
+                <p class="tracker-value">
+                    {
+                        if let Some(price) = current {
+                            per_kwh(*price)
+                        } else {
+                            "N/A".to_string()
                         }
-                    </p>
-                </div>
-                <div class="tracker-item-tomorrow">
-                    <h3>{"Tomorrow's Price"}</h3>
-                    <p class="tracker-value">
-                        {
-                            match (next_day, diff) {
-                                (Some(price), Some(difference)) => {
-                                    let sign = if *difference >= 0.0 { "+" } else { "" };
-                                    let class = if *difference >= 0.0 { "price-increase" } else { "price-decrease" };
-                                    html! {
-                                        <>
-                                            {format!("{:.2}p/kWh ", price)}
-                                            <span class={class}>
-                                                {format!("({}{}p)", sign, format!("{:.2}", difference))}
-                                            </span>
-                                        </>
-                                    }
-                                },
-                                (Some(price), None) => html! { {format!("{:.2}p/kWh", price)} },
-                                (None, _) => html! { {"Awaiting data"} },
-                            }
+                    }
+                </p>
+            </div>
+            <div class="tracker-item-tomorrow">
+                <h3>{heading("Tomorrow's Price")}</h3>
+                <p class="tracker-value">
+                    {
+                        match (next_day, diff) {
+                            (Some(price), Some(difference)) => {
+                                let sign = if *difference >= 0.0 { "+" } else { "" };
+                                let class = if *difference >= 0.0 { "price-increase" } else { "price-decrease" };
+                                html! {
+                                    <>
+                                        {per_kwh(*price)}{" "}
+                                        <span class={class}>
+                                            {format!("({sign}{})", format_price(*difference, props.unit))}
+                                        </span>
+                                    </>
+                                }
+                            },
+                            (Some(price), None) => html! { {per_kwh(*price)} },
+                            (None, _) => html! { {"Awaiting data"} },
                         }
-                    </p>
-                </div>
+                    }
+                </p>
             </div>
         </div>
     }
@@ -0,0 +1,61 @@
+use crate::models::rates::Rates;
+use crate::utils::time::london_today;
+use chrono::Datelike;
+use std::rc::Rc;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct WeekdayInsightProps {
+    pub rates: Rc<Rates>,
+    pub historical: Rc<Rates>,
+}
+
+/// Compares today's average price to the typical price for the same weekday
+#[function_component(WeekdayInsight)]
+pub fn weekday_insight(props: &WeekdayInsightProps) -> Html {
+    let comparison = use_memo(
+        (props.rates.clone(), props.historical.clone()),
+        |(rates, historical)| {
+            let today = london_today();
+            let today_avg = rates.stats_for_date(today)?.avg;
+            let typical = rates.weekday_typical(historical, today.weekday())?;
+            Some((today.format("%A").to_string(), today_avg, typical))
+        },
+    );
+
+    let Some((weekday, today_avg, typical)) = &*comparison else {
+        return html! {};
+    };
+
+    let change_pct = if typical.abs() < f64::EPSILON {
+        0.0
+    } else {
+        (today_avg - typical) / typical.abs() * 100.0
+    };
+
+    let (class, text) = if change_pct.abs() < 1.0 {
+        ("", format!("Today is in line with a typical {weekday}"))
+    } else if change_pct < 0.0 {
+        (
+            "price-decrease",
+            format!(
+                "Today is {:.0}% cheaper than a typical {weekday}",
+                -change_pct
+            ),
+        )
+    } else {
+        (
+            "price-increase",
+            format!("Today is {change_pct:.0}% pricier than a typical {weekday}"),
+        )
+    };
+
+    html! {
+        <p class="weekday-insight">
+            <span class={class}>{text}</span>
+            <span class="weekday-insight-detail">
+                {format!(" ({today_avg:.2}p vs {typical:.2}p average)")}
+            </span>
+        </p>
+    }
+}
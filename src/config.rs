@@ -2,6 +2,9 @@
 pub struct Config;
 
 impl Config {
+    /// Default application title shown in the header and browser tab
+    pub const APP_TITLE: &'static str = "Octopus Agile Dashboard";
+
     /// Enable automatic data refresh polling
     pub const ENABLE_AUTO_REFRESH: bool = true;
 
@@ -11,6 +14,88 @@ impl Config {
     /// Delay between pagination requests (ms) to avoid rate limiting
     pub const PAGINATION_DELAY_MS: u32 = 5;
 
+    /// Upper bound on pages followed for one request, guarding against `next` loops
+    pub const MAX_PAGES: usize = 50;
+
+    /// Age (hours) beyond which cached rates are not shown at startup
+    pub const RATES_CACHE_MAX_AGE_HOURS: i64 = 24;
+
+    /// Block length (hours) the cheapest-block picker opens on until the user picks another
+    pub const DEFAULT_BLOCK_HOURS: i64 = 4;
+
+    /// Time (ms) after which a rates fetch, retries included, is abandoned as an error
+    pub const FETCH_TIMEOUT_MS: u32 = 20_000;
+
     /// Maximum retry attempts for rate-limited requests
     pub const MAX_RETRY_ATTEMPTS: u32 = 10;
+
+    /// Maximum number of API requests allowed in flight at once
+    pub const MAX_CONCURRENT_REQUESTS: usize = 4;
+
+    /// Price spread (pence) below which a day is treated as flat and the chart is skipped
+    pub const FLAT_PRICE_TOLERANCE: f64 = 0.5;
+
+    /// Alert threshold (pence) the price-alert bell starts on
+    pub const CHEAP_SLOT_ALERT_P: f64 = 10.0;
+
+    /// Use the styled, keyboard-navigable region dropdown instead of a plain `<select>`
+    pub const CUSTOM_REGION_SELECTOR: bool = true;
+
+    /// Hours ahead covered by the summary's upcoming average price
+    pub const UPCOMING_AVG_HOURS: i64 = 4;
+
+    /// Peak window for the summary's peak/off-peak averages: London hours from the start
+    /// (inclusive) to the end (exclusive)
+    pub const PEAK_START_HOUR: u32 = 16;
+    pub const PEAK_END_HOUR: u32 = 19;
+
+    /// Flat unit rate (pence) the savings estimate compares against by default
+    pub const DEFAULT_FLAT_RATE_P: f64 = 24.5;
+
+    /// Daily usage (kWh) assumed by the savings estimate by default
+    pub const DEFAULT_DAILY_KWH: f64 = 8.0;
+
+    /// London hour by which tomorrow's prices are usually published
+    pub const TOMORROW_PUBLISH_HOUR: u32 = 16;
+
+    /// London hour after which missing tomorrow prices are flagged as late
+    pub const TOMORROW_LATE_HOUR: u32 = 17;
+
+    /// Days of price history fetched for the history chart, heatmap and records
+    pub const HISTORY_DAYS: i64 = 31;
+
+    /// Most recent days averaged into the typical-day banner
+    pub const TYPICAL_DAY_DAYS: usize = 7;
+
+    /// Show a good/average/bad emoji next to the current price
+    pub const SHOW_PRICE_EMOJI: bool = true;
+
+    /// Application title, overridable at build time via the `APP_TITLE` env var
+    pub const fn app_title() -> &'static str {
+        resolve_app_title(option_env!("APP_TITLE"))
+    }
+}
+
+/// Prefers a non-empty override, falling back to `Config::APP_TITLE`
+const fn resolve_app_title(override_title: Option<&'static str>) -> &'static str {
+    match override_title {
+        Some(title) if !title.is_empty() => title,
+        _ => Config::APP_TITLE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_app_title_prefers_override() {
+        assert_eq!(resolve_app_title(Some("Home Energy")), "Home Energy");
+    }
+
+    #[test]
+    fn test_app_title_falls_back_to_default() {
+        assert_eq!(resolve_app_title(None), "Octopus Agile Dashboard");
+        assert_eq!(resolve_app_title(Some("")), "Octopus Agile Dashboard");
+    }
 }
@@ -1,6 +1,27 @@
 pub mod use_carbon;
+pub mod use_carbon_forecast;
+pub mod use_chart_type;
+pub mod use_cheap_slot_alert;
+pub mod use_countdown;
+pub mod use_current_slot;
+pub mod use_echarts;
+pub mod use_export_rates;
+pub mod use_favourite_regions;
 pub mod use_historical_rates;
+pub mod use_online;
+pub mod use_palette;
+pub mod use_polled_resource;
+pub mod use_price_alert;
+pub mod use_price_unit;
+pub mod use_product;
 pub mod use_rates;
+pub mod use_refresh_interval;
 pub mod use_region;
+pub mod use_region_prices;
+pub mod use_settings;
+pub mod use_show_export;
+pub mod use_standing_charge;
 pub mod use_theme;
+pub mod use_thresholds;
 pub mod use_tracker;
+pub mod use_visibility;
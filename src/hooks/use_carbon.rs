@@ -1,59 +1,87 @@
+use super::use_polled_resource::{
+    ResourceHandle, ResourceState, poll_interval, use_polled_resource,
+};
 use crate::models::carbon::CarbonIntensity;
-use crate::services::carbon_api::fetch_carbon_intensity;
-use gloo_timers::future::TimeoutFuture;
-use std::cell::Cell;
-use std::rc::Rc;
-use wasm_bindgen_futures::spawn_local;
+use crate::services::api::Region;
+use crate::services::carbon_api::fetch_carbon_intensity_for;
+use chrono::{DateTime, Utc};
 use yew::prelude::*;
 
-#[derive(Clone, PartialEq, Debug)]
-pub enum CarbonDataState {
-    Loading,
-    Loaded(Rc<CarbonIntensity>),
-    Error(String),
-}
+pub type CarbonDataState = ResourceState<CarbonIntensity>;
+
+/// Carbon data only changes every half-hour period, so polls inside this window reuse it
+const CARBON_CACHE_MINUTES: i64 = 30;
 
+/// Carbon intensity for `region`'s grid area, or national figures if unavailable.
+/// Polls within 30 minutes of the last successful fetch for the same region skip the network;
+/// the handle's `refresh` always fetches.
 #[hook]
-pub fn use_carbon_intensity() -> UseStateHandle<CarbonDataState> {
-    let state = use_state(|| CarbonDataState::Loading);
-    let trigger = use_state(|| 0u32); // Polling trigger
-
-    {
-        let state = state.clone();
-        let trigger_value = *trigger;
-
-        use_effect_with(trigger_value, move |_| {
-            let state = state.clone();
-            let trigger = trigger;
-            let aborted = Rc::new(Cell::new(false));
-            let aborted_check = aborted.clone();
-
-            spawn_local(async move {
-                // Fetch carbon intensity data
-                match fetch_carbon_intensity().await {
-                    Ok(carbon_data) if !aborted_check.get() => {
-                        state.set(CarbonDataState::Loaded(Rc::new(carbon_data)));
-                    }
-                    Err(e) if !aborted_check.get() => {
-                        state.set(CarbonDataState::Error(e.to_string()));
-                    }
-                    _ => {} // Request was aborted, ignore result
-                }
+pub fn use_carbon_intensity(region: Region, interval_ms: u32) -> ResourceHandle<CarbonIntensity> {
+    let cache = use_mut_ref(|| None::<(Region, DateTime<Utc>, CarbonIntensity)>);
+    // Set by a manual refresh so the next fetch skips the cache
+    let bypass_cache = use_mut_ref(|| false);
 
-                // Schedule next poll if enabled
-                if crate::config::Config::ENABLE_AUTO_REFRESH && !aborted_check.get() {
-                    TimeoutFuture::new(crate::config::Config::POLLING_INTERVAL_MS).await;
-                    if !aborted_check.get() {
-                        trigger.set(*trigger + 1); // Trigger next fetch
-                    }
+    let handle = use_polled_resource(
+        region,
+        {
+            let bypass_cache = bypass_cache.clone();
+            move |region| async move {
+                let now = Utc::now();
+                let bypass = bypass_cache.replace(false);
+                let cached = cache
+                    .borrow()
+                    .as_ref()
+                    .filter(|(cached_region, fetched_at, _)| {
+                        !bypass && *cached_region == region && !should_refetch(*fetched_at, now)
+                    })
+                    .map(|(_, _, data)| data.clone());
+                if let Some(data) = cached {
+                    return Ok(data);
                 }
-            });
 
-            move || {
-                aborted.set(true);
+                let data = fetch_carbon_intensity_for(region).await?;
+                *cache.borrow_mut() = Some((region, now, data.clone()));
+                Ok(data)
             }
-        });
+        },
+        poll_interval(interval_ms),
+    );
+
+    let refresh = {
+        let refresh = handle.refresh.clone();
+        Callback::from(move |()| {
+            *bypass_cache.borrow_mut() = true;
+            refresh.emit(());
+        })
+    };
+
+    ResourceHandle { refresh, ..handle }
+}
+
+/// Whether data fetched at `last` is old enough (or from the future) to fetch again
+fn should_refetch(last: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+    let age = now - last;
+    age < chrono::Duration::zero() || age >= chrono::Duration::minutes(CARBON_CACHE_MINUTES)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_should_refetch_after_thirty_minutes() {
+        let last = Utc.with_ymd_and_hms(2026, 1, 12, 10, 0, 0).unwrap();
+
+        assert!(!should_refetch(last, last));
+        assert!(!should_refetch(last, last + chrono::Duration::minutes(29)));
+        assert!(should_refetch(last, last + chrono::Duration::minutes(30)));
     }
 
-    state
+    #[test]
+    fn test_should_refetch_when_clock_went_backwards() {
+        let last = Utc.with_ymd_and_hms(2026, 1, 12, 10, 0, 0).unwrap();
+
+        assert!(should_refetch(last, last - chrono::Duration::minutes(1)));
+    }
 }
@@ -0,0 +1,12 @@
+use super::use_polled_resource::{ResourceState, poll_interval, use_polled_resource};
+use crate::models::carbon::CarbonForecast;
+use crate::services::carbon_api::fetch_carbon_forecast;
+use yew::prelude::*;
+
+pub type CarbonForecastState = ResourceState<CarbonForecast>;
+
+/// National 48-hour carbon intensity forecast
+#[hook]
+pub fn use_carbon_forecast(interval_ms: u32) -> UseStateHandle<CarbonForecastState> {
+    use_polled_resource((), |()| fetch_carbon_forecast(), poll_interval(interval_ms)).state
+}
@@ -0,0 +1,29 @@
+use yew::prelude::*;
+
+use crate::hooks::use_settings::use_settings;
+use crate::models::chart_type::ChartType;
+
+/// Handle returned by `use_chart_type` hook
+#[derive(Clone, PartialEq)]
+pub struct ChartTypeHandle {
+    pub chart_type: ChartType,
+    pub set_chart_type: Callback<ChartType>,
+}
+
+/// Custom hook for the price chart's bar/line style, persisted in the stored settings
+#[hook]
+pub fn use_chart_type() -> ChartTypeHandle {
+    let settings = use_settings();
+
+    let set_chart_type = {
+        let settings = settings.clone();
+        Callback::from(move |new_chart_type| {
+            settings.update(move |s| s.chart_type = new_chart_type);
+        })
+    };
+
+    ChartTypeHandle {
+        chart_type: settings.get().chart_type,
+        set_chart_type,
+    }
+}
@@ -0,0 +1,130 @@
+use chrono::{DateTime, Utc};
+use gloo_timers::callback::Interval;
+use std::rc::Rc;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+use crate::models::rates::{Rate, Rates};
+use crate::services::notifications;
+
+/// How long before a cheap slot starts the alert fires
+const ALERT_LEAD_MINUTES: i64 = 15;
+
+/// How often the clock is checked against upcoming slots
+const CHECK_INTERVAL_MS: u32 = 60_000;
+
+/// Shows a browser notification when a slot priced below `threshold_p` starts within
+/// the next 15 minutes. A `None` threshold turns alerts off.
+///
+/// Permission is requested once alerts are enabled. Each slot alerts at most once, and
+/// nothing happens if notifications are unsupported or denied.
+#[hook]
+pub fn use_cheap_slot_alert(rates: Rc<Rates>, threshold_p: Option<f64>) {
+    let alerted = use_mut_ref(|| None::<DateTime<Utc>>);
+    let enabled = threshold_p.is_some();
+
+    use_effect_with(enabled, |enabled| {
+        if *enabled {
+            spawn_local(async {
+                notifications::request_permission().await;
+            });
+        }
+        || ()
+    });
+
+    use_effect_with((rates, threshold_p), move |(rates, threshold_p)| {
+        let rates = rates.clone();
+        let interval = threshold_p.map(|threshold_p| {
+            let check = move || {
+                let Some(rate) = slot_to_alert(&rates, Utc::now(), threshold_p) else {
+                    return;
+                };
+                if *alerted.borrow() == Some(rate.valid_from) {
+                    return; // Already alerted for this slot
+                }
+                *alerted.borrow_mut() = Some(rate.valid_from);
+                notify(rate);
+            };
+
+            check();
+            Interval::new(CHECK_INTERVAL_MS, check)
+        });
+        move || drop(interval)
+    });
+}
+
+/// The first slot below `threshold_p` whose 15-minute pre-window contains `now`
+fn slot_to_alert(rates: &Rates, now: DateTime<Utc>, threshold_p: f64) -> Option<&Rate> {
+    let lead = chrono::Duration::minutes(ALERT_LEAD_MINUTES);
+    rates
+        .filter_from(now)
+        .take_while(|r| r.valid_from - lead <= now)
+        .find(|r| r.valid_from > now && r.value_inc_vat < threshold_p)
+}
+
+fn notify(rate: &Rate) {
+    notifications::notify(
+        "Cheap electricity soon",
+        &format!(
+            "{} starts in {ALERT_LEAD_MINUTES} minutes",
+            rate.slot_label()
+        ),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn make_rates() -> Rates {
+        let start = Utc.with_ymd_and_hms(2026, 1, 12, 1, 0, 0).unwrap();
+        Rates::new(
+            [20.0, 8.0, 5.0]
+                .into_iter()
+                .zip(0..)
+                .map(|(value, i)| {
+                    let valid_from = start + chrono::Duration::minutes(30 * i);
+                    Rate {
+                        value_inc_vat: value,
+                        value_exc_vat: value / 1.05,
+                        valid_from,
+                        valid_to: valid_from + chrono::Duration::minutes(30),
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_alerts_inside_pre_window() {
+        let rates = make_rates();
+        // The 01:30 slot (8p) is the first below 10p
+        let now = Utc.with_ymd_and_hms(2026, 1, 12, 1, 15, 0).unwrap();
+
+        let rate = slot_to_alert(&rates, now, 10.0).unwrap();
+        assert_eq!(
+            rate.valid_from,
+            Utc.with_ymd_and_hms(2026, 1, 12, 1, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_no_alert_outside_pre_window() {
+        let rates = make_rates();
+        let early = Utc.with_ymd_and_hms(2026, 1, 12, 1, 14, 0).unwrap();
+        // Once the slot has started its pre-window is over
+        let started = Utc.with_ymd_and_hms(2026, 1, 12, 1, 30, 0).unwrap();
+
+        assert!(slot_to_alert(&rates, early, 10.0).is_none());
+        assert!(slot_to_alert(&rates, started, 10.0).is_none());
+    }
+
+    #[test]
+    fn test_threshold_excludes_pricier_slots() {
+        let rates = make_rates();
+        let now = Utc.with_ymd_and_hms(2026, 1, 12, 1, 20, 0).unwrap();
+
+        assert!(slot_to_alert(&rates, now, 8.0).is_none());
+    }
+}
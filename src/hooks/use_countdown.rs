@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use gloo_timers::callback::Interval;
+use yew::prelude::*;
+
+/// Time left until `target`, re-rendering the caller every second. Never negative.
+///
+/// The ticking interval is created once and dropped on unmount; a changed `target`
+/// takes effect on the next render.
+#[hook]
+pub fn use_countdown(target: DateTime<Utc>) -> chrono::Duration {
+    let now = use_state(Utc::now);
+
+    {
+        let now = now.setter();
+        use_effect_with((), move |()| {
+            let interval = Interval::new(1_000, move || now.set(Utc::now()));
+            move || drop(interval)
+        });
+    }
+
+    (target - *now).max(chrono::Duration::zero())
+}
@@ -0,0 +1,121 @@
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use gloo_timers::callback::Interval;
+use std::rc::Rc;
+use yew::prelude::*;
+
+use crate::models::rates::{Rate, Rates};
+use crate::utils::time::format_slot_window;
+
+/// How often the clock is checked for a slot boundary
+const TICK_INTERVAL_MS: u32 = 30_000;
+
+/// Prices for the slot in effect now and the one after it
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CurrentSlot {
+    pub current_rate: Option<Rate>,
+    pub current_price: Option<f64>,
+    pub next_price: Option<f64>,
+    /// London time window of the current slot, e.g. "14:00–14:30"
+    pub current_window: Option<String>,
+    /// Start of the clock half-hour containing now; moves at every slot boundary even
+    /// when there is no rate for it, so memos on the time of day can key on it
+    pub slot_start: DateTime<Utc>,
+}
+
+impl CurrentSlot {
+    pub fn at(rates: &Rates, now: DateTime<Utc>) -> Self {
+        Self {
+            current_rate: rates.rate_at(now).cloned(),
+            current_price: rates.rate_at(now).map(|r| r.value_inc_vat),
+            next_price: rates.next_rate(now).map(|r| r.value_inc_vat),
+            current_window: rates
+                .rate_at(now)
+                .map(|r| format_slot_window(r.valid_from, r.valid_to)),
+            slot_start: now.duration_trunc(Duration::minutes(30)).unwrap_or(now),
+        }
+    }
+}
+
+/// Current and next slot prices from the already-loaded `rates`, rechecked every 30
+/// seconds so they roll over at slot boundaries between polls without a refetch.
+///
+/// Only re-renders the caller when the slot actually changes.
+#[hook]
+pub fn use_current_slot(rates: Rc<Rates>) -> CurrentSlot {
+    let slot = use_state_eq(|| CurrentSlot::at(&rates, Utc::now()));
+
+    {
+        let slot = slot.setter();
+        use_effect_with(rates, move |rates| {
+            let rates = rates.clone();
+            let tick = move || slot.set(CurrentSlot::at(&rates, Utc::now()));
+            tick();
+            let interval = Interval::new(TICK_INTERVAL_MS, tick);
+            move || drop(interval)
+        });
+    }
+
+    (*slot).clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn make_rates() -> Rates {
+        let start = Utc.with_ymd_and_hms(2026, 1, 12, 14, 0, 0).unwrap();
+        Rates::new(
+            [20.0, 8.0, 5.0]
+                .into_iter()
+                .zip(0..)
+                .map(|(value, i)| {
+                    let valid_from = start + chrono::Duration::minutes(30 * i);
+                    Rate {
+                        value_inc_vat: value,
+                        value_exc_vat: value / 1.05,
+                        valid_from,
+                        valid_to: valid_from + chrono::Duration::minutes(30),
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_rolls_over_past_slot_boundary() {
+        let rates = make_rates();
+        let before = Utc.with_ymd_and_hms(2026, 1, 12, 14, 29, 45).unwrap();
+        let after = Utc.with_ymd_and_hms(2026, 1, 12, 14, 30, 15).unwrap();
+
+        let slot = CurrentSlot::at(&rates, before);
+        assert_eq!(
+            (slot.current_price, slot.next_price),
+            (Some(20.0), Some(8.0))
+        );
+
+        let slot = CurrentSlot::at(&rates, after);
+        assert_eq!(
+            (slot.current_price, slot.next_price),
+            (Some(8.0), Some(5.0))
+        );
+        assert!(slot.current_window.unwrap().starts_with("14:30"));
+        assert_eq!(
+            slot.slot_start,
+            Utc.with_ymd_and_hms(2026, 1, 12, 14, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_last_slot_has_no_next_price() {
+        let rates = make_rates();
+        let last = Utc.with_ymd_and_hms(2026, 1, 12, 15, 10, 0).unwrap();
+        let past_end = Utc.with_ymd_and_hms(2026, 1, 12, 15, 30, 0).unwrap();
+
+        let slot = CurrentSlot::at(&rates, last);
+        assert_eq!((slot.current_price, slot.next_price), (Some(5.0), None));
+        let slot = CurrentSlot::at(&rates, past_end);
+        assert_eq!((slot.current_price, slot.next_price), (None, None));
+        assert_eq!(slot.slot_start, past_end);
+    }
+}
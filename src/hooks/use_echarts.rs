@@ -0,0 +1,75 @@
+use charming::{
+    Chart as CharmingChart,
+    renderer::{ChartResize, Echarts, WasmRenderer},
+};
+use web_sys::HtmlElement;
+use yew::prelude::*;
+
+use crate::utils::debounce::create_debounced_resize_observer;
+
+/// Renders the chart from `build` into the element `chart_id` inside `container_ref`,
+/// again whenever `deps` change and, debounced, whenever the container is resized.
+/// `build` returns `None` when there is nothing to draw.
+#[hook]
+pub fn use_echarts<D, F>(chart_id: &'static str, container_ref: NodeRef, deps: D, build: F)
+where
+    D: PartialEq + Clone + 'static,
+    F: Fn(&D) -> Option<CharmingChart> + 'static,
+{
+    let chart_instance = use_mut_ref(|| None::<Echarts>);
+
+    use_effect_with((deps, container_ref), move |(deps, container_ref)| {
+        let observer = container_ref.cast::<HtmlElement>().and_then(|container| {
+            let render = {
+                let container = container.clone();
+                let deps = deps.clone();
+                move || {
+                    render_chart(
+                        chart_id,
+                        &container,
+                        &mut chart_instance.borrow_mut(),
+                        || build(&deps),
+                    );
+                }
+            };
+            render();
+
+            create_debounced_resize_observer(&container, render, 150)
+                .map_err(|error| {
+                    web_sys::console::error_1(
+                        &format!("ResizeObserver setup error: {error:?}").into(),
+                    );
+                })
+                .ok()
+        });
+
+        move || drop(observer)
+    });
+}
+
+fn render_chart(
+    chart_id: &str,
+    container: &HtmlElement,
+    chart_instance: &mut Option<Echarts>,
+    build: impl FnOnce() -> Option<CharmingChart>,
+) {
+    let width = container.client_width().cast_unsigned();
+    let height = container.client_height().cast_unsigned();
+
+    if width == 0 || height == 0 {
+        return;
+    }
+    let Some(chart) = build() else {
+        return;
+    };
+
+    if let Some(existing_chart) = chart_instance.as_ref() {
+        WasmRenderer::resize_chart(existing_chart, ChartResize::new(width, height, false, None));
+        WasmRenderer::update(existing_chart, &chart);
+    } else {
+        match WasmRenderer::new(width, height).render(chart_id, &chart) {
+            Ok(existing_chart) => *chart_instance = Some(existing_chart),
+            Err(e) => web_sys::console::error_1(&format!("Render error: {e:?}").into()),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use yew::prelude::*;
+
+use super::use_polled_resource::{poll_interval, use_polled_resource};
+use super::use_rates::DataState;
+use crate::models::rates::Rates;
+use crate::services::api::{Product, Region, fetch_export_rates_for_region};
+
+/// Polls Agile Outgoing export rates for `region` while `enabled`. When disabled it
+/// settles on an empty set without touching the network, and stops polling.
+#[hook]
+pub fn use_export_rates(
+    region: Region,
+    product: Product,
+    enabled: bool,
+    interval_ms: u32,
+) -> UseStateHandle<DataState> {
+    use_polled_resource(
+        (region, product, enabled),
+        |(region, product, enabled)| async move {
+            if enabled {
+                fetch_export_rates_for_region(region, product).await
+            } else {
+                Ok(Rates::new(Vec::new()))
+            }
+        },
+        poll_interval(interval_ms).filter(|_| enabled),
+    )
+    .state
+}
@@ -0,0 +1,100 @@
+use yew::prelude::*;
+
+use crate::hooks::use_settings::use_settings;
+use crate::services::api::Region;
+
+/// Handle returned by `use_favourite_regions` hook
+#[derive(Clone, PartialEq)]
+pub struct FavouriteRegionsHandle {
+    pub favourites: Vec<Region>,
+    /// Stars a region, or unstars it if it is already a favourite
+    pub toggle: Callback<Region>,
+}
+
+/// Custom hook for the starred regions, persisted in the stored settings
+#[hook]
+pub fn use_favourite_regions() -> FavouriteRegionsHandle {
+    // Favourites from the stored settings (none by default)
+    let settings = use_settings();
+    let favourites = dedup_favourites(settings.get().favourite_regions.clone());
+
+    let toggle = Callback::from(move |region| {
+        settings.update(move |s| {
+            s.favourite_regions =
+                toggle_favourite(&dedup_favourites(s.favourite_regions.clone()), region);
+        });
+    });
+
+    FavouriteRegionsHandle { favourites, toggle }
+}
+
+/// `favourites` with `region` added at the end, or removed if it is already there
+pub fn toggle_favourite(favourites: &[Region], region: Region) -> Vec<Region> {
+    if favourites.contains(&region) {
+        favourites
+            .iter()
+            .copied()
+            .filter(|r| *r != region)
+            .collect()
+    } else {
+        favourites.iter().copied().chain([region]).collect()
+    }
+}
+
+/// Drops repeats, keeping each region where it first appears
+pub fn dedup_favourites(favourites: Vec<Region>) -> Vec<Region> {
+    favourites
+        .into_iter()
+        .fold(Vec::new(), |mut unique, region| {
+            if !unique.contains(&region) {
+                unique.push(region);
+            }
+            unique
+        })
+}
+
+/// Every region with the favourites first, and how many of them are favourites
+pub fn favourites_first(favourites: &[Region]) -> (Vec<Region>, usize) {
+    let favourites = dedup_favourites(favourites.to_vec());
+    let count = favourites.len();
+    let rest = Region::all().iter().filter(|r| !favourites.contains(r));
+    (favourites.iter().chain(rest).copied().collect(), count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_adds_then_removes() {
+        let added = toggle_favourite(&[Region::C], Region::M);
+        assert_eq!(added, vec![Region::C, Region::M]);
+
+        assert_eq!(toggle_favourite(&added, Region::C), vec![Region::M]);
+        assert!(toggle_favourite(&[Region::M], Region::M).is_empty());
+    }
+
+    #[test]
+    fn test_dedup_keeps_first_occurrence() {
+        assert_eq!(
+            dedup_favourites(vec![Region::M, Region::C, Region::M, Region::C]),
+            vec![Region::M, Region::C]
+        );
+        assert!(dedup_favourites(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn test_favourites_first_lists_every_region_once() {
+        let (regions, count) = favourites_first(&[Region::P, Region::C, Region::P]);
+
+        assert_eq!(count, 2);
+        assert_eq!(&regions[..2], &[Region::P, Region::C]);
+        assert_eq!(regions[2], Region::A);
+        assert_eq!(regions.len(), Region::all().len());
+    }
+
+    #[test]
+    fn test_favourites_first_without_favourites() {
+        assert_eq!(favourites_first(&[]), (Region::all().to_vec(), 0));
+    }
+}
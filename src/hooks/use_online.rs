@@ -0,0 +1,31 @@
+use gloo::events::EventListener;
+use yew::prelude::*;
+
+/// Tracks whether the browser reports a network connection, updating on the window's
+/// `online` and `offline` events.
+///
+/// Reports online when there is no window (e.g. outside a browser).
+#[hook]
+pub fn use_online() -> bool {
+    let online = use_state(is_online);
+
+    {
+        let online = online.clone();
+        use_effect_with((), move |()| {
+            let listeners = web_sys::window().map(|window| {
+                ["online", "offline"].map(|event| {
+                    let online = online.clone();
+                    EventListener::new(&window, event, move |_| online.set(is_online()))
+                })
+            });
+            move || drop(listeners)
+        });
+    }
+
+    *online
+}
+
+/// `navigator.onLine`, or true when there is no window
+pub fn is_online() -> bool {
+    web_sys::window().is_none_or(|w| w.navigator().on_line())
+}
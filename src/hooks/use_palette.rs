@@ -0,0 +1,27 @@
+use yew::prelude::*;
+
+use crate::hooks::use_settings::use_settings;
+use crate::models::palette::Palette;
+
+/// Handle returned by `use_palette` hook
+#[derive(Clone, PartialEq)]
+pub struct PaletteHandle {
+    pub palette: Palette,
+    pub set_palette: Callback<Palette>,
+}
+
+/// Custom hook for the color palette, persisted in the stored settings
+#[hook]
+pub fn use_palette() -> PaletteHandle {
+    let settings = use_settings();
+
+    let set_palette = {
+        let settings = settings.clone();
+        Callback::from(move |new_palette| settings.update(move |s| s.palette = new_palette))
+    };
+
+    PaletteHandle {
+        palette: settings.get().palette,
+        set_palette,
+    }
+}
@@ -0,0 +1,352 @@
+use std::future::Future;
+use std::rc::Rc;
+use yew::prelude::*;
+
+use super::use_online::{is_online, use_online};
+use super::use_visibility::use_visibility;
+use crate::config::Config;
+use crate::models::error::AppError;
+use crate::utils::abort::{AbortController, abortable};
+use chrono::{DateTime, Utc};
+use gloo_timers::future::TimeoutFuture;
+use wasm_bindgen_futures::spawn_local;
+
+/// Lifecycle of a polled resource
+#[derive(Clone, PartialEq, Debug)]
+pub enum ResourceState<T> {
+    Loading,
+    Loaded(Rc<T>),
+    /// A background re-poll is in flight; the previous data stays visible
+    Refreshing(Rc<T>),
+    /// The last fetch failed; kept whole so displays can advise per kind of failure
+    Error(Rc<AppError>),
+}
+
+/// Events driving a `ResourceState` transition
+#[derive(Debug)]
+pub enum ResourceAction<T> {
+    /// A fetch is starting; any data on screen stays visible until it resolves
+    Fetch,
+    /// The fetch finished
+    Resolved(Result<T, AppError>),
+    /// The fetch failed while the browser was offline. Data on screen is kept, otherwise
+    /// `fallback` (e.g. a cached copy) is shown; only with neither is it an error.
+    FailedOffline {
+        error: AppError,
+        fallback: Option<T>,
+    },
+}
+
+impl<T> ResourceState<T> {
+    /// Returns the data if it is loaded (including while refreshing)
+    pub const fn data(&self) -> Option<&Rc<T>> {
+        match self {
+            Self::Loaded(data) | Self::Refreshing(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Returns true while a re-poll is in flight over existing data
+    pub const fn is_refreshing(&self) -> bool {
+        matches!(self, Self::Refreshing(_))
+    }
+
+    /// State to show when a fetch starts: keep existing data, otherwise load from scratch
+    pub fn begin_refresh(&self) -> Self {
+        self.data()
+            .map_or(Self::Loading, |data| Self::Refreshing(data.clone()))
+    }
+
+    /// Applies an action, returning the next state
+    pub fn reduce(&self, action: ResourceAction<T>) -> Self {
+        match action {
+            ResourceAction::Fetch => self.begin_refresh(),
+            ResourceAction::Resolved(Ok(data)) => Self::Loaded(Rc::new(data)),
+            ResourceAction::Resolved(Err(e)) => Self::Error(Rc::new(e)),
+            ResourceAction::FailedOffline { error, fallback } => match (self.data(), fallback) {
+                (Some(data), _) => Self::Loaded(data.clone()),
+                (None, Some(fallback)) => Self::Loaded(Rc::new(fallback)),
+                (None, None) => Self::Error(Rc::new(error)),
+            },
+        }
+    }
+}
+
+/// Handle returned by `use_polled_resource`
+#[derive(Clone, PartialEq)]
+pub struct ResourceHandle<T> {
+    pub state: UseStateHandle<ResourceState<T>>,
+    /// Fetches immediately; ignored while a fetch is already in flight
+    pub refresh: Callback<()>,
+    /// When the last successful fetch completed
+    pub last_updated: Option<DateTime<Utc>>,
+    /// True while showing data fetched for a previous key (e.g. the old region)
+    pub stale: bool,
+}
+
+/// Polling interval to use, or `None` when auto-refresh is disabled in config
+pub const fn poll_interval(interval_ms: u32) -> Option<u32> {
+    if Config::ENABLE_AUTO_REFRESH {
+        Some(interval_ms)
+    } else {
+        None
+    }
+}
+
+/// Fetches a resource for `key` and re-polls it every `interval_ms`.
+/// A changed interval takes effect from the next poll.
+/// Polling pauses while the page is hidden or the browser is offline, and fetches straight
+/// away when it is shown again or reconnects.
+///
+/// Changing `key` or re-polling keeps the previous data visible (stale-while-revalidate);
+/// only the very first load shows `Loading`.
+/// Results arriving after the key changes or the component unmounts are dropped.
+#[hook]
+pub fn use_polled_resource<T, K, F, Fut>(
+    key: K,
+    fetch: F,
+    interval_ms: Option<u32>,
+) -> ResourceHandle<T>
+where
+    T: 'static,
+    K: Clone + PartialEq + 'static,
+    F: FnOnce(K) -> Fut + 'static,
+    Fut: Future<Output = Result<T, AppError>> + 'static,
+{
+    use_seeded_polled_resource(key, |_| None, fetch, interval_ms)
+}
+
+/// Like `use_polled_resource`, but `seed` may supply data, with when it was fetched, to show
+/// before the first fetch completes (e.g. from a local cache). Seeded data is refreshed in
+/// the background. A fetch failing while offline with nothing on screen falls back to the
+/// seed instead of an error.
+#[hook]
+pub fn use_seeded_polled_resource<T, K, S, F, Fut>(
+    key: K,
+    seed: S,
+    fetch: F,
+    interval_ms: Option<u32>,
+) -> ResourceHandle<T>
+where
+    T: 'static,
+    K: Clone + PartialEq + 'static,
+    S: Fn(&K) -> Option<(T, DateTime<Utc>)> + 'static,
+    F: FnOnce(K) -> Fut + 'static,
+    Fut: Future<Output = Result<T, AppError>> + 'static,
+{
+    let seed = Rc::new(seed);
+    let loaded_key = use_mut_ref(|| None::<K>);
+    // When the seeded data was fetched, passed from the first render's state to `last_updated`
+    let seeded_at = std::cell::Cell::new(None);
+    let state = {
+        let loaded_key = loaded_key.clone();
+        let key = key.clone();
+        let seed = seed.clone();
+        let seeded_at = &seeded_at;
+        use_state(move || match seed(&key) {
+            Some((data, fetched_at)) => {
+                *loaded_key.borrow_mut() = Some(key);
+                seeded_at.set(Some(fetched_at));
+                ResourceState::Loaded(Rc::new(data))
+            }
+            None => ResourceState::Loading,
+        })
+    };
+    let trigger = use_state(|| 0u32); // Polling trigger
+    let interval = use_mut_ref(|| interval_ms);
+    *interval.borrow_mut() = interval_ms;
+    let last_updated = use_state(|| seeded_at.get());
+    let in_flight = use_mut_ref(|| false);
+    // Polls only fall due while the page is visible and the browser online
+    let visible = use_visibility();
+    let online = use_online();
+    let active = visible && online;
+    let active_now = use_mut_ref(|| active);
+
+    {
+        let state = state.clone();
+        let trigger = trigger.clone();
+        let last_updated = last_updated.clone();
+        let in_flight = in_flight.clone();
+        let loaded_key = loaded_key.clone();
+        let interval = interval.clone();
+        let active_now = active_now.clone();
+        let seed = seed.clone();
+        let trigger_value = *trigger;
+
+        use_effect_with((trigger_value, key.clone()), move |(_, key)| {
+            let state = state.clone();
+            let trigger = trigger;
+            let key = key.clone();
+            // Re-keying or unmounting aborts the fetch, cancelling its HTTP request
+            let controller = AbortController::new();
+            let signal = controller.signal();
+
+            state.set(state.reduce(ResourceAction::Fetch));
+            *in_flight.borrow_mut() = true;
+
+            spawn_local(async move {
+                let result = abortable(fetch(key.clone()), &signal).await;
+                if signal.is_aborted() {
+                    return; // Superseded; leave the state to the new fetch
+                }
+                *in_flight.borrow_mut() = false;
+                let action = match result {
+                    Err(error) if !is_online() => {
+                        // Only needed with nothing on screen
+                        let fallback = state.data().is_none().then(|| seed(&key)).flatten().map(
+                            |(data, fetched_at)| {
+                                *loaded_key.borrow_mut() = Some(key);
+                                last_updated.set(Some(fetched_at));
+                                data
+                            },
+                        );
+                        ResourceAction::FailedOffline { error, fallback }
+                    }
+                    result => {
+                        if result.is_ok() {
+                            *loaded_key.borrow_mut() = Some(key);
+                            last_updated.set(Some(Utc::now()));
+                        }
+                        ResourceAction::Resolved(result)
+                    }
+                };
+                state.set(state.reduce(action));
+
+                // Schedule next poll if enabled, reading the latest interval.
+                // A poll falling due while hidden or offline is dropped; coming back fetches.
+                let next_interval = *interval.borrow();
+                if let Some(interval_ms) = next_interval {
+                    TimeoutFuture::new(interval_ms).await;
+                    if !signal.is_aborted() && *active_now.borrow() {
+                        trigger.set(*trigger + 1); // Trigger next fetch
+                    }
+                }
+            });
+
+            move || controller.abort()
+        });
+    }
+
+    // Fetch as soon as the page is visible and online again. Bumping the trigger cancels any
+    // pending poll, and a fetch already in flight schedules its own, so neither doubles up.
+    {
+        let trigger = trigger.clone();
+        let in_flight = in_flight.clone();
+        use_effect_with(active, move |active| {
+            let was_active = active_now.replace(*active);
+            if *active && !was_active && !*in_flight.borrow() {
+                trigger.set(*trigger + 1);
+            }
+            || ()
+        });
+    }
+
+    // Bumping the trigger re-runs the effect, whose cleanup cancels the pending poll
+    let refresh = {
+        let trigger = trigger.clone();
+        Callback::from(move |()| {
+            if !*in_flight.borrow() {
+                trigger.set(*trigger + 1);
+            }
+        })
+    };
+
+    let stale = state.data().is_some() && loaded_key.borrow().as_ref() != Some(&key);
+
+    ResourceHandle {
+        state,
+        refresh,
+        last_updated: *last_updated,
+        stale,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    fn mock_fetch(ok: bool) -> impl Future<Output = Result<u32, AppError>> {
+        std::future::ready(if ok {
+            Ok(42)
+        } else {
+            Err(AppError::ApiError("boom".to_string()))
+        })
+    }
+
+    #[test]
+    fn test_initial_fetch_loads_then_resolves() {
+        let state = ResourceState::<u32>::Loading.reduce(ResourceAction::Fetch);
+        assert_eq!(state, ResourceState::Loading);
+
+        let state = state.reduce(ResourceAction::Resolved(block_on(mock_fetch(true))));
+        assert_eq!(state, ResourceState::Loaded(Rc::new(42)));
+    }
+
+    #[test]
+    fn test_repoll_keeps_data_until_resolved() {
+        let loaded = ResourceState::Loaded(Rc::new(7));
+
+        let refreshing = loaded.reduce(ResourceAction::Fetch);
+        assert!(refreshing.is_refreshing());
+        assert_eq!(refreshing.data(), Some(&Rc::new(7)));
+
+        let state = refreshing.reduce(ResourceAction::Resolved(block_on(mock_fetch(true))));
+        assert_eq!(state, ResourceState::Loaded(Rc::new(42)));
+    }
+
+    #[test]
+    fn test_fetch_after_error_shows_loading() {
+        // Nothing to keep on screen, so fall back to the full loading state
+        let failed = ResourceState::<u32>::Error(Rc::new(AppError::ApiError("boom".to_string())));
+
+        assert_eq!(failed.reduce(ResourceAction::Fetch), ResourceState::Loading);
+    }
+
+    #[test]
+    fn test_offline_failure_keeps_data_on_screen() {
+        let refreshing = ResourceState::Refreshing(Rc::new(7));
+
+        let state = refreshing.reduce(ResourceAction::FailedOffline {
+            error: AppError::NetworkError("offline".to_string()),
+            fallback: Some(3),
+        });
+        // What was showing is at least as fresh as the fallback
+        assert_eq!(state, ResourceState::Loaded(Rc::new(7)));
+    }
+
+    #[test]
+    fn test_offline_failure_falls_back_without_data() {
+        let state = ResourceState::<u32>::Loading.reduce(ResourceAction::FailedOffline {
+            error: AppError::NetworkError("offline".to_string()),
+            fallback: Some(3),
+        });
+        assert_eq!(state, ResourceState::Loaded(Rc::new(3)));
+    }
+
+    #[test]
+    fn test_offline_failure_without_fallback_is_error() {
+        let state = ResourceState::<u32>::Loading.reduce(ResourceAction::FailedOffline {
+            error: AppError::NetworkError("offline".to_string()),
+            fallback: None,
+        });
+        assert!(matches!(&state, ResourceState::Error(e) if e.offers_retry()));
+
+        // Back online, the next fetch starts from scratch and can succeed
+        let state = state.reduce(ResourceAction::Fetch);
+        assert_eq!(state, ResourceState::Loading);
+        let state = state.reduce(ResourceAction::Resolved(block_on(mock_fetch(true))));
+        assert_eq!(state, ResourceState::Loaded(Rc::new(42)));
+    }
+
+    #[test]
+    fn test_failed_fetch_moves_to_error() {
+        let refreshing = ResourceState::Refreshing(Rc::new(7));
+
+        let state = refreshing.reduce(ResourceAction::Resolved(block_on(mock_fetch(false))));
+        assert!(
+            matches!(state, ResourceState::Error(e) if *e == AppError::ApiError("boom".to_string()))
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use yew::prelude::*;
+
+use crate::hooks::use_rates::RatesHandle;
+use crate::hooks::use_settings::use_settings;
+use crate::services::notifications;
+
+/// Handle returned by `use_price_alert` hook
+#[derive(Clone, PartialEq)]
+pub struct PriceAlertHandle {
+    /// Price (pence) below which upcoming slots raise a notification; `None` is off
+    pub threshold: Option<f64>,
+    pub set_threshold: Callback<Option<f64>>,
+}
+
+/// Custom hook for price-drop notifications, with the threshold persisted in the stored
+/// settings.
+///
+/// Each time fresh rates arrive, slots in the next 12 hours below the threshold are
+/// notified, at most once per slot. `Summary` reuses the threshold for its reminder just
+/// before a cheap slot starts (`use_cheap_slot_alert`).
+#[hook]
+pub fn use_price_alert(rates: &RatesHandle) -> PriceAlertHandle {
+    // Threshold from the stored settings (off by default)
+    let settings = use_settings();
+    let threshold = settings.get().alert_threshold_p;
+
+    // Effect: Check for cheap slots after every successful poll
+    {
+        let data = rates.state.data().cloned();
+        use_effect_with((rates.last_updated, threshold), move |(_, threshold)| {
+            if let (Some(rates), Some(threshold)) = (data, *threshold) {
+                notifications::alert_cheap_slots(&rates, threshold);
+            }
+            || ()
+        });
+    }
+
+    let set_threshold = Callback::from(move |new_threshold| {
+        settings.update(move |s| s.alert_threshold_p = new_threshold);
+    });
+
+    PriceAlertHandle {
+        threshold,
+        set_threshold,
+    }
+}
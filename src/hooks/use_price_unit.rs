@@ -0,0 +1,27 @@
+use yew::prelude::*;
+
+use crate::hooks::use_settings::use_settings;
+use crate::models::price_unit::PriceUnit;
+
+/// Handle returned by `use_price_unit` hook
+#[derive(Clone, PartialEq)]
+pub struct PriceUnitHandle {
+    pub price_unit: PriceUnit,
+    pub set_price_unit: Callback<PriceUnit>,
+}
+
+/// Custom hook for the unit prices are shown in, persisted in the stored settings
+#[hook]
+pub fn use_price_unit() -> PriceUnitHandle {
+    let settings = use_settings();
+
+    let set_price_unit = {
+        let settings = settings.clone();
+        Callback::from(move |new_unit| settings.update(move |s| s.price_unit = new_unit))
+    };
+
+    PriceUnitHandle {
+        price_unit: settings.get().price_unit,
+        set_price_unit,
+    }
+}
@@ -0,0 +1,51 @@
+use gloo_storage::Storage;
+use yew::prelude::*;
+
+use crate::services::api::Product;
+use crate::services::storage;
+
+/// Handle returned by `use_product` hook
+#[derive(Clone, PartialEq)]
+pub struct ProductHandle {
+    pub product: Product,
+    pub set_product: Callback<Product>,
+}
+
+/// Custom hook for Agile product selection with localStorage persistence
+#[hook]
+pub fn use_product() -> ProductHandle {
+    // Load product from localStorage, fallback to the current Agile release
+    let product = use_state(|| load_product_preference().unwrap_or_default());
+
+    // Effect: Persist product to localStorage on change
+    {
+        let product_value = *product;
+        use_effect_with(product_value, move |product| {
+            save_product_preference(*product);
+            || ()
+        });
+    }
+
+    // Set product callback
+    let set_product = {
+        let product = product.clone();
+        Callback::from(move |new_product| product.set(new_product))
+    };
+
+    ProductHandle {
+        product: *product,
+        set_product,
+    }
+}
+
+/// Load product preference from localStorage
+fn load_product_preference() -> Option<Product> {
+    storage::safe_get("product")
+}
+
+/// Save product preference to localStorage
+fn save_product_preference(product: Product) {
+    if let Err(e) = gloo_storage::LocalStorage::set("product", product) {
+        web_sys::console::warn_1(&format!("Failed to save product: {e:?}").into());
+    }
+}
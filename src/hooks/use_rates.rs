@@ -1,74 +1,67 @@
-use std::cell::Cell;
-use std::rc::Rc;
 use yew::prelude::*;
 
-use crate::models::rates::Rates;
-use crate::services::api::{Region, fetch_rates_for_region};
-use gloo_timers::future::TimeoutFuture;
-use wasm_bindgen_futures::spawn_local;
+use super::use_polled_resource::{
+    ResourceHandle, ResourceState, poll_interval, use_seeded_polled_resource,
+};
+use super::use_product::ProductHandle;
+use super::use_region::RegionHandle;
+use crate::config::Config;
+use crate::models::rates::{CachedRates, Rates};
+use crate::services::api::{Product, Region, fetch_rates_update};
+use crate::services::cache;
+use crate::utils::timeout::with_timeout;
 
-#[derive(Clone, PartialEq, Debug)]
-pub enum DataState {
-    Loading,
-    Loaded(Rc<Rates>),
-    Error(String),
-}
+pub type DataState = ResourceState<Rates>;
 
-impl DataState {
-    /// Returns the data if it is loaded
-    pub const fn data(&self) -> Option<&Rc<Rates>> {
-        match self {
-            Self::Loaded(rates) => Some(rates),
-            _ => None,
-        }
-    }
-}
+/// Rates state plus a manual `refresh` callback and the last successful fetch time
+pub type RatesHandle = ResourceHandle<Rates>;
 
+/// Fetches rates for `region` and `product`, starting from any fresh cached copy so the
+/// page is useful even if the first request fails. A fetch that hangs past
+/// `Config::FETCH_TIMEOUT_MS` resolves as an error instead of loading forever.
+///
+/// Re-polls only ask for slots newer than those already held; changing region or product,
+/// or holding data for over a day, fetches the full window again.
 #[hook]
-pub fn use_rates(region: Region) -> UseStateHandle<DataState> {
-    let state = use_state(|| DataState::Loading);
-    let trigger = use_state(|| 0u32); // Polling trigger
-
-    {
-        let state = state.clone();
-        let trigger_value = *trigger;
-
-        use_effect_with((trigger_value, region), move |(_, region)| {
-            let state = state.clone();
-            let trigger = trigger;
-            let region = *region;
-            let aborted = Rc::new(Cell::new(false));
-            let aborted_check = aborted.clone();
-
-            // Reset to loading when region changes
-            state.set(DataState::Loading);
+pub fn use_rates(region: Region, product: Product, interval_ms: u32) -> RatesHandle {
+    // Last fetched rates, stamped with their last full fetch, and who they were for
+    let held = use_mut_ref(|| None::<((Region, Product), CachedRates)>);
 
-            spawn_local(async move {
-                // Fetch data for the specified region
-                match fetch_rates_for_region(region).await {
-                    Ok(rates) if !aborted_check.get() => {
-                        state.set(DataState::Loaded(Rc::new(rates)));
-                    }
-                    Err(e) if !aborted_check.get() => {
-                        state.set(DataState::Error(e.to_string()));
-                    }
-                    _ => {} // Request was aborted, ignore result
-                }
-
-                // Schedule next poll if enabled
-                if crate::config::Config::ENABLE_AUTO_REFRESH && !aborted_check.get() {
-                    TimeoutFuture::new(crate::config::Config::POLLING_INTERVAL_MS).await;
-                    if !aborted_check.get() {
-                        trigger.set(*trigger + 1); // Trigger next fetch
-                    }
-                }
-            });
+    use_seeded_polled_resource(
+        (region, product),
+        |(region, product)| cache::load_rates(*region, *product),
+        move |key| async move {
+            let (region, product) = key;
+            let previous = held
+                .borrow()
+                .as_ref()
+                .filter(|(held_key, _)| *held_key == key)
+                .map(|(_, rates)| rates.clone());
+            let fetched = with_timeout(
+                fetch_rates_update(region, product, previous),
+                Config::FETCH_TIMEOUT_MS,
+            )
+            .await?;
+            cache::save_rates(region, product, &fetched.rates);
+            let rates = fetched.rates.clone();
+            *held.borrow_mut() = Some((key, fetched));
+            Ok(rates)
+        },
+        poll_interval(interval_ms),
+    )
+}
 
-            move || {
-                aborted.set(true);
-            }
-        });
-    }
+/// The app's single rates subscription plus the selected region and product, shared via
+/// `RatesProvider`
+#[derive(Clone, PartialEq)]
+pub struct RatesContext {
+    pub rates: RatesHandle,
+    pub region: RegionHandle,
+    pub product: ProductHandle,
+}
 
-    state
+/// Reads the shared rates subscription; must be called beneath a `RatesProvider`
+#[hook]
+pub fn use_rates_context() -> RatesContext {
+    use_context::<RatesContext>().expect("use_rates_context called outside a RatesProvider")
 }
@@ -0,0 +1,52 @@
+use gloo_storage::Storage;
+use yew::prelude::*;
+
+use crate::config::Config;
+use crate::services::storage;
+
+/// Handle returned by `use_refresh_interval` hook
+#[derive(Clone, PartialEq)]
+pub struct RefreshIntervalHandle {
+    pub interval_ms: u32,
+    pub set_interval: Callback<u32>,
+}
+
+/// Custom hook for the polling interval with localStorage persistence
+#[hook]
+pub fn use_refresh_interval() -> RefreshIntervalHandle {
+    // Load interval from localStorage, fallback to the configured default
+    let interval_ms =
+        use_state(|| load_interval_preference().unwrap_or(Config::POLLING_INTERVAL_MS));
+
+    // Effect: Persist interval to localStorage on change
+    {
+        let interval_value = *interval_ms;
+        use_effect_with(interval_value, move |interval_ms| {
+            save_interval_preference(*interval_ms);
+            || ()
+        });
+    }
+
+    // Set interval callback
+    let set_interval = {
+        let interval_ms = interval_ms.clone();
+        Callback::from(move |new_interval| interval_ms.set(new_interval))
+    };
+
+    RefreshIntervalHandle {
+        interval_ms: *interval_ms,
+        set_interval,
+    }
+}
+
+/// Load interval preference from localStorage, ignoring a zero interval
+fn load_interval_preference() -> Option<u32> {
+    storage::safe_get("refresh_interval_ms").filter(|ms| *ms > 0)
+}
+
+/// Save interval preference to localStorage
+fn save_interval_preference(interval_ms: u32) {
+    if let Err(e) = gloo_storage::LocalStorage::set("refresh_interval_ms", interval_ms) {
+        web_sys::console::warn_1(&format!("Failed to save refresh interval: {e:?}").into());
+    }
+}
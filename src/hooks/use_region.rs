@@ -1,6 +1,6 @@
-use gloo_storage::Storage;
 use yew::prelude::*;
 
+use crate::hooks::use_settings::use_settings;
 use crate::services::api::Region;
 
 /// Handle returned by `use_region` hook
@@ -10,41 +10,18 @@ pub struct RegionHandle {
     pub set_region: Callback<Region>,
 }
 
-/// Custom hook for region management with localStorage persistence
+/// Custom hook for region management, persisted in the stored settings
 #[hook]
 pub fn use_region() -> RegionHandle {
-    // Load region from localStorage, fallback to default (Region::C / London)
-    let region = use_state(|| load_region_preference().unwrap_or_default());
+    let settings = use_settings();
 
-    // Effect: Persist region to localStorage on change
-    {
-        let region_value = *region;
-        use_effect_with(region_value, move |region| {
-            save_region_preference(*region);
-            || ()
-        });
-    }
-
-    // Set region callback
     let set_region = {
-        let region = region.clone();
-        Callback::from(move |new_region| region.set(new_region))
+        let settings = settings.clone();
+        Callback::from(move |new_region| settings.update(move |s| s.region = new_region))
     };
 
     RegionHandle {
-        region: *region,
+        region: settings.get().region,
         set_region,
     }
 }
-
-/// Load region preference from localStorage
-fn load_region_preference() -> Option<Region> {
-    gloo_storage::LocalStorage::get("region").ok()
-}
-
-/// Save region preference to localStorage
-fn save_region_preference(region: Region) {
-    if let Err(e) = gloo_storage::LocalStorage::set("region", region) {
-        web_sys::console::warn_1(&format!("Failed to save region: {e:?}").into());
-    }
-}
@@ -0,0 +1,16 @@
+use super::use_polled_resource::{ResourceState, poll_interval, use_polled_resource};
+use crate::services::api::{Product, Region, fetch_current_prices_all_regions};
+use yew::prelude::*;
+
+pub type RegionPricesState = ResourceState<Vec<(Region, f64)>>;
+
+/// Current price of `product` in every region that responded
+#[hook]
+pub fn use_region_prices(product: Product, interval_ms: u32) -> UseStateHandle<RegionPricesState> {
+    use_polled_resource(
+        product,
+        fetch_current_prices_all_regions,
+        poll_interval(interval_ms),
+    )
+    .state
+}
@@ -0,0 +1,61 @@
+use std::rc::Rc;
+use yew::prelude::*;
+
+use crate::services::settings::{self, Settings};
+
+/// A change to one or more preferences
+pub type SettingsChange = Box<dyn FnOnce(&mut Settings)>;
+
+/// The preferences held by `SettingsProvider`. Each change is saved as it is applied, so
+/// nothing is written until a preference actually changes.
+#[derive(Debug, PartialEq)]
+pub struct SettingsState(Settings);
+
+impl SettingsState {
+    pub fn load() -> Self {
+        Self(settings::load())
+    }
+}
+
+impl Reducible for SettingsState {
+    type Action = SettingsChange;
+
+    fn reduce(self: Rc<Self>, change: Self::Action) -> Rc<Self> {
+        let mut next = self.0.clone();
+        change(&mut next);
+        if next == self.0 {
+            return self;
+        }
+        settings::save(&next);
+        Rc::new(Self(next))
+    }
+}
+
+/// Handle returned by `use_settings` hook, shared by every preference via
+/// `SettingsProvider`
+#[derive(Clone, PartialEq)]
+pub struct SettingsHandle {
+    state: UseReducerHandle<SettingsState>,
+}
+
+impl SettingsHandle {
+    pub const fn new(state: UseReducerHandle<SettingsState>) -> Self {
+        Self { state }
+    }
+
+    /// The current preferences
+    pub fn get(&self) -> &Settings {
+        &self.state.0
+    }
+
+    /// Changes one or more preferences, applied on top of any change still pending
+    pub fn update(&self, change: impl FnOnce(&mut Settings) + 'static) {
+        self.state.dispatch(Box::new(change));
+    }
+}
+
+/// Reads the shared preferences; must be called beneath a `SettingsProvider`
+#[hook]
+pub fn use_settings() -> SettingsHandle {
+    use_context::<SettingsHandle>().expect("use_settings called outside a SettingsProvider")
+}
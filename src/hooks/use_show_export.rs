@@ -0,0 +1,27 @@
+use yew::prelude::*;
+
+use crate::hooks::use_settings::use_settings;
+
+/// Handle returned by `use_show_export` hook
+#[derive(Clone, PartialEq)]
+pub struct ShowExportHandle {
+    pub show_export: bool,
+    pub set_show_export: Callback<bool>,
+}
+
+/// Custom hook for whether export prices are compared against import, persisted in the
+/// stored settings
+#[hook]
+pub fn use_show_export() -> ShowExportHandle {
+    let settings = use_settings();
+
+    let set_show_export = {
+        let settings = settings.clone();
+        Callback::from(move |enabled| settings.update(move |s| s.show_export = enabled))
+    };
+
+    ShowExportHandle {
+        show_export: settings.get().show_export,
+        set_show_export,
+    }
+}
@@ -0,0 +1,20 @@
+use super::use_polled_resource::{ResourceState, poll_interval, use_polled_resource};
+use crate::models::standing_charge::StandingCharge;
+use crate::services::api::{Product, Region, fetch_standing_charge};
+use yew::prelude::*;
+
+pub type StandingChargeState = ResourceState<StandingCharge>;
+
+#[hook]
+pub fn use_standing_charge(
+    region: Region,
+    product: Product,
+    interval_ms: u32,
+) -> UseStateHandle<StandingChargeState> {
+    use_polled_resource(
+        (region, product),
+        |(region, product)| fetch_standing_charge(region, product),
+        poll_interval(interval_ms),
+    )
+    .state
+}
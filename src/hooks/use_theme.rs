@@ -1,16 +1,9 @@
 use gloo::events::EventListener;
-use gloo_storage::Storage;
-use serde::{Deserialize, Serialize};
 use web_sys::wasm_bindgen::JsCast;
 use yew::prelude::*;
 
-/// Theme enum representing user's theme preference
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
-pub enum Theme {
-    Light,
-    Dark,
-    Auto, // Follow system preference
-}
+use crate::hooks::use_settings::use_settings;
+use crate::models::theme::Theme;
 
 /// Handle returned by `use_theme` hook
 #[derive(Clone, PartialEq)]
@@ -24,14 +17,15 @@ pub struct ThemeHandle {
 /// Custom hook for theme management
 #[hook]
 pub fn use_theme() -> ThemeHandle {
-    // Load user preference from localStorage, fallback to Auto
-    let theme = use_state(|| load_theme_preference().unwrap_or(Theme::Auto));
+    // User preference from the stored settings (Auto by default)
+    let settings = use_settings();
+    let theme = settings.get().theme;
 
     // Detect system preference
     let system_preference = use_state(detect_system_preference);
 
     // Compute effective theme (resolve Auto to Light/Dark)
-    let effective_theme = match *theme {
+    let effective_theme = match theme {
         Theme::Auto => *system_preference,
         other => other,
     };
@@ -53,35 +47,24 @@ pub fn use_theme() -> ThemeHandle {
         });
     }
 
-    // Effect: Persist theme to localStorage
-    {
-        let theme_value = *theme;
-        use_effect_with(theme_value, move |theme| {
-            save_theme_preference(*theme);
-            || ()
-        });
-    }
-
     // Toggle callback: switches between Light and Dark
     let toggle = {
-        let theme = theme.clone();
+        let settings = settings.clone();
         Callback::from(move |()| {
-            let new_theme = match *theme {
-                Theme::Dark => Theme::Light,
-                _ => Theme::Dark,
-            };
-            theme.set(new_theme);
+            settings.update(|s| {
+                s.theme = match s.theme {
+                    Theme::Dark => Theme::Light,
+                    _ => Theme::Dark,
+                };
+            });
         })
     };
 
     // Set theme callback
-    let set_theme = {
-        let theme = theme.clone();
-        Callback::from(move |new_theme| theme.set(new_theme))
-    };
+    let set_theme = Callback::from(move |new_theme| settings.update(move |s| s.theme = new_theme));
 
     ThemeHandle {
-        theme: *theme,
+        theme,
         effective_theme,
         toggle,
         set_theme,
@@ -115,25 +98,6 @@ fn apply_theme_to_dom(theme: Theme) {
     }
 }
 
-/// Load theme preference from localStorage
-fn load_theme_preference() -> Option<Theme> {
-    if let Ok(theme) = gloo_storage::LocalStorage::get("theme") {
-        Some(theme)
-    } else {
-        web_sys::console::warn_1(
-            &"localStorage unavailable or read failed, using default theme".into(),
-        );
-        None
-    }
-}
-
-/// Save theme preference to localStorage
-fn save_theme_preference(theme: Theme) {
-    if let Err(e) = gloo_storage::LocalStorage::set("theme", theme) {
-        web_sys::console::warn_1(&format!("Failed to save theme: {e:?}").into());
-    }
-}
-
 /// Setup `MediaQueryList` event listener for system preference changes
 fn setup_media_query_listener(setter: UseStateSetter<Theme>) -> Option<EventListener> {
     web_sys::window()
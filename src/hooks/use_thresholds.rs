@@ -0,0 +1,29 @@
+use yew::prelude::*;
+
+use crate::hooks::use_settings::use_settings;
+use crate::models::thresholds::PriceThresholds;
+
+/// Handle returned by `use_thresholds` hook
+#[derive(Clone, PartialEq)]
+pub struct ThresholdsHandle {
+    pub thresholds: PriceThresholds,
+    pub set_thresholds: Callback<PriceThresholds>,
+}
+
+/// Custom hook for the cheap/expensive price thresholds, persisted in the stored settings
+#[hook]
+pub fn use_thresholds() -> ThresholdsHandle {
+    let settings = use_settings();
+
+    let set_thresholds = {
+        let settings = settings.clone();
+        Callback::from(move |new_thresholds| {
+            settings.update(move |s| s.thresholds = new_thresholds);
+        })
+    };
+
+    ThresholdsHandle {
+        thresholds: settings.get().thresholds,
+        set_thresholds,
+    }
+}
@@ -0,0 +1,32 @@
+use gloo::events::EventListener;
+use yew::prelude::*;
+
+/// Tracks whether the page is visible, updating on the document's `visibilitychange`.
+///
+/// Reports visible when there is no document (e.g. outside a browser).
+#[hook]
+pub fn use_visibility() -> bool {
+    let visible = use_state(is_document_visible);
+
+    {
+        let visible = visible.clone();
+        use_effect_with((), move |()| {
+            let listener = web_sys::window()
+                .and_then(|w| w.document())
+                .map(|document| {
+                    EventListener::new(&document, "visibilitychange", move |_| {
+                        visible.set(is_document_visible());
+                    })
+                });
+            move || drop(listener)
+        });
+    }
+
+    *visible
+}
+
+fn is_document_visible() -> bool {
+    web_sys::window()
+        .and_then(|w| w.document())
+        .is_none_or(|document| !document.hidden())
+}
@@ -1,34 +1,142 @@
+use agile_dashboard::models::palette::ChartTheme;
+use agile_dashboard::models::rates::Granularity;
+use agile_dashboard::models::theme::Theme;
+use agile_dashboard::utils::time::london_today;
+use agile_dashboard::{components, config, hooks};
+use chrono::Utc;
+use std::rc::Rc;
 use yew::prelude::*;
 
-mod components;
-mod config;
-mod hooks;
-mod models;
-mod services;
-mod utils;
-
+use components::banner::compute_means;
 use components::chart::Chart;
 use components::status::Status;
 use components::summary::Summary;
 use components::tracker_display::TrackerDisplay;
-use components::{CarbonDisplay, CheapestPeriod, RegionSelector, ThemeToggle, TraceBanner};
+use components::{
+    BestTime, BlockPicker, CarbonChart, CarbonDisplay, ChartTypeToggle, CheapestPeriod,
+    CostCalculator, CustomRegionSelector, DirectionToggle, ErrorNotice, ExportButton,
+    ExportOverlayToggle, GapBanner, GranularityToggle, HistoricalChart, OfflineBanner,
+    PaletteToggle, PlungeBanner, PostcodeLookup, PriceAlertToggle, PriceHeatmap, PriceUnitToggle,
+    ProductSelector, RatesImport, RatesProvider, Records, RefreshIndicator, RefreshSettings,
+    RegionComparison, RegionSelector, SavingsEstimate, SettingsProvider, SlotCountdown,
+    TariffDirection, ThemeToggle, ThresholdSettings, TraceBanner, WeekdayInsight,
+};
+use config::Config;
 use hooks::use_carbon::{CarbonDataState, use_carbon_intensity};
+use hooks::use_carbon_forecast::use_carbon_forecast;
+use hooks::use_chart_type::use_chart_type;
+use hooks::use_export_rates::use_export_rates;
+use hooks::use_favourite_regions::use_favourite_regions;
 use hooks::use_historical_rates::use_historical_rates;
-use hooks::use_rates::use_rates;
-use hooks::use_region::use_region;
-use hooks::use_theme::{Theme, use_theme};
-use hooks::use_tracker::use_tracker_rates;
+use hooks::use_palette::use_palette;
+use hooks::use_polled_resource::poll_interval;
+use hooks::use_price_alert::use_price_alert;
+use hooks::use_price_unit::use_price_unit;
+use hooks::use_rates::{DataState, RatesContext, use_rates_context};
+use hooks::use_refresh_interval::{RefreshIntervalHandle, use_refresh_interval};
+use hooks::use_show_export::use_show_export;
+use hooks::use_standing_charge::use_standing_charge;
+use hooks::use_theme::use_theme;
+use hooks::use_thresholds::use_thresholds;
+use hooks::use_tracker::{use_tracker_gas_rates, use_tracker_rates};
 
 #[function_component(App)]
 fn app() -> Html {
-    let region_handle = use_region();
+    let interval_handle = use_refresh_interval();
+
+    html! {
+        <SettingsProvider>
+            <RatesProvider interval_ms={interval_handle.interval_ms}>
+                <Dashboard {interval_handle} />
+            </RatesProvider>
+        </SettingsProvider>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct DashboardProps {
+    interval_handle: RefreshIntervalHandle,
+}
+
+#[function_component(Dashboard)]
+fn dashboard(props: &DashboardProps) -> Html {
+    let RatesContext {
+        rates: rates_handle,
+        region: region_handle,
+        product: product_handle,
+    } = use_rates_context();
     let region = region_handle.region;
+    let product = product_handle.product;
+
+    let interval_handle = &props.interval_handle;
+    let interval_ms = interval_handle.interval_ms;
 
-    let state = use_rates(region);
-    let historical_state = use_historical_rates();
-    let tracker_state = use_tracker_rates(region);
-    let carbon_state = use_carbon_intensity();
+    let state = rates_handle.state.clone();
+    // Dim the previous region's rates while the new region loads
+    let stale_class = rates_handle.stale.then_some("stale");
+    // Pasted rates replace what is on screen until the next poll
+    let on_import = {
+        let state = rates_handle.state.clone();
+        Callback::from(move |rates| state.set(DataState::Loaded(Rc::new(rates))))
+    };
+    // The next scheduled re-poll, if auto-refresh is on
+    let next_check = poll_interval(interval_ms)
+        .zip(rates_handle.last_updated)
+        .map(|(ms, at)| at + chrono::Duration::milliseconds(i64::from(ms)));
+    let historical_state = use_historical_rates(region, product, interval_ms);
+    let tracker_state = use_tracker_rates(region, interval_ms);
+    let tracker_gas_state = use_tracker_gas_rates(region, interval_ms);
+    let standing_state = use_standing_charge(region, product, interval_ms);
+    let carbon = use_carbon_intensity(region, interval_ms);
+    let carbon_state = carbon.state.clone();
+    let forecast_state = use_carbon_forecast(interval_ms);
     let theme_handle = use_theme();
+    let thresholds_handle = use_thresholds();
+    let thresholds = thresholds_handle.thresholds;
+    let chart_type_handle = use_chart_type();
+    let palette_handle = use_palette();
+    let favourites_handle = use_favourite_regions();
+    let palette = palette_handle.palette;
+    let price_unit_handle = use_price_unit();
+    let price_alert = use_price_alert(&rates_handle);
+    let unit = price_unit_handle.price_unit;
+    let direction = use_state(TariffDirection::default);
+    let exporting = *direction == TariffDirection::Export;
+    let show_export_handle = use_show_export();
+    // Export prices are only fetched when shown or compared against import
+    let export_state = use_export_rates(
+        region,
+        product,
+        exporting || show_export_handle.show_export,
+        interval_ms,
+    );
+    // Compared export prices, while the import view is shown
+    let export_overlay = (!exporting && show_export_handle.show_export)
+        .then(|| export_state.data().cloned())
+        .flatten();
+    // The summary and chart follow the import/export toggle; everything else is import
+    let shown_state = if exporting {
+        (*export_state).clone()
+    } else {
+        (*state).clone()
+    };
+    let on_direction = {
+        let direction = direction.clone();
+        Callback::from(move |d| direction.set(d))
+    };
+    let granularity = use_state(Granularity::default);
+    let on_granularity = {
+        let granularity = granularity.clone();
+        Callback::from(move |g| granularity.set(g))
+    };
+
+    // Keep the browser tab title in sync with the configured branding
+    use_effect_with((), |()| {
+        if let Some(document) = web_sys::window().and_then(|w| w.document()) {
+            document.set_title(Config::app_title());
+        }
+        || ()
+    });
 
     // Extract all historical rate values for banner (31 days × 48 half-hours = ~1488 points)
     let banner_values = use_memo(historical_state.clone(), |state| {
@@ -38,34 +146,149 @@ fn app() -> Html {
         }
     });
 
+    // Mean price per half-hour over the last week, for the typical-day banner
+    let typical_day = use_memo(historical_state.clone(), |state| {
+        state.data().map_or_else(Vec::new, |rates| {
+            let days: Vec<_> = rates
+                .to_daily_matrix()
+                .into_iter()
+                .map(|(_, row)| row)
+                .collect();
+            compute_means(&days[days.len().saturating_sub(Config::TYPICAL_DAY_DAYS)..])
+        })
+    });
+
+    // Banner colors match the charts'
+    let chart_theme = ChartTheme::for_dark_mode(theme_handle.effective_theme == Theme::Dark);
+
     html! {
         <div class="app-container">
             <header class="app-header">
                 <CheapestPeriod />
-                <h1>{"Octopus Agile Dashboard"}</h1>
-                <RegionSelector region={region} on_change={region_handle.set_region.clone()} />
-                <ThemeToggle />
+                <h1>{Config::app_title()}</h1>
+                if let Some(rates) = state.data() {
+                    <SlotCountdown rates={rates.clone()} />
+                }
+                if Config::CUSTOM_REGION_SELECTOR {
+                    <CustomRegionSelector
+                        region={region}
+                        on_change={region_handle.set_region.clone()}
+                        favourites={favourites_handle.favourites.clone()}
+                        on_toggle_favourite={favourites_handle.toggle.clone()}
+                    />
+                } else {
+                    <RegionSelector
+                        region={region}
+                        on_change={region_handle.set_region.clone()}
+                        favourites={favourites_handle.favourites.clone()}
+                    />
+                }
+                <PriceAlertToggle
+                    threshold={price_alert.threshold}
+                    on_change={price_alert.set_threshold.clone()}
+                />
+                <div class="header-toggles">
+                    <PaletteToggle {palette} on_change={palette_handle.set_palette.clone()} />
+                    <ThemeToggle />
+                </div>
             </header>
 
             <main class="app-main">
+                <OfflineBanner
+                    last_updated={rates_handle.last_updated}
+                    has_data={state.data().is_some()}
+                />
+                if let Some(rates) = state.data() {
+                    <GapBanner rates={rates.clone()} />
+                    <PlungeBanner rates={rates.clone()} />
+                }
+
                 // Banner section - only show when historical data is loaded and values exist
                 if let Some(_rates) = historical_state.data() {
                     if !banner_values.is_empty() {
                         <section class="banner-section">
+                            <RefreshIndicator active={historical_state.is_refreshing()} />
                             <TraceBanner
                                 values={(*banner_values).clone()}
+                                color={chart_theme.accent.to_string()}
                                 height={100}
                                 stroke_width={2.0}
                                 smooth={true}
                             />
+                            if !typical_day.is_empty() {
+                                <p class="banner-caption">
+                                    {format!("Average day over the last {} days", Config::TYPICAL_DAY_DAYS)}
+                                </p>
+                                <TraceBanner
+                                    values={(*typical_day).clone()}
+                                    height={60}
+                                    color={chart_theme.muted.to_string()}
+                                />
+                            }
                         </section>
                     }
                 }
 
                 if let Some(rates) = state.data() {
-                    <section class="data-section">
-                        <h2>{"Agile Electricity"}</h2>
-                        <Summary rates={rates.clone()} />
+                    <section class={classes!("data-section", stale_class)}>
+                        <RefreshIndicator active={state.is_refreshing()} />
+                        <h2>
+                            {if exporting { "Agile Outgoing" } else { "Agile Electricity" }}
+                            if shown_state.data().is_some_and(|shown| shown.has_tomorrow()) {
+                                <span class="tomorrow-badge">{"Tomorrow's prices available"}</span>
+                            }
+                        </h2>
+                        <DirectionToggle direction={*direction} on_change={on_direction} />
+                        <PriceUnitToggle {unit} on_change={price_unit_handle.set_price_unit.clone()} />
+                        if !exporting {
+                            <ExportOverlayToggle
+                                enabled={show_export_handle.show_export}
+                                on_change={show_export_handle.set_show_export.clone()}
+                            />
+                        }
+                        {
+                            match &shown_state {
+                                DataState::Loaded(shown) | DataState::Refreshing(shown) => html! {
+                                    <Summary
+                                        rates={shown.clone()}
+                                        standing_charge={
+                                            (!exporting)
+                                                .then(|| standing_state.data().map(|c| c.value_inc_vat))
+                                                .flatten()
+                                        }
+                                        cheap_alert_threshold={
+                                            price_alert.threshold.filter(|_| !exporting)
+                                        }
+                                        {unit}
+                                        direction={*direction}
+                                        export_price={
+                                            export_overlay
+                                                .as_ref()
+                                                .and_then(|export| export.rate_at(Utc::now()))
+                                                .map(|r| r.value_inc_vat)
+                                        }
+                                        {next_check}
+                                    />
+                                },
+                                DataState::Loading => html! { <p>{"Loading export prices..."}</p> },
+                                DataState::Error(err) => html! {
+                                    <ErrorNotice error={err.clone()} context="Error loading export prices" />
+                                },
+                            }
+                        }
+                        if !exporting {
+                            <SavingsEstimate
+                                rates={rates.clone()}
+                                standing_charge={standing_state.data().map(|c| c.value_inc_vat)}
+                            />
+                            <BlockPicker rates={rates.clone()} />
+                            if let Some(forecast) = forecast_state.data() {
+                                <BestTime rates={rates.clone()} forecast={forecast.clone()} />
+                            }
+                            if let Some(historical) = historical_state.data() {
+                                <WeekdayInsight rates={rates.clone()} historical={historical.clone()} />
+                            }
+                        }
                     </section>
 
                     {
@@ -76,25 +299,112 @@ fn app() -> Html {
                                     <p>{"Loading tracker data..."}</p>
                                 </section>
                             },
-                            hooks::use_tracker::TrackerDataState::Loaded(tracker_rates) => html! {
+                            hooks::use_tracker::TrackerDataState::Loaded(tracker_rates)
+                            | hooks::use_tracker::TrackerDataState::Refreshing(tracker_rates) => html! {
                                 <section class="tracker-section">
-                                    <h2>{"Tracker Electricity"}</h2>
-                                    <TrackerDisplay rates={tracker_rates.clone()} />
+                                    <RefreshIndicator active={tracker_state.is_refreshing()} />
+                                    <h2>
+                                        {
+                                            if tracker_gas_state.data().is_some() {
+                                                "Tracker Electricity & Gas"
+                                            } else {
+                                                "Tracker Electricity"
+                                            }
+                                        }
+                                    </h2>
+                                    <TrackerDisplay
+                                        rates={tracker_rates.clone()}
+                                        gas={tracker_gas_state.data().cloned()}
+                                        {unit}
+                                    />
                                 </section>
                             },
                             hooks::use_tracker::TrackerDataState::Error(err) => html! {
                                 <section class="tracker-section">
                                     <h2>{"Tracker Electricity"}</h2>
-                                    <p class="error">{format!("Error loading tracker data: {}", err)}</p>
+                                    <ErrorNotice error={err.clone()} context="Error loading tracker data" />
                                 </section>
                             },
                         }
                     }
 
                     // Chart
-                    <section class="chart-section">
+                    <section class={classes!("chart-section", stale_class)}>
+                        <RefreshIndicator active={state.is_refreshing()} />
                         <h2>{"Energy Price Distribution"}</h2>
-                        <Chart rates={rates.clone()} dark_mode={theme_handle.effective_theme == Theme::Dark} />
+                        <ChartTypeToggle
+                            chart_type={chart_type_handle.chart_type}
+                            on_change={chart_type_handle.set_chart_type.clone()}
+                        />
+                        <GranularityToggle granularity={*granularity} on_change={on_granularity} />
+                        if let Some(shown) = shown_state.data() {
+                            {
+                                match shown.stats_for_date(london_today()) {
+                                    Some(stats) if shown.is_flat(Config::FLAT_PRICE_TOLERANCE) => html! {
+                                        <p class="chart-placeholder">
+                                            {format!("Prices are flat around {:.2}p today", stats.avg)}
+                                        </p>
+                                    },
+                                    // Thresholds describe import prices, so export is left unshaded
+                                    _ => html! {
+                                        <Chart
+                                            rates={shown.clone()}
+                                            dark_mode={theme_handle.effective_theme == Theme::Dark}
+                                            cheap_threshold={thresholds.cheap.filter(|_| !exporting)}
+                                            expensive_threshold={thresholds.expensive.filter(|_| !exporting)}
+                                            chart_type={chart_type_handle.chart_type}
+                                            {palette}
+                                            granularity={*granularity}
+                                            export_rates={export_overlay.clone()}
+                                            direction={*direction}
+                                        />
+                                    },
+                                }
+                            }
+                        }
+                        if !exporting {
+                            <ThresholdSettings
+                                {thresholds}
+                                on_change={thresholds_handle.set_thresholds.clone()}
+                            />
+                        }
+                    </section>
+
+                    if let Some(historical) = historical_state.data() {
+                        <section class="chart-section">
+                            <RefreshIndicator active={historical_state.is_refreshing()} />
+                            <h2>{"Price History"}</h2>
+                            <HistoricalChart
+                                rates={historical.clone()}
+                                dark_mode={theme_handle.effective_theme == Theme::Dark}
+                            />
+                            <PriceHeatmap
+                                rates={historical.clone()}
+                                dark_mode={theme_handle.effective_theme == Theme::Dark}
+                            />
+                            <Records historical={historical.clone()} />
+                        </section>
+                    }
+
+                    if let Some(historical) = historical_state.data() {
+                        <section class="comparison-section">
+                            <h2>{"What Would My Usage Cost?"}</h2>
+                            <CostCalculator
+                                rates={historical.clone()}
+                                {region}
+                                standing_charge={
+                                    // Either may still hold the previous region's data mid-fetch
+                                    (!historical_state.is_refreshing() && !standing_state.is_refreshing())
+                                        .then(|| standing_state.data().map(|c| c.value_inc_vat))
+                                        .flatten()
+                                }
+                            />
+                        </section>
+                    }
+
+                    <section class="comparison-section">
+                        <h2>{"Prices Across Regions"}</h2>
+                        <RegionComparison {interval_ms} {product} selected={region} />
                     </section>
 
                     // Carbon tracking
@@ -106,16 +416,33 @@ fn app() -> Html {
                                     <p>{"Loading carbon intensity data..."}</p>
                                 </section>
                             },
-                            CarbonDataState::Loaded(carbon_data) => html! {
+                            CarbonDataState::Loaded(carbon_data)
+                            | CarbonDataState::Refreshing(carbon_data) => html! {
                                 <section class="carbon-section">
+                                    <RefreshIndicator active={carbon_state.is_refreshing()} />
                                     <h2>{"Grid Carbon Intensity"}</h2>
-                                    <CarbonDisplay data={carbon_data.clone()} />
+                                    <CarbonDisplay
+                                        data={carbon_data.clone()}
+                                        forecast={forecast_state.data().cloned()}
+                                        {palette}
+                                    />
+                                    if !carbon_data.periods.is_empty() {
+                                        <CarbonChart
+                                            data={carbon_data.clone()}
+                                            dark_mode={theme_handle.effective_theme == Theme::Dark}
+                                            {palette}
+                                        />
+                                    }
                                 </section>
                             },
                             CarbonDataState::Error(err) => html! {
                                 <section class="carbon-section">
                                     <h2>{"Grid Carbon Intensity"}</h2>
-                                    <p class="error">{format!("Error loading carbon data: {}", err)}</p>
+                                    <ErrorNotice
+                                        error={err.clone()}
+                                        context="Error loading carbon data"
+                                        on_retry={carbon.refresh.clone()}
+                                    />
                                 </section>
                             },
                         }
@@ -126,7 +453,17 @@ fn app() -> Html {
             <footer class="app-footer">
                 <section class="status-section">
                     <h2>{"API Status"}</h2>
-                    <Status state={(*state).clone()} />
+                    <Status />
+                    <RefreshSettings
+                        interval_ms={interval_ms}
+                        on_change={interval_handle.set_interval.clone()}
+                    />
+                    <ProductSelector {product} on_change={product_handle.set_product.clone()} />
+                    <PostcodeLookup on_change={region_handle.set_region.clone()} />
+                    if let Some(rates) = state.data() {
+                        <ExportButton rates={rates.clone()} {region} />
+                    }
+                    <RatesImport on_import={on_import} />
                 </section>
             </footer>
 
@@ -1,3 +1,6 @@
+use super::palette::Palette;
+use super::rates::slots_for;
+use crate::utils::time::london_time;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -26,6 +29,32 @@ impl IntensityIndex {
         }
     }
 
+    /// Returns the chart color for this category, green through red
+    pub const fn color(&self) -> &'static str {
+        self.color_for(Palette::Default)
+    }
+
+    /// Returns the chart color for this category in `palette`. The color-blind-safe
+    /// scale runs from Okabe-Ito blue through yellow to vermillion.
+    pub const fn color_for(&self, palette: Palette) -> &'static str {
+        match palette {
+            Palette::Default => match self {
+                Self::VeryLow => "#16a34a",
+                Self::Low => "#65a30d",
+                Self::Moderate => "#ca8a04",
+                Self::High => "#ea580c",
+                Self::VeryHigh => "#dc2626",
+            },
+            Palette::ColorBlindSafe => match self {
+                Self::VeryLow => "#0072b2",
+                Self::Low => "#56b4e9",
+                Self::Moderate => "#f0e442",
+                Self::High => "#e69f00",
+                Self::VeryHigh => "#d55e00",
+            },
+        }
+    }
+
     /// Returns human-readable label
     pub const fn label(&self) -> &'static str {
         match self {
@@ -108,21 +137,119 @@ impl CarbonIntensityData {
     }
 }
 
+/// Chart series for a set of periods: ("HH:MM" London start time labels, forecasts)
+pub fn series_data(carbon: &[CarbonIntensityData]) -> (Vec<String>, Vec<u32>) {
+    carbon
+        .iter()
+        .map(|period| {
+            (
+                london_time(period.from).format("%H:%M").to_string(),
+                period.intensity.forecast,
+            )
+        })
+        .unzip()
+}
+
+/// Returns the upcoming period (starting at or after `now`) with the lowest forecast.
+/// Ties resolve to the earliest period.
+pub fn greenest_upcoming(
+    carbon: &[CarbonIntensityData],
+    now: DateTime<Utc>,
+) -> Option<&CarbonIntensityData> {
+    carbon
+        .iter()
+        .filter(|period| period.from >= now)
+        .min_by_key(|period| (period.intensity.forecast, period.from))
+}
+
+/// How far ahead `CarbonForecast::greenest_window` looks
+const GREENEST_HORIZON_HOURS: i64 = 24;
+
+/// Half-hourly intensity forecast running forward from a start time, as returned by
+/// the `fw48h` endpoint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CarbonForecast {
+    periods: Vec<CarbonIntensityData>,
+}
+
+impl CarbonForecast {
+    /// Wraps forecast periods, ordering them by start time
+    pub fn new(mut periods: Vec<CarbonIntensityData>) -> Self {
+        periods.sort_by_key(|period| period.from);
+        Self { periods }
+    }
+
+    pub fn periods(&self) -> &[CarbonIntensityData] {
+        &self.periods
+    }
+
+    /// Lowest-average-intensity contiguous block of `duration` within the first 24 hours
+    /// of the forecast. Returns the block's start, end and mean intensity (gCO₂/kWh).
+    ///
+    /// The duration is rounded up to whole half-hour periods and the block may not span
+    /// a gap. Actual values are used where present, otherwise the forecast; ties go to
+    /// the earliest block.
+    pub fn greenest_window(
+        &self,
+        duration: chrono::Duration,
+    ) -> Option<(DateTime<Utc>, DateTime<Utc>, f64)> {
+        let slots = slots_for(duration)?;
+        let horizon = self.periods.first()?.from + chrono::Duration::hours(GREENEST_HORIZON_HOURS);
+        let end = self.periods.partition_point(|period| period.to <= horizon);
+
+        self.periods[..end]
+            .windows(slots)
+            .filter(|window| window.windows(2).all(|pair| pair[0].to == pair[1].from))
+            .map(|window| {
+                let total: u32 = window.iter().map(CarbonIntensityData::best_intensity).sum();
+                let mean = f64::from(total) / slots as f64;
+                (window[0].from, window[slots - 1].to, mean)
+            })
+            .min_by(|a, b| a.2.total_cmp(&b.2))
+    }
+}
+
 /// Container for current and next period carbon intensity data
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CarbonIntensity {
     pub latest_intensity: CarbonIntensityData,
     pub next: CarbonIntensityData,
+    /// All periods returned for the day, in API order
+    pub periods: Vec<CarbonIntensityData>,
+    /// Grid region the figures cover; `None` for national (GB) data
+    pub region: Option<String>,
 }
 
 impl CarbonIntensity {
-    pub const fn new(latest_intensity: CarbonIntensityData, next: CarbonIntensityData) -> Self {
+    pub const fn new(
+        latest_intensity: CarbonIntensityData,
+        next: CarbonIntensityData,
+        periods: Vec<CarbonIntensityData>,
+    ) -> Self {
         Self {
             latest_intensity,
             next,
+            periods,
+            region: None,
         }
     }
 
+    /// Marks the figures as covering a named grid region
+    pub fn with_region(mut self, region: String) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Forecast series across all of today's periods
+    pub fn series_data(&self) -> (Vec<String>, Vec<u32>) {
+        series_data(&self.periods)
+    }
+
+    /// Returns the lowest-forecast period still to come today
+    pub fn greenest_upcoming(&self, now: DateTime<Utc>) -> Option<&CarbonIntensityData> {
+        greenest_upcoming(&self.periods, now)
+    }
+
     /// Returns the last actual intensity
     pub fn latest_intensity(&self) -> u32 {
         self.latest_intensity.best_intensity()
@@ -163,3 +290,184 @@ impl CarbonIntensity {
         self.latest_intensity.has_actual()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const INDICES: [IntensityIndex; 5] = [
+        IntensityIndex::VeryLow,
+        IntensityIndex::Low,
+        IntensityIndex::Moderate,
+        IntensityIndex::High,
+        IntensityIndex::VeryHigh,
+    ];
+
+    #[test]
+    fn test_index_colors_are_distinct_in_each_palette() {
+        for palette in [Palette::Default, Palette::ColorBlindSafe] {
+            let mut colors: Vec<&str> = INDICES.iter().map(|i| i.color_for(palette)).collect();
+            assert!(colors.iter().all(|c| c.len() == 7 && c.starts_with('#')));
+            colors.sort_unstable();
+            colors.dedup();
+            assert_eq!(colors.len(), INDICES.len(), "{palette:?}");
+        }
+    }
+
+    #[test]
+    fn test_palettes_differ_and_default_matches_color() {
+        for index in INDICES {
+            assert_eq!(index.color(), index.color_for(Palette::Default));
+            assert_ne!(
+                index.color_for(Palette::Default),
+                index.color_for(Palette::ColorBlindSafe)
+            );
+        }
+    }
+
+    fn make_period(hour: u32, forecast: u32) -> CarbonIntensityData {
+        CarbonIntensityData {
+            from: Utc.with_ymd_and_hms(2026, 1, 12, hour, 0, 0).unwrap(),
+            to: Utc.with_ymd_and_hms(2026, 1, 12, hour, 30, 0).unwrap(),
+            intensity: Intensity {
+                forecast,
+                actual: None,
+                index: IntensityIndex::Moderate,
+            },
+        }
+    }
+
+    #[test]
+    fn test_greenest_window_lowest_mean_block() {
+        let forecast = CarbonForecast::new(
+            [200, 150, 90, 80, 120, 60]
+                .into_iter()
+                .zip(0..)
+                .map(|(forecast, i)| {
+                    let from = Utc.with_ymd_and_hms(2026, 1, 12, 0, 0, 0).unwrap()
+                        + chrono::Duration::minutes(30 * i);
+                    CarbonIntensityData {
+                        from,
+                        to: from + chrono::Duration::minutes(30),
+                        ..make_period(0, forecast)
+                    }
+                })
+                .collect(),
+        );
+
+        // 01:00–02:00 averages 85, beating the lone 60 next to a 120
+        let (start, end, mean) = forecast
+            .greenest_window(chrono::Duration::hours(1))
+            .unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2026, 1, 12, 1, 0, 0).unwrap());
+        assert_eq!(end, Utc.with_ymd_and_hms(2026, 1, 12, 2, 0, 0).unwrap());
+        assert!((mean - 85.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_greenest_window_prefers_actual_and_skips_gaps() {
+        let mut measured = make_period(1, 5);
+        measured.intensity.actual = Some(300);
+        // 01:00 was forecast greenest but measured dirtier. Each period ends half an hour
+        // before the next begins, so no hour-long block exists.
+        let forecast = CarbonForecast::new(vec![
+            make_period(3, 10),
+            measured,
+            make_period(2, 100),
+            make_period(5, 10),
+        ]);
+
+        assert!(
+            forecast
+                .greenest_window(chrono::Duration::hours(1))
+                .is_none()
+        );
+        let (start, _, mean) = forecast
+            .greenest_window(chrono::Duration::minutes(30))
+            .unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2026, 1, 12, 3, 0, 0).unwrap());
+        assert!((mean - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_greenest_window_limited_to_24_hours() {
+        let start = Utc.with_ymd_and_hms(2026, 1, 12, 0, 0, 0).unwrap();
+        let period = |offset_hours, forecast| CarbonIntensityData {
+            from: start + chrono::Duration::hours(offset_hours),
+            to: start + chrono::Duration::hours(offset_hours) + chrono::Duration::minutes(30),
+            ..make_period(0, forecast)
+        };
+        let forecast = CarbonForecast::new(vec![period(0, 150), period(30, 20)]);
+
+        let (from, _, _) = forecast
+            .greenest_window(chrono::Duration::minutes(30))
+            .unwrap();
+        assert_eq!(from, start);
+        assert!(
+            CarbonForecast::new(vec![])
+                .greenest_window(chrono::Duration::hours(2))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_series_data_labels_in_london_time() {
+        // 2026-07-01 is in BST, so 10:00 UTC is 11:00 in London
+        let summer = CarbonIntensityData {
+            from: Utc.with_ymd_and_hms(2026, 7, 1, 10, 0, 0).unwrap(),
+            to: Utc.with_ymd_and_hms(2026, 7, 1, 10, 30, 0).unwrap(),
+            ..make_period(10, 70)
+        };
+        let periods = vec![make_period(9, 150), make_period(10, 120), summer];
+
+        let (labels, values) = series_data(&periods);
+        assert_eq!(labels, vec!["09:00", "10:00", "11:00"]);
+        assert_eq!(values, vec![150, 120, 70]);
+    }
+
+    #[test]
+    fn test_series_data_empty() {
+        let (labels, values) = series_data(&[]);
+        assert!(labels.is_empty() && values.is_empty());
+    }
+
+    #[test]
+    fn test_greenest_upcoming_selects_lowest_future_forecast() {
+        let periods = vec![
+            make_period(10, 150),
+            make_period(11, 90),
+            make_period(12, 120),
+        ];
+        let now = Utc.with_ymd_and_hms(2026, 1, 12, 9, 45, 0).unwrap();
+
+        let greenest = greenest_upcoming(&periods, now).unwrap();
+        assert_eq!(greenest.intensity.forecast, 90);
+    }
+
+    #[test]
+    fn test_greenest_upcoming_ignores_past_periods() {
+        let periods = vec![
+            make_period(8, 40),
+            make_period(10, 150),
+            make_period(11, 130),
+            make_period(12, 130),
+        ];
+        let now = Utc.with_ymd_and_hms(2026, 1, 12, 10, 0, 0).unwrap();
+
+        // 08:00 is greener but already passed; ties go to the earlier slot
+        let greenest = greenest_upcoming(&periods, now).unwrap();
+        assert_eq!(
+            greenest.from,
+            Utc.with_ymd_and_hms(2026, 1, 12, 11, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_greenest_upcoming_none_when_all_past() {
+        let periods = vec![make_period(8, 40)];
+        let now = Utc.with_ymd_and_hms(2026, 1, 12, 9, 0, 0).unwrap();
+
+        assert!(greenest_upcoming(&periods, now).is_none());
+    }
+}
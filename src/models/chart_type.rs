@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// How the price chart draws its series
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ChartType {
+    /// One bar per slot, colored by price band
+    #[default]
+    Bar,
+    /// A line through the slot prices, easier to read across several days
+    Line,
+}
+
+impl ChartType {
+    /// Returns human-readable label
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Bar => "Bars",
+            Self::Line => "Line",
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use super::error::AppError;
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+/// Metered usage for one half-hour slot
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsumptionReading {
+    pub start: DateTime<Utc>,
+    pub kwh: f64,
+}
+
+/// Half-hourly usage loaded from a smart meter export
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsumptionProfile {
+    readings: Vec<ConsumptionReading>,
+}
+
+/// What a consumption profile cost against a set of rates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostBreakdown {
+    /// Usage (kWh) in readings with a matching rate
+    pub total_kwh: f64,
+    /// Cost in pounds of those readings
+    pub total_cost: f64,
+    /// Readings outside the loaded rates, left out of the totals
+    pub unmatched: usize,
+    /// London days with at least one matched reading
+    pub days: usize,
+}
+
+impl CostBreakdown {
+    /// Average unit price in pence, or `None` if no usage was matched
+    pub fn avg_p_per_kwh(&self) -> Option<f64> {
+        (self.total_kwh > 0.0).then(|| self.total_cost * 100.0 / self.total_kwh)
+    }
+
+    /// Cost in pounds with a daily standing charge (pence) added for each day covered
+    pub fn total_with_standing(&self, standing_charge_p: f64) -> f64 {
+        (self.days as f64).mul_add(standing_charge_p / 100.0, self.total_cost)
+    }
+}
+
+impl ConsumptionProfile {
+    /// Creates a profile, sorting readings by start time
+    pub fn new(mut readings: Vec<ConsumptionReading>) -> Self {
+        readings.sort_by_key(|r| r.start);
+        Self { readings }
+    }
+
+    /// Parses a CSV of half-hourly readings.
+    ///
+    /// With a header row, the timestamp column is the first named like "start", "time" or
+    /// "date" and the usage column the first naming "kwh" or "consumption", so Octopus's
+    /// `Consumption (kWh), Start, End` export works as-is. Without one, the columns are
+    /// `timestamp,kwh`. Timestamps are RFC 3339; any without an offset are taken as UTC.
+    pub fn from_csv(csv: &str) -> Result<Self, AppError> {
+        let mut lines = csv
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+
+        let Some((first_no, first)) = lines.next() else {
+            return Err(AppError::ParseError("No readings in CSV".to_string()));
+        };
+
+        let mut readings = Vec::new();
+        let columns = match parse_row(first, (0, 1)) {
+            Some(reading) => {
+                readings.push(reading);
+                (0, 1)
+            }
+            None => column_indices(first).ok_or_else(|| {
+                AppError::ParseError(format!("Unrecognised CSV header on line {}", first_no + 1))
+            })?,
+        };
+
+        for (no, line) in lines {
+            let reading = parse_row(line, columns).ok_or_else(|| {
+                AppError::ParseError(format!("Invalid reading on line {}: {line}", no + 1))
+            })?;
+            readings.push(reading);
+        }
+
+        if readings.is_empty() {
+            return Err(AppError::ParseError("No readings in CSV".to_string()));
+        }
+        Ok(Self::new(readings))
+    }
+
+    /// Readings in chronological order
+    pub fn readings(&self) -> &[ConsumptionReading] {
+        &self.readings
+    }
+}
+
+/// `(timestamp, kwh)` column positions named by a header row
+fn column_indices(header: &str) -> Option<(usize, usize)> {
+    let names: Vec<String> = header
+        .split(',')
+        .map(|name| name.trim().trim_matches('"').to_lowercase())
+        .collect();
+    let timestamp = names
+        .iter()
+        .position(|n| n.contains("start") || n.contains("time") || n.contains("date"))?;
+    let kwh = names
+        .iter()
+        .position(|n| n.contains("kwh") || n.contains("consumption"))?;
+    Some((timestamp, kwh))
+}
+
+fn parse_row(line: &str, (timestamp, kwh): (usize, usize)) -> Option<ConsumptionReading> {
+    let fields: Vec<&str> = line
+        .split(',')
+        .map(|f| f.trim().trim_matches('"'))
+        .collect();
+    let start = parse_timestamp(fields.get(timestamp)?)?;
+    let kwh = fields
+        .get(kwh)?
+        .parse::<f64>()
+        .ok()
+        .filter(|v| v.is_finite())?;
+    Some(ConsumptionReading { start, kwh })
+}
+
+fn parse_timestamp(field: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(field)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"]
+                .iter()
+                .find_map(|format| NaiveDateTime::parse_from_str(field, format).ok())
+                .map(|naive| naive.and_utc())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_parses_headerless_csv() {
+        let profile =
+            ConsumptionProfile::from_csv("2024-01-15T00:30:00Z,0.4\n2024-01-15T00:00:00Z,0.25\n")
+                .unwrap();
+
+        assert_eq!(
+            profile.readings(),
+            &[
+                ConsumptionReading {
+                    start: Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap(),
+                    kwh: 0.25,
+                },
+                ConsumptionReading {
+                    start: Utc.with_ymd_and_hms(2024, 1, 15, 0, 30, 0).unwrap(),
+                    kwh: 0.4,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parses_octopus_export_with_offsets() {
+        let csv = "Consumption (kWh), Start, End\n\
+                   0.123, 2024-07-01T00:00:00+01:00, 2024-07-01T00:30:00+01:00\n\
+                   0.456, 2024-07-01T00:30:00+01:00, 2024-07-01T01:00:00+01:00\n";
+
+        let profile = ConsumptionProfile::from_csv(csv).unwrap();
+        let readings = profile.readings();
+        assert_eq!(readings.len(), 2);
+        // Midnight BST is 23:00 UTC the day before
+        assert_eq!(
+            readings[0].start,
+            Utc.with_ymd_and_hms(2024, 6, 30, 23, 0, 0).unwrap()
+        );
+        assert!((readings[0].kwh - 0.123).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_naive_timestamps_are_utc() {
+        let profile = ConsumptionProfile::from_csv("timestamp,kwh\n2024-01-15 08:00,1.5").unwrap();
+
+        assert_eq!(
+            profile.readings()[0].start,
+            Utc.with_ymd_and_hms(2024, 1, 15, 8, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rejects_bad_rows_and_empty_input() {
+        assert!(matches!(
+            ConsumptionProfile::from_csv("start,kwh\n2024-01-15T00:00:00Z,lots"),
+            Err(AppError::ParseError(msg)) if msg.contains("line 2")
+        ));
+        assert!(ConsumptionProfile::from_csv("").is_err());
+        assert!(ConsumptionProfile::from_csv("start,kwh\n").is_err());
+        assert!(ConsumptionProfile::from_csv("foo,bar\n").is_err());
+    }
+
+    #[test]
+    fn test_avg_p_per_kwh() {
+        let breakdown = CostBreakdown {
+            total_kwh: 4.0,
+            total_cost: 1.0,
+            unmatched: 0,
+            days: 2,
+        };
+        assert_eq!(breakdown.avg_p_per_kwh(), Some(25.0));
+        assert!((breakdown.total_with_standing(50.0) - 2.0).abs() < 1e-9);
+
+        let empty = CostBreakdown {
+            total_kwh: 0.0,
+            total_cost: 0.0,
+            unmatched: 3,
+            days: 0,
+        };
+        assert_eq!(empty.avg_p_per_kwh(), None);
+    }
+}
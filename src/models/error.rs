@@ -3,18 +3,104 @@ pub enum AppError {
     #[error("API Error: {0}")]
     ApiError(String),
 
+    /// A 5xx response: the status code and the response body
+    #[error("Server error {0}: {1}")]
+    ServerError(u16, String),
+
     #[error("Data Error: {0}")]
     DataError(String),
 
     #[error("Configuration error: {0}")]
     ConfigError(String),
 
+    /// Carries the server's suggested wait in milliseconds, from `Retry-After`, if given
     #[error("Rate limited")]
-    RateLimited,
+    RateLimited(Option<u32>),
 
     #[error("Authentication error: {0}")]
     AuthError(String),
 
     #[error("Not found: {0}")]
     NotFound(String),
+
+    #[error("Network error: {0}")]
+    NetworkError(String),
+
+    #[error("Parse error: {0}")]
+    ParseError(String),
+
+    /// The request was cancelled through an `AbortSignal` before it finished
+    #[error("Request aborted")]
+    Aborted,
+}
+
+impl AppError {
+    /// Whether the failure may clear up on its own: rate limits, network trouble and
+    /// server-side (5xx) API errors
+    pub const fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::RateLimited(_) | Self::NetworkError(_) | Self::ServerError(..)
+        )
+    }
+
+    /// What the user can do about the failure, for the kinds where there is advice to give
+    pub const fn guidance(&self) -> Option<&'static str> {
+        match self {
+            Self::RateLimited(_) => Some("The API is busy; retrying automatically."),
+            Self::NetworkError(_) => Some("You may be offline. Check your connection and retry."),
+            Self::ParseError(_) => {
+                Some("The API response has changed; please report a bug so we can fix it.")
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether a manual retry is worth offering straight away
+    pub const fn offers_retry(&self) -> bool {
+        matches!(self, Self::NetworkError(_))
+    }
+}
+
+impl From<reqwest::Error> for AppError {
+    /// Classifies transport failures: timeouts and connection problems are network
+    /// errors, undecodable bodies are parse errors
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            Self::NetworkError(format!("Request timeout: {error}"))
+        } else if error.is_decode() {
+            Self::ParseError(format!("Failed to parse response: {error}"))
+        } else if error.is_request() {
+            Self::NetworkError(format!("Request error: {error}"))
+        } else {
+            Self::NetworkError(format!("Connection error: {error}"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guidance_per_kind() {
+        let limited = AppError::RateLimited(Some(1_000));
+        assert!(
+            limited
+                .guidance()
+                .unwrap()
+                .contains("retrying automatically")
+        );
+        assert!(!limited.offers_retry());
+
+        let offline = AppError::NetworkError("Connection error".to_string());
+        assert!(offline.guidance().unwrap().contains("offline"));
+        assert!(offline.offers_retry());
+
+        let changed = AppError::ParseError("missing field".to_string());
+        assert!(changed.guidance().unwrap().contains("report a bug"));
+        assert!(!changed.offers_retry());
+
+        assert_eq!(AppError::NotFound("region".to_string()).guidance(), None);
+    }
 }
@@ -1,3 +1,11 @@
 pub mod carbon;
+pub mod chart_type;
+pub mod consumption;
 pub mod error;
+pub mod palette;
+pub mod price_unit;
 pub mod rates;
+pub mod score;
+pub mod standing_charge;
+pub mod theme;
+pub mod thresholds;
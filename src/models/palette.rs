@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+/// Colors shared by the charts and the trace banner, matching the light and dark
+/// themes' CSS variables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChartTheme {
+    /// Card background the charts sit on
+    pub background: &'static str,
+    /// Titles
+    pub text: &'static str,
+    /// Axis labels and legends
+    pub axis: &'static str,
+    pub grid: &'static str,
+    /// Single-series lines, such as the price history and the trace banner
+    pub accent: &'static str,
+    /// De-emphasised lines
+    pub muted: &'static str,
+}
+
+impl ChartTheme {
+    pub const LIGHT: Self = Self {
+        background: "#ffffff",
+        text: "#1f2937",
+        axis: "#6b7280",
+        grid: "#e5e7eb",
+        accent: "#2563eb",
+        muted: "#9ca3af",
+    };
+
+    pub const DARK: Self = Self {
+        background: "#1a1a1a",
+        text: "#e4e4e7",
+        axis: "#a1a1aa",
+        grid: "#404040",
+        accent: "#60a5fa",
+        muted: "#71717a",
+    };
+
+    /// The colors for the resolved theme
+    pub const fn for_dark_mode(dark_mode: bool) -> Self {
+        if dark_mode { Self::DARK } else { Self::LIGHT }
+    }
+}
+
+/// Color scheme for the carbon indices and the price chart's threshold colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Palette {
+    /// Green through red
+    #[default]
+    Default,
+    /// Okabe-Ito colors, distinguishable with the common forms of color blindness
+    ColorBlindSafe,
+}
+
+impl Palette {
+    /// The other palette
+    pub const fn toggled(self) -> Self {
+        match self {
+            Self::Default => Self::ColorBlindSafe,
+            Self::ColorBlindSafe => Self::Default,
+        }
+    }
+
+    /// CSS class that restyles palette-colored elements beneath it, if any
+    pub const fn css_class(self) -> Option<&'static str> {
+        match self {
+            Self::Default => None,
+            Self::ColorBlindSafe => Some("palette-color-blind"),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// Unit prices are shown in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PriceUnit {
+    /// Pence per kWh, as Octopus publishes them
+    #[default]
+    Pence,
+    /// Pounds per kWh, matching how bills are totalled
+    Pounds,
+}
+
+impl PriceUnit {
+    /// Returns human-readable label
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Pence => "p/kWh",
+            Self::Pounds => "£/kWh",
+        }
+    }
+}
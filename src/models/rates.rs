@@ -1,7 +1,16 @@
+use super::consumption::{ConsumptionProfile, CostBreakdown};
 use super::error::AppError;
-use crate::utils::time::{london_date, london_time, london_today};
-use chrono::{DateTime, Utc};
+use crate::config::Config;
+use crate::utils::time::{
+    format_slot_window, london_date, london_midnight_utc, london_time, london_today,
+};
+use chrono::{DateTime, Datelike, DurationRound, Timelike, Utc, Weekday};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write as _;
+
+/// Half-hour slots in a day without a clock change
+pub const SLOTS_PER_DAY: usize = 48;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Rate {
@@ -11,19 +20,68 @@ pub struct Rate {
     pub valid_to: DateTime<Utc>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+impl Rate {
+    /// Price labelled with its London-time slot window, e.g. "18.70p · 01:00–01:30"
+    pub fn slot_label(&self) -> String {
+        format!(
+            "{:.2}p · {}",
+            self.value_inc_vat,
+            format_slot_window(self.valid_from, self.valid_to)
+        )
+    }
+}
+
+/// Length of an Agile pricing slot
+const SLOT_SECONDS: usize = 30 * 60;
+
+/// Whole half-hour slots needed to cover `duration`; `None` if it is not positive
+pub(crate) fn slots_for(duration: chrono::Duration) -> Option<usize> {
+    let slots = usize::try_from(duration.num_seconds())
+        .ok()?
+        .div_ceil(SLOT_SECONDS);
+    (slots > 0).then_some(slots)
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Rates {
     data: Vec<Rate>,
 }
 
-/// Statistics for a specific day (price range and average only)
+/// Rates with the time they were fetched, as persisted for offline startup
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CachedRates {
+    pub fetched_at: DateTime<Utc>,
+    pub rates: Rates,
+}
+
+impl CachedRates {
+    pub const fn new(rates: Rates, fetched_at: DateTime<Utc>) -> Self {
+        Self { fetched_at, rates }
+    }
+
+    /// Returns the rates if they were fetched less than `max_age` before `now`
+    pub fn into_fresh(self, now: DateTime<Utc>, max_age: chrono::Duration) -> Option<Rates> {
+        let age = now - self.fetched_at;
+        (age >= chrono::Duration::zero() && age < max_age).then_some(self.rates)
+    }
+}
+
+/// Statistics for a specific day
 #[derive(Debug, Clone, PartialEq)]
 pub struct DayStats {
     pub min: f64,
     pub max: f64,
     pub avg: f64,
+    /// Middle price, interpolated between the two central slots on even counts
+    pub median: f64,
+    /// Price that 90% of slots are at or below (linearly interpolated)
+    pub p90: f64,
     pub price_range: String,
     pub rate_count: usize,
+    /// Earliest slot at the day's minimum price
+    pub cheapest: Rate,
+    /// Earliest slot at the day's maximum price
+    pub priciest: Rate,
 }
 
 /// Combined stats including today/tomorrow + current/next
@@ -33,15 +91,279 @@ pub struct DailyStats {
     pub tomorrow: Option<DayStats>,
     pub current: f64,
     pub next: f64,
+    /// Share of today's slots priced below the current one (0–100, ties count half)
+    pub current_percentile: f64,
+}
+
+impl DailyStats {
+    /// How far the current price sits from today's average, as a percentage of the
+    /// average. Positive means pricier than average; a zero average gives 0.
+    pub fn current_vs_avg_pct(&self) -> f64 {
+        self.today.vs_avg_pct(self.current)
+    }
+}
+
+/// Where a price sits within a day's range, split into equal thirds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceBand {
+    Cheap,
+    Average,
+    Expensive,
+}
+
+impl PriceBand {
+    /// Returns an at-a-glance emoji for the band
+    pub const fn emoji(&self) -> &'static str {
+        match self {
+            Self::Cheap => "😀",
+            Self::Average => "😐",
+            Self::Expensive => "😡",
+        }
+    }
+
+    /// The band with cheap and expensive swapped, for prices that are earned rather
+    /// than paid
+    pub const fn mirrored(self) -> Self {
+        match self {
+            Self::Cheap => Self::Expensive,
+            Self::Average => Self::Average,
+            Self::Expensive => Self::Cheap,
+        }
+    }
+
+    /// Returns human-readable label
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Cheap => "Cheap",
+            Self::Average => "Average",
+            Self::Expensive => "Expensive",
+        }
+    }
+}
+
+impl DayStats {
+    /// Summarises a set of rates in chronological order; `None` if there are none.
+    /// Tied extremes resolve to the earliest slot.
+    pub fn from_rates(rates: &[&Rate]) -> Option<Self> {
+        let cheapest = first_extreme(rates.iter().copied(), |value, best| value < best)?;
+        let priciest = first_extreme(rates.iter().copied(), |value, best| value > best)?;
+
+        let min = cheapest.value_inc_vat;
+        let max = priciest.value_inc_vat;
+
+        let avg = rates.iter().map(|r| r.value_inc_vat).sum::<f64>() / rates.len() as f64;
+
+        let mut sorted: Vec<f64> = rates.iter().map(|r| r.value_inc_vat).collect();
+        sorted.sort_by(f64::total_cmp);
+
+        Some(Self {
+            min,
+            max,
+            avg,
+            median: percentile_of_sorted(&sorted, 50.0),
+            p90: percentile_of_sorted(&sorted, 90.0),
+            price_range: format!("{min:.2}p - {max:.2}p"),
+            rate_count: rates.len(),
+            cheapest: cheapest.clone(),
+            priciest: priciest.clone(),
+        })
+    }
+
+    /// Classifies a price against this day's range.
+    /// Negative prices are always cheap; a flat day (range under 0.01p) is average.
+    pub fn band(&self, value: f64) -> PriceBand {
+        let range = self.max - self.min;
+
+        if value < 0.0 {
+            PriceBand::Cheap
+        } else if range < 0.01 {
+            PriceBand::Average
+        } else if value <= range.mul_add(1.0 / 3.0, self.min) {
+            PriceBand::Cheap
+        } else if value >= range.mul_add(-1.0 / 3.0, self.max) {
+            PriceBand::Expensive
+        } else {
+            PriceBand::Average
+        }
+    }
+
+    /// How far `value` sits from this day's average, as a percentage of the average.
+    /// Positive means pricier than average; a zero average gives 0.
+    pub fn vs_avg_pct(&self, value: f64) -> f64 {
+        if self.avg.abs() < f64::EPSILON {
+            return 0.0;
+        }
+        // Divide by the magnitude so a negative average keeps the sign meaningful
+        (value - self.avg) / self.avg.abs() * 100.0
+    }
+}
+
+/// Returns the emoji for how good `value` is relative to the day's stats
+pub fn price_emoji(value: f64, stats: &DayStats) -> &'static str {
+    stats.band(value).emoji()
+}
+
+/// `(period start, mean, min, max)` prices over a group of slots
+pub type PeriodStats = (DateTime<Utc>, f64, f64, f64);
+
+/// A London local time-of-day band `(start, end, label)`. `end` is exclusive, and a band
+/// ending at or before its start wraps past midnight.
+pub type TimeBand<'a> = (chrono::NaiveTime, chrono::NaiveTime, &'a str);
+
+/// A month's cheapest and most expensive slots
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonthRecords {
+    pub low: Rate,
+    pub high: Rate,
+}
+
+/// Prices within one time band
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandStats {
+    pub label: String,
+    pub mean: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Number of half-hour slots in the band
+    pub count: usize,
+}
+
+/// Economy-7-style bands for comparing against a fixed time-of-use tariff: overnight,
+/// day, the `Config` peak window and evening
+pub fn default_bands() -> [TimeBand<'static>; 4] {
+    let at = |hour| chrono::NaiveTime::from_hms_opt(hour, 0, 0).expect("hour should be valid");
+    [
+        (at(0), at(7), "Overnight"),
+        (at(7), at(Config::PEAK_START_HOUR), "Day"),
+        (
+            at(Config::PEAK_START_HOUR),
+            at(Config::PEAK_END_HOUR),
+            "Peak",
+        ),
+        (at(Config::PEAK_END_HOUR), at(0), "Evening"),
+    ]
+}
+
+fn in_band(time: chrono::NaiveTime, (start, end, _): &TimeBand) -> bool {
+    if start < end {
+        (*start..*end).contains(&time)
+    } else {
+        time >= *start || time < *end
+    }
+}
+
+/// Time span covered by each point of the price chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Granularity {
+    #[default]
+    HalfHour,
+    Hour,
+    Day,
+}
+
+impl Granularity {
+    /// Returns human-readable label
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::HalfHour => "30 min",
+            Self::Hour => "1 h",
+            Self::Day => "1 day",
+        }
+    }
+}
+
+/// Whether tomorrow's prices are in yet, and if not whether they are overdue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TomorrowStatus {
+    Available,
+    /// Not published yet, but still within the usual window
+    Pending,
+    /// Past the hour they normally appear by, and still missing
+    Late,
+}
+
+/// Accepted import shapes: our own export, or a raw Octopus API response
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RatesJson {
+    Export { data: Vec<Rate> },
+    Api { results: Vec<Rate> },
+}
+
+/// Sorts `data` by `valid_from`, keeping the later of any slots sharing a start and
+/// cutting short any slot that runs past the next one's start, so each instant is covered
+/// by at most one rate. Returns whether anything was dropped or cut.
+fn normalise(data: &mut Vec<Rate>) -> bool {
+    // Stable, so slots sharing a start stay in fetch order
+    data.sort_by_key(|r| r.valid_from);
+
+    let len = data.len();
+    data.dedup_by(|later, earlier| {
+        let duplicate = later.valid_from == earlier.valid_from;
+        if duplicate {
+            std::mem::swap(later, earlier);
+        }
+        duplicate
+    });
+    let mut changed = data.len() != len;
+
+    for i in 1..data.len() {
+        let next_start = data[i].valid_from;
+        if data[i - 1].valid_to > next_start {
+            data[i - 1].valid_to = next_start;
+            changed = true;
+        }
+    }
+    changed
 }
 
 impl Rates {
-    /// Creates a new Rates collection, sorting by `valid_from` time
+    /// Creates a new Rates collection, sorted by `valid_from` time. Input order counts as
+    /// fetch order: see `normalise` for how duplicated or overlapping slots are resolved.
+    /// A console warning is logged when any were found.
     pub fn new(mut data: Vec<Rate>) -> Self {
-        data.sort_by_key(|r| r.valid_from);
+        if normalise(&mut data) && cfg!(target_arch = "wasm32") {
+            web_sys::console::warn_1(&"Rates contained duplicate or overlapping slots".into());
+        }
         Self { data }
     }
 
+    /// Adds `other` to these rates, keeping them in order. A slot already held with the same
+    /// `valid_from` is replaced, so overlapping slots take the newer fetch's price.
+    pub fn merge(&mut self, other: Vec<Rate>) {
+        // Re-fetched slots are expected here, so this is not worth a warning
+        self.data.extend(other);
+        normalise(&mut self.data);
+    }
+
+    /// Drops slots that ended at or before `from`
+    pub fn drop_before(&mut self, from: DateTime<Utc>) {
+        self.data.retain(|r| r.valid_to > from);
+    }
+
+    /// The rates in chronological order
+    pub fn into_vec(self) -> Vec<Rate> {
+        self.data
+    }
+
+    /// Serialises the rates as `{"data": [...]}`
+    pub fn to_json(&self) -> Result<String, AppError> {
+        serde_json::to_string(self)
+            .map_err(|e| AppError::DataError(format!("Failed to export rates: {e}")))
+    }
+
+    /// Parses rates exported by `to_json`, or a pasted `{"results": [...]}` API response.
+    /// Input order does not matter.
+    pub fn from_json(s: &str) -> Result<Self, AppError> {
+        let data = match serde_json::from_str(s)
+            .map_err(|e| AppError::DataError(format!("Invalid rates JSON: {e}")))?
+        {
+            RatesJson::Export { data } => data,
+            RatesJson::Api { results } => results,
+        };
+        Ok(Self::new(data))
+    }
+
     /// Extract all price values in chronological order (sorted by `valid_from`)
     pub fn all_values(&self) -> Vec<f64> {
         self.data.iter().map(|r| r.value_inc_vat).collect()
@@ -66,22 +388,195 @@ impl Rates {
         self.rate_at(current.valid_to)
     }
 
+    /// Time left in the slot valid at `now`, or `None` if no rate covers `now`
+    pub fn time_until_next_change(&self, now: DateTime<Utc>) -> Option<chrono::Duration> {
+        self.rate_at(now).map(|rate| rate.valid_to - now)
+    }
+
+    /// Rates starting at or after `from`, in chronological order
     pub fn filter_from(&self, from: DateTime<Utc>) -> impl Iterator<Item = &Rate> {
         self.data.iter().filter(move |r| r.valid_from >= from)
     }
 
+    /// Mean price of the slots starting in `[from, from + duration)`. A window running past
+    /// the end of the data averages the slots it does cover; `None` if it covers none.
+    pub fn avg_over(&self, from: DateTime<Utc>, duration: chrono::Duration) -> Option<f64> {
+        let until = from + duration;
+        let (sum, count) = self
+            .filter_from(from)
+            .take_while(|r| r.valid_from < until)
+            .fold((0.0, 0usize), |(sum, count), r| {
+                (sum + r.value_inc_vat, count + 1)
+            });
+
+        (count > 0).then(|| sum / count as f64)
+    }
+
+    /// Rates starting in `[from, until)`, cheapest first.
+    /// Equal prices are ordered chronologically so the earliest slot wins ties.
+    pub fn cheapest_between(&self, from: DateTime<Utc>, until: DateTime<Utc>) -> Vec<&Rate> {
+        let mut rates: Vec<&Rate> = self
+            .filter_from(from)
+            .take_while(|r| r.valid_from < until)
+            .collect();
+
+        rates.sort_by(|a, b| {
+            a.value_inc_vat
+                .total_cmp(&b.value_inc_vat)
+                .then(a.valid_from.cmp(&b.valid_from))
+        });
+        rates
+    }
+
+    /// Cheapest contiguous block of at least `duration` lying within `[search_from, search_to)`.
+    ///
+    /// The duration is rounded up to whole half-hour slots, every slot must be covered,
+    /// and the block may not span a gap in the data. Returns the block's start and mean
+    /// price; ties go to the earliest block.
+    pub fn cheapest_window(
+        &self,
+        duration: chrono::Duration,
+        search_from: DateTime<Utc>,
+        search_to: DateTime<Utc>,
+    ) -> Option<(DateTime<Utc>, f64)> {
+        let slots = slots_for(duration)?;
+        let start = self.data.partition_point(|r| r.valid_from < search_from);
+        let end = self.data.partition_point(|r| r.valid_to <= search_to);
+        self.data
+            .get(start..end)?
+            .windows(slots)
+            .filter(|window| {
+                window
+                    .windows(2)
+                    .all(|pair| pair[0].valid_to == pair[1].valid_from)
+            })
+            .map(|window| {
+                let mean = window.iter().map(|r| r.value_inc_vat).sum::<f64>() / slots as f64;
+                (window[0].valid_from, mean)
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Cheapest contiguous block of `duration` starting at or after `from` and ending by the
+    /// last published slot. Returns the block's start, end and mean price.
+    pub fn cheapest_block(
+        &self,
+        duration: chrono::Duration,
+        from: DateTime<Utc>,
+    ) -> Option<(DateTime<Utc>, DateTime<Utc>, f64)> {
+        let search_to = self.data.last()?.valid_to;
+        let (start, mean) = self.cheapest_window(duration, from, search_to)?;
+        let slots = i64::try_from(slots_for(duration)?).ok()?;
+        Some((start, start + chrono::Duration::minutes(30 * slots), mean))
+    }
+
+    /// Cost in pounds of a schedule of `(slot start, kWh)` pairs.
+    /// Timestamps only need to fall within a slot; any uncovered slot is an error.
+    pub fn cost_for_schedule(&self, schedule: &[(DateTime<Utc>, f64)]) -> Result<f64, AppError> {
+        schedule.iter().try_fold(0.0, |total, &(slot, kwh)| {
+            let rate = self.rate_at(slot).ok_or_else(|| {
+                AppError::DataError(format!("No rate published for {}", slot.to_rfc3339()))
+            })?;
+            Ok(kwh.mul_add(rate.value_inc_vat / 100.0, total))
+        })
+    }
+
+    /// Cost of a consumption profile, pricing each reading at the rate covering its start.
+    /// Readings outside the loaded rates are counted as unmatched rather than failing;
+    /// it is an error only if none match.
+    pub fn cost_for(&self, profile: &ConsumptionProfile) -> Result<CostBreakdown, AppError> {
+        let mut breakdown = CostBreakdown {
+            total_kwh: 0.0,
+            total_cost: 0.0,
+            unmatched: 0,
+            days: 0,
+        };
+        let mut days = BTreeSet::new();
+
+        for reading in profile.readings() {
+            match self.rate_at(reading.start) {
+                Some(rate) => {
+                    days.insert(london_date(reading.start));
+                    breakdown.total_kwh += reading.kwh;
+                    breakdown.total_cost = reading
+                        .kwh
+                        .mul_add(rate.value_inc_vat / 100.0, breakdown.total_cost);
+                }
+                None => breakdown.unmatched += 1,
+            }
+        }
+
+        if breakdown.unmatched == profile.readings().len() {
+            return Err(AppError::DataError(
+                "No readings fall within the loaded rates".to_string(),
+            ));
+        }
+        breakdown.days = days.len();
+        Ok(breakdown)
+    }
+
     pub fn series_data(&self) -> Result<(Vec<String>, Vec<f64>), AppError> {
-        self.series_data_from(london_today())
+        self.series_data_with_tail(0)
+    }
+
+    /// All rates labelled in the browser's local timezone as "YYYY-MM-DD HH:MM",
+    /// for viewers outside the UK
+    pub fn series_data_local(&self) -> Result<(Vec<String>, Vec<f64>), AppError> {
+        self.series_data_in(&chrono::Local)
+    }
+
+    fn series_data_in<Tz: chrono::TimeZone>(
+        &self,
+        tz: &Tz,
+    ) -> Result<(Vec<String>, Vec<f64>), AppError>
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        if self.data.is_empty() {
+            return Err(AppError::DataError("No rates available".to_string()));
+        }
+
+        Ok(self
+            .data
+            .iter()
+            .map(|r| {
+                (
+                    r.valid_from
+                        .with_timezone(tz)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string(),
+                    r.value_inc_vat,
+                )
+            })
+            .unzip())
+    }
+
+    /// Like `series_data`, but prepends up to `tail_slots` rates from before today
+    /// so the chart has some continuity early in the morning
+    pub fn series_data_with_tail(
+        &self,
+        tail_slots: usize,
+    ) -> Result<(Vec<String>, Vec<f64>), AppError> {
+        self.series_data_from(london_today(), tail_slots)
     }
 
     fn series_data_from(
         &self,
         start_of_today: chrono::NaiveDate,
+        tail_slots: usize,
     ) -> Result<(Vec<String>, Vec<f64>), AppError> {
-        let (x_data, y_data): (Vec<_>, Vec<_>) = self
+        // Data is sorted, so the first rate of today splits the slice
+        let first_today = self
             .data
+            .partition_point(|r| london_date(r.valid_from) < start_of_today);
+
+        if first_today == self.data.len() {
+            return Err(AppError::DataError("No rates for today".to_string()));
+        }
+
+        let start = first_today.saturating_sub(tail_slots);
+        let (x_data, y_data) = self.data[start..]
             .iter()
-            .filter(|r| london_date(r.valid_from) >= start_of_today)
             .map(|r| {
                 (
                     london_time(r.valid_from).format("%a %H:%M").to_string(),
@@ -90,14 +585,70 @@ impl Rates {
             })
             .unzip();
 
-        if x_data.is_empty() {
-            return Err(AppError::DataError("No rates for today".to_string()));
+        Ok((x_data, y_data))
+    }
+
+    /// Chart series for a single London local date: ("Day HH:MM" labels, prices)
+    pub fn series_data_for(
+        &self,
+        date: chrono::NaiveDate,
+    ) -> Result<(Vec<String>, Vec<f64>), AppError> {
+        let rates = self.filter_for_date(date);
+        if rates.is_empty() {
+            return Err(AppError::DataError(format!("No rates for {date}")));
         }
 
-        Ok((x_data, y_data))
+        Ok(rates
+            .iter()
+            .map(|r| {
+                (
+                    london_time(r.valid_from).format("%a %H:%M").to_string(),
+                    r.value_inc_vat,
+                )
+            })
+            .unzip())
+    }
+
+    /// Tomorrow's rates (London local date), in chronological order; empty until published
+    pub fn filter_for_tomorrow(&self) -> Vec<&Rate> {
+        self.filter_for_date(london_today() + chrono::Duration::days(1))
+    }
+
+    /// End of the last published slot
+    pub fn latest_available(&self) -> Option<DateTime<Utc>> {
+        self.data.iter().map(|r| r.valid_to).max()
     }
 
-    /// Filter rates for a specific London local date
+    /// Whether tomorrow's prices (London date of `now`) are in. Missing prices count as
+    /// late from `late_hour` London local time.
+    pub fn tomorrow_status(&self, now: DateTime<Utc>, late_hour: u32) -> TomorrowStatus {
+        let tomorrow = london_date(now) + chrono::Duration::days(1);
+        if self
+            .latest_available()
+            .is_some_and(|end| end > london_midnight_utc(tomorrow))
+        {
+            TomorrowStatus::Available
+        } else if london_time(now).hour() < late_hour {
+            TomorrowStatus::Pending
+        } else {
+            TomorrowStatus::Late
+        }
+    }
+
+    /// Whether tomorrow's prices have been published yet. "Tomorrow" is the London local
+    /// date rather than the UTC one, matching the rest of the day handling: in BST the
+    /// UTC date flips an hour late, leaving tomorrow's first two slots on today.
+    pub fn has_tomorrow(&self) -> bool {
+        self.has_rates_after(london_today())
+    }
+
+    fn has_rates_after(&self, today: chrono::NaiveDate) -> bool {
+        self.data
+            .last()
+            .is_some_and(|r| london_date(r.valid_from) > today)
+    }
+
+    /// Filter rates for a specific London local date, in chronological order
     fn filter_for_date(&self, date: chrono::NaiveDate) -> Vec<&Rate> {
         self.data
             .iter()
@@ -105,115 +656,586 @@ impl Rates {
             .collect()
     }
 
+    /// Share of the London `date`'s slots priced below `value` (0–100, ties count half)
+    pub fn percentile_on(&self, date: chrono::NaiveDate, value: f64) -> f64 {
+        let values: Vec<f64> = self
+            .filter_for_date(date)
+            .iter()
+            .map(|r| r.value_inc_vat)
+            .collect();
+        percentile_rank(&values, value)
+    }
+
     /// Compute statistics for a specific date, returns None if no data
     pub fn stats_for_date(&self, date: chrono::NaiveDate) -> Option<DayStats> {
-        let filtered_rates = self.filter_for_date(date);
-
-        if filtered_rates.is_empty() {
-            return None;
-        }
+        DayStats::from_rates(&self.filter_for_date(date))
+    }
 
-        let mut min = f64::INFINITY;
-        let mut max = f64::NEG_INFINITY;
-        let mut sum = 0.0;
+    /// Today's cheapest slot in London time, the earliest of any ties; `None` if there
+    /// are no rates today
+    pub fn min_rate_today(&self) -> Option<&Rate> {
+        first_extreme(self.filter_for_date(london_today()), |value, best| {
+            value < best
+        })
+    }
 
-        for rate in &filtered_rates {
-            let val = rate.value_inc_vat;
-            min = min.min(val);
-            max = max.max(val);
-            sum += val;
-        }
+    /// Today's most expensive slot in London time, the earliest of any ties; `None` if
+    /// there are no rates today
+    pub fn max_rate_today(&self) -> Option<&Rate> {
+        first_extreme(self.filter_for_date(london_today()), |value, best| {
+            value > best
+        })
+    }
 
-        let avg = sum / filtered_rates.len() as f64;
+    /// Whether there are no rates at all
+    pub const fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
 
-        Some(DayStats {
-            min,
-            max,
-            avg,
-            price_range: format!("{min:.2}p - {max:.2}p"),
-            rate_count: filtered_rates.len(),
+    /// CSV of every slot in chronological order: `valid_from,valid_to,value_inc_vat`,
+    /// with UTC RFC3339 timestamps. No field can contain a comma or quote, so none are quoted.
+    pub fn to_csv(&self) -> String {
+        let header = String::from("valid_from,valid_to,value_inc_vat\n");
+
+        self.data.iter().fold(header, |mut csv, rate| {
+            let _ = writeln!(
+                csv,
+                "{},{},{}",
+                rate.valid_from
+                    .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                rate.valid_to
+                    .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                rate.value_inc_vat
+            );
+            csv
         })
     }
 
-    /// Get comprehensive daily statistics (today + optional tomorrow)
-    pub fn daily_stats(&self) -> Result<DailyStats, AppError> {
-        let today = london_today();
-        let tomorrow = today + chrono::Duration::days(1);
+    /// CSV of daily min/max/avg (inc. VAT) for each London day in `from..=to`.
+    /// Days without any rates are skipped.
+    pub fn daily_stats_csv(&self, from: chrono::NaiveDate, to: chrono::NaiveDate) -> String {
+        let header = String::from("date,min,max,avg,slots\n");
+
+        from.iter_days()
+            .take_while(|date| *date <= to)
+            .filter_map(|date| self.stats_for_date(date).map(|stats| (date, stats)))
+            .fold(header, |mut csv, (date, stats)| {
+                let _ = writeln!(
+                    csv,
+                    "{},{:.2},{:.2},{:.2},{}",
+                    date.format("%Y-%m-%d"),
+                    stats.min,
+                    stats.max,
+                    stats.avg,
+                    stats.rate_count
+                );
+                csv
+            })
+    }
 
-        let today_stats = self
-            .stats_for_date(today)
-            .ok_or_else(|| AppError::DataError("No data for today".to_string()))?;
+    /// Estimated cost of today in pence: `daily_kwh` at today's average unit price
+    /// plus the daily standing charge
+    pub fn daily_cost(&self, daily_kwh: f64, standing_charge_p: f64) -> Result<f64, AppError> {
+        self.daily_cost_on(london_today(), daily_kwh, standing_charge_p)
+    }
 
-        let tomorrow_stats = self.stats_for_date(tomorrow);
+    fn daily_cost_on(
+        &self,
+        date: chrono::NaiveDate,
+        daily_kwh: f64,
+        standing_charge_p: f64,
+    ) -> Result<f64, AppError> {
+        let stats = self
+            .stats_for_date(date)
+            .ok_or_else(|| AppError::DataError("No data for today".to_string()))?;
+        Ok(stats.avg.mul_add(daily_kwh, standing_charge_p))
+    }
 
-        let current = self.rate_at(Utc::now()).map_or(0.0, |r| r.value_inc_vat);
-        let next = self.next_rate(Utc::now()).map_or(0.0, |r| r.value_inc_vat);
+    /// Pence saved today on Agile versus a flat `flat_rate_p` tariff, for `daily_kwh` spread
+    /// evenly across today's slots. Negative when Agile works out dearer.
+    pub fn savings_vs_flat(&self, flat_rate_p: f64, daily_kwh: f64) -> Result<f64, AppError> {
+        self.savings_vs_flat_on(london_today(), flat_rate_p, daily_kwh)
+    }
 
-        Ok(DailyStats {
-            today: today_stats,
-            tomorrow: tomorrow_stats,
-            current,
-            next,
-        })
+    fn savings_vs_flat_on(
+        &self,
+        date: chrono::NaiveDate,
+        flat_rate_p: f64,
+        daily_kwh: f64,
+    ) -> Result<f64, AppError> {
+        let stats = self
+            .stats_for_date(date)
+            .ok_or_else(|| AppError::DataError("No data for today".to_string()))?;
+        Ok((flat_rate_p - stats.avg) * daily_kwh)
     }
-}
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct TrackerRates {
-    data: Vec<Rate>,
-}
+    /// All slots priced below zero (plunge pricing), in chronological order
+    pub fn negative_periods(&self) -> Vec<&Rate> {
+        self.data.iter().filter(|r| r.value_inc_vat < 0.0).collect()
+    }
 
-impl TrackerRates {
-    pub fn new(mut data: Vec<Rate>) -> Self {
-        data.sort_by_key(|r| r.valid_from);
-        Self { data }
+    /// Whether any of today's slots are priced below zero
+    pub fn has_negative_today(&self) -> bool {
+        self.has_negative_on(london_today())
     }
 
-    pub fn current_rate(&self) -> Option<&Rate> {
-        let now = Utc::now();
-        self.data
+    fn has_negative_on(&self, date: chrono::NaiveDate) -> bool {
+        self.filter_for_date(date)
             .iter()
-            .find(|r| r.valid_from <= now && r.valid_to > now)
+            .any(|r| r.value_inc_vat < 0.0)
     }
 
-    pub fn next_day_rate(&self) -> Option<&Rate> {
-        let today = london_today();
-        self.data.iter().find(|r| london_date(r.valid_from) > today)
+    /// Whether today's prices all lie within `tolerance` pence of each other
+    pub fn is_flat(&self, tolerance: f64) -> bool {
+        self.is_flat_on(london_today(), tolerance)
     }
 
-    pub fn current_price(&self) -> Option<f64> {
-        self.current_rate().map(|r| r.value_inc_vat)
+    fn is_flat_on(&self, date: chrono::NaiveDate, tolerance: f64) -> bool {
+        self.stats_for_date(date)
+            .is_some_and(|stats| stats.max - stats.min <= tolerance)
     }
 
-    pub fn next_day_price(&self) -> Option<f64> {
-        self.next_day_rate().map(|r| r.value_inc_vat)
+    /// Missing intervals as `(start, end)`, where one slot ends before the next begins
+    pub fn gaps(&self) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+        self.data
+            .windows(2)
+            .filter(|pair| pair[0].valid_to < pair[1].valid_from)
+            .map(|pair| (pair[0].valid_to, pair[1].valid_from))
+            .collect()
     }
 
-    pub fn price_difference(&self) -> Option<f64> {
-        match (self.current_price(), self.next_day_price()) {
-            (Some(current), Some(next)) => Some(next - current),
-            _ => None,
-        }
+    /// A copy with each gap filled by half-hour placeholder slots priced at `value`.
+    /// The last placeholder in a gap is cut short if the gap isn't a whole number of slots.
+    pub fn fill_gaps(&self, value: f64) -> Self {
+        let slot = chrono::Duration::minutes(30);
+        let placeholders = self.gaps().into_iter().flat_map(|(start, end)| {
+            std::iter::successors(Some(start), move |from| Some(*from + slot))
+                .take_while(move |from| *from < end)
+                .map(move |from| Rate {
+                    value_inc_vat: value,
+                    value_exc_vat: value / 1.2,
+                    valid_from: from,
+                    valid_to: (from + slot).min(end),
+                })
+        });
+        Self::new(self.data.iter().cloned().chain(placeholders).collect())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use chrono::TimeZone;
+    /// Today's average price inside and outside the peak window, as `(peak, off_peak)`.
+    /// A slot is peak if its London start hour is in `peak_start..peak_end`; either side
+    /// is `None` when no slot falls in it.
+    pub fn peak_offpeak_averages(
+        &self,
+        peak_start: u32,
+        peak_end: u32,
+    ) -> (Option<f64>, Option<f64>) {
+        self.peak_offpeak_averages_on(london_today(), peak_start, peak_end)
+    }
 
-    fn make_rate(hour: u32, value: f64) -> Rate {
-        let valid_from = Utc.with_ymd_and_hms(2024, 1, 15, hour, 0, 0).unwrap();
-        let valid_to = Utc.with_ymd_and_hms(2024, 1, 15, hour, 30, 0).unwrap();
-        Rate {
-            value_inc_vat: value,
-            value_exc_vat: value / 1.2,
-            valid_from,
-            valid_to,
-        }
+    fn peak_offpeak_averages_on(
+        &self,
+        date: chrono::NaiveDate,
+        peak_start: u32,
+        peak_end: u32,
+    ) -> (Option<f64>, Option<f64>) {
+        let mean = |peak: bool| {
+            let (sum, count) = self
+                .filter_for_date(date)
+                .into_iter()
+                .filter(|r| {
+                    (peak_start..peak_end).contains(&london_time(r.valid_from).hour()) == peak
+                })
+                .fold((0.0, 0usize), |(sum, count), r| {
+                    (sum + r.value_inc_vat, count + 1)
+                });
+            (count > 0).then(|| sum / count as f64)
+        };
+
+        (mean(true), mean(false))
     }
 
-    #[test]
+    /// Today's prices split into time-of-day `bands`, in the order given. A slot belongs
+    /// to every band containing its London start time; bands with no slots are left out.
+    pub fn banded_stats(&self, bands: &[TimeBand]) -> Vec<BandStats> {
+        self.banded_stats_on(london_today(), bands)
+    }
+
+    fn banded_stats_on(&self, date: chrono::NaiveDate, bands: &[TimeBand]) -> Vec<BandStats> {
+        let slots = self.filter_for_date(date);
+        bands
+            .iter()
+            .filter_map(|band| {
+                let values: Vec<f64> = slots
+                    .iter()
+                    .filter(|r| in_band(london_time(r.valid_from).time(), band))
+                    .map(|r| r.value_inc_vat)
+                    .collect();
+                (!values.is_empty()).then(|| BandStats {
+                    label: band.2.to_string(),
+                    mean: values.iter().sum::<f64>() / values.len() as f64,
+                    min: values.iter().copied().fold(f64::INFINITY, f64::min),
+                    max: values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                    count: values.len(),
+                })
+            })
+            .collect()
+    }
+
+    /// Typical price for `weekday` to compare these rates against: the average of every
+    /// `historical` slot whose London local date falls on it. Slots these rates also
+    /// cover are left out, so a day is never compared with itself. None if none match.
+    pub fn weekday_typical(&self, historical: &Self, weekday: Weekday) -> Option<f64> {
+        let (sum, count) = historical
+            .data
+            .iter()
+            .filter(|r| london_date(r.valid_from).weekday() == weekday)
+            .filter(|r| self.rate_at(r.valid_from).is_none())
+            .fold((0.0, 0usize), |(sum, count), r| {
+                (sum + r.value_inc_vat, count + 1)
+            });
+
+        (count > 0).then(|| sum / count as f64)
+    }
+
+    /// Mean price per London local date, oldest first. Days with missing slots
+    /// average whatever slots exist.
+    pub fn daily_averages(&self) -> Vec<(chrono::NaiveDate, f64)> {
+        let mut days: BTreeMap<chrono::NaiveDate, (f64, usize)> = BTreeMap::new();
+        for rate in &self.data {
+            let (sum, count) = days.entry(london_date(rate.valid_from)).or_default();
+            *sum += rate.value_inc_vat;
+            *count += 1;
+        }
+
+        days.into_iter()
+            .map(|(date, (sum, count))| (date, sum / count as f64))
+            .collect()
+    }
+
+    /// Cheapest slot in the whole dataset, the earliest of any ties
+    pub fn record_low(&self) -> Option<&Rate> {
+        first_extreme(&self.data, |value, best| value < best)
+    }
+
+    /// Most expensive slot in the whole dataset, the earliest of any ties
+    pub fn record_high(&self) -> Option<&Rate> {
+        first_extreme(&self.data, |value, best| value > best)
+    }
+
+    /// Cheapest and most expensive slot per London `(year, month)`, earliest of any ties
+    pub fn records_by_month(&self) -> BTreeMap<(i32, u32), MonthRecords> {
+        let mut months: BTreeMap<(i32, u32), MonthRecords> = BTreeMap::new();
+        for rate in &self.data {
+            let date = london_date(rate.valid_from);
+            months
+                .entry((date.year(), date.month()))
+                .and_modify(|records| {
+                    if rate.value_inc_vat < records.low.value_inc_vat {
+                        records.low = rate.clone();
+                    }
+                    if rate.value_inc_vat > records.high.value_inc_vat {
+                        records.high = rate.clone();
+                    }
+                })
+                .or_insert_with(|| MonthRecords {
+                    low: rate.clone(),
+                    high: rate.clone(),
+                });
+        }
+        months
+    }
+
+    /// Mean, min and max price per clock hour, oldest first. London's offset is a whole
+    /// number of hours, so UTC hours line up with local ones; hours with missing slots
+    /// use whatever slots exist.
+    pub fn aggregate_hourly(&self) -> Vec<PeriodStats> {
+        self.aggregate_by(|start| {
+            start
+                .duration_trunc(chrono::Duration::hours(1))
+                .unwrap_or(start)
+        })
+    }
+
+    /// Mean, min and max price per London local date, keyed by its midnight, oldest first.
+    /// Clock-change days cover 46 or 50 slots; partial days use the slots present.
+    pub fn aggregate_daily(&self) -> Vec<PeriodStats> {
+        self.aggregate_by(|start| london_midnight_utc(london_date(start)))
+    }
+
+    /// Groups consecutive slots sharing a `period_start`, which must not decrease over time
+    fn aggregate_by(
+        &self,
+        period_start: impl Fn(DateTime<Utc>) -> DateTime<Utc>,
+    ) -> Vec<PeriodStats> {
+        self.data
+            .chunk_by(|a, b| period_start(a.valid_from) == period_start(b.valid_from))
+            .map(|group| {
+                let (sum, min, max) = group.iter().fold(
+                    (0.0, f64::INFINITY, f64::NEG_INFINITY),
+                    |(sum, min, max), r| {
+                        let v = r.value_inc_vat;
+                        (sum + v, min.min(v), max.max(v))
+                    },
+                );
+                (
+                    period_start(group[0].valid_from),
+                    sum / group.len() as f64,
+                    min,
+                    max,
+                )
+            })
+            .collect()
+    }
+
+    /// Chart series from today onward at `granularity`, with aggregated points at their
+    /// mean price. `tail_slots` of yesterday are only prepended at half-hour granularity.
+    pub fn series_at(
+        &self,
+        granularity: Granularity,
+        tail_slots: usize,
+    ) -> Result<(Vec<String>, Vec<f64>), AppError> {
+        self.series_at_from(london_today(), granularity, tail_slots)
+    }
+
+    fn series_at_from(
+        &self,
+        today: chrono::NaiveDate,
+        granularity: Granularity,
+        tail_slots: usize,
+    ) -> Result<(Vec<String>, Vec<f64>), AppError> {
+        let (periods, format) = match granularity {
+            Granularity::HalfHour => return self.series_data_from(today, tail_slots),
+            Granularity::Hour => (self.aggregate_hourly(), "%a %H:%M"),
+            Granularity::Day => (self.aggregate_daily(), "%a %d %b"),
+        };
+
+        let (x_data, y_data): (Vec<String>, Vec<f64>) = periods
+            .into_iter()
+            .filter(|(start, ..)| london_date(*start) >= today)
+            .map(|(start, mean, ..)| (london_time(start).format(format).to_string(), mean))
+            .unzip();
+
+        if x_data.is_empty() {
+            return Err(AppError::DataError("No rates for today".to_string()));
+        }
+        Ok((x_data, y_data))
+    }
+
+    /// These rates' prices at each of `labels` (as produced by another set's `series_at`),
+    /// for overlaying a second tariff on the same chart; `None` where there is no point
+    pub fn overlay_values(&self, labels: &[String], granularity: Granularity) -> Vec<Option<f64>> {
+        self.overlay_values_from(london_today(), labels, granularity)
+    }
+
+    fn overlay_values_from(
+        &self,
+        today: chrono::NaiveDate,
+        labels: &[String],
+        granularity: Granularity,
+    ) -> Vec<Option<f64>> {
+        // A day's tail covers any the other series prepended; labels carry the weekday,
+        // so they are unique across the window
+        let points: HashMap<String, f64> = self
+            .series_at_from(today, granularity, SLOTS_PER_DAY)
+            .map(|(x_data, y_data)| x_data.into_iter().zip(y_data).collect())
+            .unwrap_or_default();
+        labels
+            .iter()
+            .map(|label| points.get(label).copied())
+            .collect()
+    }
+
+    /// Number of chart points on a London local date at `granularity`
+    pub fn points_on(&self, granularity: Granularity, date: chrono::NaiveDate) -> usize {
+        let periods = match granularity {
+            Granularity::HalfHour => return self.filter_for_date(date).len(),
+            Granularity::Hour => self.aggregate_hourly(),
+            Granularity::Day => self.aggregate_daily(),
+        };
+        periods
+            .iter()
+            .filter(|(start, ..)| london_date(*start) == date)
+            .count()
+    }
+
+    /// One row per London local date, oldest first, with a price per half-hour of local
+    /// time-of-day. Slots with no rate are `None`, so DST days leave gaps rather than zeros;
+    /// on the autumn change the repeated hour keeps its first occurrence.
+    pub fn to_daily_matrix(&self) -> Vec<(chrono::NaiveDate, Vec<Option<f64>>)> {
+        let mut days: BTreeMap<chrono::NaiveDate, Vec<Option<f64>>> = BTreeMap::new();
+        for rate in &self.data {
+            let local = london_time(rate.valid_from);
+            let slot = (local.hour() * 2 + local.minute() / 30) as usize;
+            let row = days
+                .entry(local.date_naive())
+                .or_insert_with(|| vec![None; SLOTS_PER_DAY]);
+            row[slot].get_or_insert(rate.value_inc_vat);
+        }
+
+        days.into_iter().collect()
+    }
+
+    /// Get comprehensive daily statistics (today + optional tomorrow)
+    pub fn daily_stats(&self) -> Result<DailyStats, AppError> {
+        let today = london_today();
+        let tomorrow = today + chrono::Duration::days(1);
+
+        let today_stats = self
+            .stats_for_date(today)
+            .ok_or_else(|| AppError::DataError("No data for today".to_string()))?;
+
+        let tomorrow_stats = self.stats_for_date(tomorrow);
+
+        let current = self.rate_at(Utc::now()).map_or(0.0, |r| r.value_inc_vat);
+        let next = self.next_rate(Utc::now()).map_or(0.0, |r| r.value_inc_vat);
+
+        Ok(DailyStats {
+            today: today_stats,
+            tomorrow: tomorrow_stats,
+            current,
+            next,
+            current_percentile: self.percentile_on(today, current),
+        })
+    }
+}
+
+/// The first rate in `rates` that no later one `beats`, comparing `(value, best)` prices
+fn first_extreme<'a>(
+    rates: impl IntoIterator<Item = &'a Rate>,
+    beats: impl Fn(f64, f64) -> bool,
+) -> Option<&'a Rate> {
+    rates.into_iter().reduce(|best, r| {
+        if beats(r.value_inc_vat, best.value_inc_vat) {
+            r
+        } else {
+            best
+        }
+    })
+}
+
+/// `pct`th percentile (0–100) of an ascending slice, interpolating linearly between
+/// neighbouring values. The 50th percentile is the usual median.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // rank is in 0..=last
+fn percentile_of_sorted(sorted: &[f64], pct: f64) -> f64 {
+    let Some(last) = sorted.len().checked_sub(1) else {
+        return 0.0;
+    };
+
+    let rank = pct.clamp(0.0, 100.0) / 100.0 * last as f64;
+    let lower = rank.floor();
+    let index = lower as usize;
+    let upper = sorted.get(index + 1).copied().unwrap_or(sorted[index]);
+    (upper - sorted[index]).mul_add(rank - lower, sorted[index])
+}
+
+/// Percentile rank of `value` within `values` (0–100). Equal values count half,
+/// so a flat day puts every price at 50 rather than dividing by a zero range.
+fn percentile_rank(values: &[f64], value: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+
+    let below = values.iter().filter(|v| **v < value).count() as f64;
+    let equal = values.iter().filter(|v| **v == value).count() as f64;
+    0.5f64.mul_add(equal, below) / values.len() as f64 * 100.0
+}
+
+/// Which meter a Tracker tariff prices.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Fuel {
+    #[default]
+    Electricity,
+    Gas,
+}
+
+impl Fuel {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Electricity => "Electricity",
+            Self::Gas => "Gas",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrackerRates {
+    fuel: Fuel,
+    data: Vec<Rate>,
+}
+
+impl TrackerRates {
+    /// Electricity Tracker rates.
+    pub fn new(data: Vec<Rate>) -> Self {
+        Self::for_fuel(Fuel::Electricity, data)
+    }
+
+    pub fn for_fuel(fuel: Fuel, mut data: Vec<Rate>) -> Self {
+        data.sort_by_key(|r| r.valid_from);
+        Self { fuel, data }
+    }
+
+    pub const fn fuel(&self) -> Fuel {
+        self.fuel
+    }
+
+    pub fn current_rate(&self) -> Option<&Rate> {
+        let now = Utc::now();
+        self.data
+            .iter()
+            .find(|r| r.valid_from <= now && r.valid_to > now)
+    }
+
+    pub fn next_day_rate(&self) -> Option<&Rate> {
+        let today = london_today();
+        self.data.iter().find(|r| london_date(r.valid_from) > today)
+    }
+
+    pub fn current_price(&self) -> Option<f64> {
+        self.current_rate().map(|r| r.value_inc_vat)
+    }
+
+    pub fn next_day_price(&self) -> Option<f64> {
+        self.next_day_rate().map(|r| r.value_inc_vat)
+    }
+
+    pub fn price_difference(&self) -> Option<f64> {
+        match (self.current_price(), self.next_day_price()) {
+            (Some(current), Some(next)) => Some(next - current),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Timelike};
+
+    fn make_rate(hour: u32, value: f64) -> Rate {
+        let valid_from = Utc.with_ymd_and_hms(2024, 1, 15, hour, 0, 0).unwrap();
+        let valid_to = Utc.with_ymd_and_hms(2024, 1, 15, hour, 30, 0).unwrap();
+        Rate {
+            value_inc_vat: value,
+            value_exc_vat: value / 1.2,
+            valid_from,
+            valid_to,
+        }
+    }
+
+    #[test]
+    fn test_slot_label_shows_price_and_local_window() {
+        let rate = Rate {
+            value_inc_vat: 18.7,
+            value_exc_vat: 18.7 / 1.2,
+            valid_from: Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap(),
+            valid_to: Utc.with_ymd_and_hms(2024, 7, 1, 0, 30, 0).unwrap(),
+        };
+
+        // BST: 00:00 UTC is 01:00 in London
+        assert_eq!(rate.slot_label(), "18.70p · 01:00–01:30");
+    }
+
+    #[test]
     fn test_rate_at_finds_correct_rate() {
         let rates = Rates::new(vec![
             make_rate(10, 15.0),
@@ -256,6 +1278,21 @@ mod tests {
         assert_eq!(next.value_inc_vat, 20.0);
     }
 
+    #[test]
+    fn test_time_until_next_change() {
+        let rates = Rates::new(vec![make_rate(10, 15.0)]);
+
+        let time = Utc.with_ymd_and_hms(2024, 1, 15, 10, 12, 30).unwrap();
+        assert_eq!(
+            rates.time_until_next_change(time),
+            Some(chrono::Duration::seconds(17 * 60 + 30))
+        );
+
+        // The slot end belongs to the next slot, which isn't loaded
+        let end = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap();
+        assert_eq!(rates.time_until_next_change(end), None);
+    }
+
     #[test]
     fn test_rate_at_returns_none_for_gap() {
         let rates = Rates::new(vec![make_rate(10, 15.0)]);
@@ -474,11 +1511,1552 @@ mod tests {
             },
         ]);
 
-        let (x_data, y_data) = rates.series_data_from(spring_forward_day).unwrap();
+        let (x_data, y_data) = rates.series_data_from(spring_forward_day, 0).unwrap();
 
         assert_eq!(y_data, vec![10.0, 12.0]);
         assert!(x_data.iter().any(|label| label.contains("00:00")));
         assert!(x_data.iter().any(|label| label.contains("02:00")));
         assert!(!x_data.iter().any(|label| label.contains("01:00")));
     }
+
+    #[test]
+    fn test_series_data_in_shifts_labels_by_offset() {
+        use chrono::FixedOffset;
+
+        let rates = make_half_hourly(
+            Utc.with_ymd_and_hms(2026, 1, 12, 23, 30, 0).unwrap(),
+            &[10.0, 12.0],
+        );
+
+        let (utc_labels, _) = rates.series_data_in(&Utc).unwrap();
+        assert_eq!(utc_labels, vec!["2026-01-12 23:30", "2026-01-13 00:00"]);
+
+        let plus_two = FixedOffset::east_opt(2 * 3600).unwrap();
+        let (x_data, y_data) = rates.series_data_in(&plus_two).unwrap();
+        assert_eq!(x_data, vec!["2026-01-13 01:30", "2026-01-13 02:00"]);
+        assert_eq!(y_data, vec![10.0, 12.0]);
+    }
+
+    #[test]
+    fn test_series_data_local_empty_is_error() {
+        assert!(Rates::new(vec![]).series_data_local().is_err());
+    }
+
+    #[test]
+    fn test_series_data_for_bst_day_uses_london_calendar() {
+        use chrono::NaiveDate;
+
+        // 22:30-00:00 UTC on 2026-07-01 is 23:30-01:00 BST
+        let rates = make_half_hourly(
+            Utc.with_ymd_and_hms(2026, 7, 1, 22, 30, 0).unwrap(),
+            &[11.0, 12.0, 13.0],
+        );
+
+        let (x_data, y_data) = rates
+            .series_data_for(NaiveDate::from_ymd_opt(2026, 7, 1).unwrap())
+            .unwrap();
+        assert_eq!(x_data, vec!["Wed 23:30"]);
+        assert_eq!(y_data, vec![11.0]);
+
+        // 23:00 UTC is already the 2nd in London
+        let (x_data, y_data) = rates
+            .series_data_for(NaiveDate::from_ymd_opt(2026, 7, 2).unwrap())
+            .unwrap();
+        assert_eq!(x_data, vec!["Thu 00:00", "Thu 00:30"]);
+        assert_eq!(y_data, vec![12.0, 13.0]);
+    }
+
+    #[test]
+    fn test_series_data_for_gmt_day_matches_utc() {
+        use chrono::NaiveDate;
+
+        let rates = make_half_hourly(
+            Utc.with_ymd_and_hms(2026, 1, 14, 23, 0, 0).unwrap(),
+            &[11.0, 12.0, 13.0],
+        );
+
+        let (x_data, y_data) = rates
+            .series_data_for(NaiveDate::from_ymd_opt(2026, 1, 14).unwrap())
+            .unwrap();
+        assert_eq!(x_data, vec!["Wed 23:00", "Wed 23:30"]);
+        assert_eq!(y_data, vec![11.0, 12.0]);
+    }
+
+    fn make_midnight_rates() -> Rates {
+        // 22:30 - 01:00 UTC on 2024-01-15/16 (GMT, so London time matches)
+        Rates::new(vec![
+            Rate {
+                value_inc_vat: 10.0,
+                value_exc_vat: 8.33,
+                valid_from: Utc.with_ymd_and_hms(2024, 1, 15, 22, 30, 0).unwrap(),
+                valid_to: Utc.with_ymd_and_hms(2024, 1, 15, 23, 0, 0).unwrap(),
+            },
+            Rate {
+                value_inc_vat: 11.0,
+                value_exc_vat: 9.17,
+                valid_from: Utc.with_ymd_and_hms(2024, 1, 15, 23, 0, 0).unwrap(),
+                valid_to: Utc.with_ymd_and_hms(2024, 1, 15, 23, 30, 0).unwrap(),
+            },
+            Rate {
+                value_inc_vat: 12.0,
+                value_exc_vat: 10.0,
+                valid_from: Utc.with_ymd_and_hms(2024, 1, 15, 23, 30, 0).unwrap(),
+                valid_to: Utc.with_ymd_and_hms(2024, 1, 16, 0, 0, 0).unwrap(),
+            },
+            Rate {
+                value_inc_vat: 20.0,
+                value_exc_vat: 16.67,
+                valid_from: Utc.with_ymd_and_hms(2024, 1, 16, 0, 0, 0).unwrap(),
+                valid_to: Utc.with_ymd_and_hms(2024, 1, 16, 0, 30, 0).unwrap(),
+            },
+            Rate {
+                value_inc_vat: 21.0,
+                value_exc_vat: 17.5,
+                valid_from: Utc.with_ymd_and_hms(2024, 1, 16, 0, 30, 0).unwrap(),
+                valid_to: Utc.with_ymd_and_hms(2024, 1, 16, 1, 0, 0).unwrap(),
+            },
+        ])
+    }
+
+    #[test]
+    fn test_series_data_without_tail_is_today_only() {
+        use chrono::NaiveDate;
+
+        let today = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
+        let (x_data, y_data) = make_midnight_rates().series_data_from(today, 0).unwrap();
+
+        assert_eq!(y_data, vec![20.0, 21.0]);
+        assert_eq!(x_data, vec!["Tue 00:00", "Tue 00:30"]);
+    }
+
+    #[test]
+    fn test_series_data_with_tail_prepends_yesterday() {
+        use chrono::NaiveDate;
+
+        let today = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
+        let (x_data, y_data) = make_midnight_rates().series_data_from(today, 2).unwrap();
+
+        assert_eq!(y_data, vec![11.0, 12.0, 20.0, 21.0]);
+        assert_eq!(x_data[0], "Mon 23:00");
+        assert_eq!(x_data[2], "Tue 00:00");
+    }
+
+    #[test]
+    fn test_series_data_tail_longer_than_history() {
+        use chrono::NaiveDate;
+
+        let today = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
+        let (_, y_data) = make_midnight_rates().series_data_from(today, 10).unwrap();
+
+        assert_eq!(y_data.len(), 5);
+    }
+
+    #[test]
+    fn test_series_data_tail_requires_today() {
+        use chrono::NaiveDate;
+
+        let day_after = NaiveDate::from_ymd_opt(2024, 1, 17).unwrap();
+        assert!(
+            make_midnight_rates()
+                .series_data_from(day_after, 4)
+                .is_err()
+        );
+    }
+
+    fn make_day_stats(min: f64, max: f64) -> DayStats {
+        DayStats {
+            min,
+            max,
+            avg: f64::midpoint(min, max),
+            median: f64::midpoint(min, max),
+            p90: 0.9f64.mul_add(max - min, min),
+            price_range: format!("{min:.2}p - {max:.2}p"),
+            rate_count: 48,
+            cheapest: make_rate(3, min),
+            priciest: make_rate(17, max),
+        }
+    }
+
+    #[test]
+    fn test_day_stats_from_rates() {
+        let rates = [make_rate(1, 12.0), make_rate(2, 4.0), make_rate(3, 8.0)];
+        let refs: Vec<&Rate> = rates.iter().collect();
+
+        let stats = DayStats::from_rates(&refs).unwrap();
+        assert!((stats.avg - 8.0).abs() < 1e-9);
+        assert_eq!(stats.price_range, "4.00p - 12.00p");
+        assert_eq!(stats.rate_count, 3);
+        assert!(DayStats::from_rates(&[]).is_none());
+    }
+
+    #[test]
+    fn test_day_stats_extremes_prefer_earliest_slot() {
+        let rates = [
+            make_rate(1, 9.0),
+            make_rate(2, 4.0),
+            make_rate(3, 30.0),
+            make_rate(4, 4.0),
+            make_rate(5, 30.0),
+        ];
+        let refs: Vec<&Rate> = rates.iter().collect();
+
+        let stats = DayStats::from_rates(&refs).unwrap();
+        assert_eq!(stats.cheapest, rates[1]);
+        assert_eq!(stats.priciest, rates[2]);
+        assert_eq!((stats.min, stats.max), (4.0, 30.0));
+    }
+
+    #[test]
+    fn test_day_stats_extremes_of_a_flat_day_are_the_first_slot() {
+        let rates = [make_rate(6, 12.5), make_rate(7, 12.5), make_rate(8, 12.5)];
+        let refs: Vec<&Rate> = rates.iter().collect();
+
+        let stats = DayStats::from_rates(&refs).unwrap();
+        assert_eq!(stats.cheapest, rates[0]);
+        assert_eq!(stats.priciest, rates[0]);
+    }
+
+    #[test]
+    fn test_current_vs_avg_pct() {
+        let with_current = |current, stats| DailyStats {
+            today: stats,
+            tomorrow: None,
+            current,
+            next: 0.0,
+            current_percentile: 50.0,
+        };
+
+        // Average 20p
+        let above = with_current(25.0, make_day_stats(10.0, 30.0));
+        let below = with_current(15.0, make_day_stats(10.0, 30.0));
+        assert!((above.current_vs_avg_pct() - 25.0).abs() < 1e-9);
+        assert!((below.current_vs_avg_pct() + 25.0).abs() < 1e-9);
+
+        // Average 0p must not divide by zero
+        let zero_avg = with_current(5.0, make_day_stats(-10.0, 10.0));
+        assert!(zero_avg.current_vs_avg_pct().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_price_emoji_per_band() {
+        let stats = make_day_stats(10.0, 40.0);
+
+        assert_eq!(price_emoji(10.0, &stats), "😀");
+        assert_eq!(price_emoji(20.0, &stats), "😀");
+        assert_eq!(price_emoji(25.0, &stats), "😐");
+        assert_eq!(price_emoji(30.0, &stats), "😡");
+        assert_eq!(price_emoji(40.0, &stats), "😡");
+    }
+
+    #[test]
+    fn test_price_emoji_negative_prices() {
+        // Negative prices are cheap even when the whole day is negative
+        let stats = make_day_stats(-5.0, -1.0);
+
+        assert_eq!(price_emoji(-1.0, &stats), "😀");
+        assert_eq!(price_emoji(-5.0, &stats), "😀");
+        assert_eq!(stats.band(-0.5), PriceBand::Cheap);
+    }
+
+    #[test]
+    fn test_mirrored_band_swaps_cheap_and_expensive() {
+        let stats = make_day_stats(10.0, 40.0);
+
+        assert_eq!(stats.band(10.0).mirrored().emoji(), "😡");
+        assert_eq!(stats.band(25.0).mirrored(), PriceBand::Average);
+        assert_eq!(stats.band(40.0).mirrored().emoji(), "😀");
+    }
+
+    #[test]
+    fn test_price_band_flat_day_is_average() {
+        let stats = make_day_stats(15.0, 15.0);
+        assert_eq!(stats.band(15.0), PriceBand::Average);
+    }
+
+    #[test]
+    fn test_list_apis_are_chronological_for_unsorted_input() {
+        use chrono::NaiveDate;
+
+        let rates = Rates::new(vec![
+            make_rate(12, 25.0),
+            make_rate(10, 15.0),
+            make_rate(11, 20.0),
+        ]);
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let from = Utc.with_ymd_and_hms(2024, 1, 15, 11, 0, 0).unwrap();
+
+        assert_eq!(rates.all_values(), vec![15.0, 20.0, 25.0]);
+
+        let filtered: Vec<f64> = rates.filter_from(from).map(|r| r.value_inc_vat).collect();
+        assert_eq!(filtered, vec![20.0, 25.0]);
+
+        let for_date: Vec<f64> = rates
+            .filter_for_date(date)
+            .iter()
+            .map(|r| r.value_inc_vat)
+            .collect();
+        assert_eq!(for_date, vec![15.0, 20.0, 25.0]);
+    }
+
+    /// Half-hour rates covering `[from, from + slots * 30min)`, priced by `price(index)`
+    fn half_hourly(from: DateTime<Utc>, slots: usize, price: impl Fn(usize) -> f64) -> Rates {
+        let mut valid_from = from;
+        Rates::new(
+            (0..slots)
+                .map(|i| {
+                    let rate = Rate {
+                        value_inc_vat: price(i),
+                        value_exc_vat: price(i) / 1.2,
+                        valid_from,
+                        valid_to: valid_from + chrono::Duration::minutes(30),
+                    };
+                    valid_from = rate.valid_to;
+                    rate
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_peak_offpeak_averages_split_on_local_hour() {
+        // A BST day: London midnight is 23:00 UTC, so slot 32 starts at 16:00 local
+        let midnight = Utc.with_ymd_and_hms(2024, 6, 30, 23, 0, 0).unwrap();
+        let rates = half_hourly(midnight, 48, |i| match i {
+            31 | 38 => 20.0, // 15:30 and 19:00, just outside the window
+            32..38 => 35.0,
+            _ => 10.0,
+        });
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 7, 1).unwrap();
+
+        let (peak, off_peak) = rates.peak_offpeak_averages_on(date, 16, 19);
+        assert_eq!(peak, Some(35.0));
+        // 40 slots at 10p and two at 20p
+        assert!((off_peak.unwrap() - 440.0 / 42.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_peak_offpeak_averages_with_one_side_empty() {
+        let midnight = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let morning = half_hourly(midnight, 8, |_| 12.0);
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        assert_eq!(
+            morning.peak_offpeak_averages_on(date, 16, 19),
+            (None, Some(12.0))
+        );
+        assert_eq!(
+            morning.peak_offpeak_averages_on(date.succ_opt().unwrap(), 16, 19),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn test_aggregate_spring_forward_day_has_46_slots() {
+        // 31 March 2024 runs 00:00–23:00 UTC; two slots of 1 April follow
+        let midnight = Utc.with_ymd_and_hms(2024, 3, 31, 0, 0, 0).unwrap();
+        let rates = half_hourly(midnight, 48, |i| i as f64);
+
+        let daily = rates.aggregate_daily();
+        assert_eq!(
+            daily,
+            vec![
+                (midnight, 22.5, 0.0, 45.0),
+                (
+                    Utc.with_ymd_and_hms(2024, 3, 31, 23, 0, 0).unwrap(),
+                    46.5,
+                    46.0,
+                    47.0
+                ),
+            ]
+        );
+
+        let hourly = rates.aggregate_hourly();
+        assert_eq!(hourly.len(), 24);
+        assert_eq!(hourly[0], (midnight, 0.5, 0.0, 1.0));
+        let april = chrono::NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+        assert_eq!(
+            rates.points_on(Granularity::Hour, april.pred_opt().unwrap()),
+            23
+        );
+        assert_eq!(rates.points_on(Granularity::Hour, april), 1);
+    }
+
+    #[test]
+    fn test_aggregate_fall_back_day_has_50_slots() {
+        // London midnight on 27 October 2024 is still BST: 23:00 UTC the day before
+        let midnight = Utc.with_ymd_and_hms(2024, 10, 26, 23, 0, 0).unwrap();
+        let rates = half_hourly(midnight, 50, |i| if i % 2 == 0 { 10.0 } else { 20.0 });
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 10, 27).unwrap();
+
+        assert_eq!(rates.aggregate_daily(), vec![(midnight, 15.0, 10.0, 20.0)]);
+        assert_eq!(rates.points_on(Granularity::Day, date), 1);
+        assert_eq!(rates.points_on(Granularity::Hour, date), 25);
+        assert_eq!(rates.points_on(Granularity::HalfHour, date), 50);
+    }
+
+    #[test]
+    fn test_series_at_aggregates_from_today() {
+        let midnight = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        // Yesterday's last hour, then three hours of today
+        let rates = half_hourly(midnight - chrono::Duration::hours(1), 8, |i| i as f64);
+        let today = chrono::NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        let (x_data, y_data) = rates.series_at_from(today, Granularity::Hour, 4).unwrap();
+        assert_eq!(x_data, vec!["Mon 00:00", "Mon 01:00", "Mon 02:00"]);
+        assert_eq!(y_data, vec![2.5, 4.5, 6.5]);
+
+        let (x_data, y_data) = rates.series_at_from(today, Granularity::Day, 4).unwrap();
+        assert_eq!(
+            (x_data, y_data),
+            (vec!["Mon 15 Jan".to_string()], vec![4.5])
+        );
+
+        let (x_data, _) = rates
+            .series_at_from(today, Granularity::HalfHour, 1)
+            .unwrap();
+        assert_eq!(x_data.len(), 7);
+
+        let tomorrow = today.succ_opt().unwrap();
+        assert!(rates.series_at_from(tomorrow, Granularity::Day, 0).is_err());
+    }
+
+    #[test]
+    fn test_overlay_values_align_by_label() {
+        let midnight = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let today = chrono::NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let import = half_hourly(midnight, 6, |_| 20.0);
+        // Export starts an hour later and runs past the import data
+        let export = half_hourly(midnight + chrono::Duration::hours(1), 6, |i| i as f64);
+
+        let (labels, _) = import
+            .series_at_from(today, Granularity::HalfHour, 0)
+            .unwrap();
+        assert_eq!(
+            export.overlay_values_from(today, &labels, Granularity::HalfHour),
+            vec![None, None, Some(0.0), Some(1.0), Some(2.0), Some(3.0)]
+        );
+
+        let (labels, _) = import.series_at_from(today, Granularity::Hour, 0).unwrap();
+        assert_eq!(
+            export.overlay_values_from(today, &labels, Granularity::Hour),
+            vec![None, Some(0.5), Some(2.5)]
+        );
+
+        assert_eq!(
+            Rates::new(vec![]).overlay_values_from(today, &labels, Granularity::Hour),
+            vec![None; 3]
+        );
+    }
+
+    #[test]
+    fn test_min_max_rate_today_pick_earliest_tie() {
+        let midnight = london_midnight_utc(london_today());
+        let rates = half_hourly(midnight, 5, |i| [15.0, 4.0, 30.0, 4.0, 30.0][i]);
+
+        let min = rates.min_rate_today().unwrap();
+        let max = rates.max_rate_today().unwrap();
+
+        assert_eq!(min.valid_from, midnight + chrono::Duration::minutes(30));
+        assert_eq!(max.valid_from, midnight + chrono::Duration::minutes(60));
+    }
+
+    #[test]
+    fn test_min_max_rate_today_without_rates_today() {
+        let yesterday = london_midnight_utc(london_today()) - chrono::Duration::days(1);
+        let rates = half_hourly(yesterday, 4, |i| i as f64);
+
+        assert!(rates.min_rate_today().is_none());
+        assert!(rates.max_rate_today().is_none());
+        assert!(Rates::new(vec![]).min_rate_today().is_none());
+        assert!(Rates::new(vec![]).max_rate_today().is_none());
+    }
+
+    #[test]
+    fn test_records_across_months() {
+        let at = |month, day, hour| Utc.with_ymd_and_hms(2024, month, day, hour, 0, 0).unwrap();
+        let rate = |from: DateTime<Utc>, value: f64| Rate {
+            value_inc_vat: value,
+            value_exc_vat: value / 1.05,
+            valid_from: from,
+            valid_to: from + chrono::Duration::minutes(30),
+        };
+        let rates = Rates::new(vec![
+            rate(at(2, 10, 3), -4.3),
+            rate(at(2, 20, 17), 35.0),
+            rate(at(3, 2, 3), -4.3),
+            rate(at(3, 2, 18), 40.0),
+            rate(at(3, 9, 18), 40.0),
+            // 23:30 UTC on 31 March is already April in London
+            rate(Utc.with_ymd_and_hms(2024, 3, 31, 23, 30, 0).unwrap(), 12.0),
+        ]);
+
+        assert_eq!(rates.record_low().unwrap().valid_from, at(2, 10, 3));
+        assert_eq!(rates.record_high().unwrap().valid_from, at(3, 2, 18));
+
+        let months = rates.records_by_month();
+        assert_eq!(
+            months.keys().copied().collect::<Vec<_>>(),
+            vec![(2024, 2), (2024, 3), (2024, 4)]
+        );
+        let march = &months[&(2024, 3)];
+        assert_eq!(march.low.valid_from, at(3, 2, 3));
+        assert_eq!(march.high.valid_from, at(3, 2, 18));
+        assert_eq!(months[&(2024, 4)].low, months[&(2024, 4)].high);
+    }
+
+    #[test]
+    fn test_records_of_empty_history() {
+        let rates = Rates::new(vec![]);
+
+        assert!(rates.record_low().is_none());
+        assert!(rates.record_high().is_none());
+        assert!(rates.records_by_month().is_empty());
+    }
+
+    #[test]
+    fn test_banded_stats_counts_full_day() {
+        let midnight = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let rates = half_hourly(midnight, SLOTS_PER_DAY, |i| i as f64);
+        let today = chrono::NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        let stats = rates.banded_stats_on(today, &default_bands());
+
+        let counts: Vec<(&str, usize)> =
+            stats.iter().map(|b| (b.label.as_str(), b.count)).collect();
+        assert_eq!(
+            counts,
+            vec![("Overnight", 14), ("Day", 18), ("Peak", 6), ("Evening", 10)]
+        );
+        assert_eq!(stats.iter().map(|b| b.count).sum::<usize>(), SLOTS_PER_DAY);
+        // Peak is 16:00-19:00, slots 32 to 37
+        assert_eq!(
+            (stats[2].min, stats[2].max, stats[2].mean),
+            (32.0, 37.0, 34.5)
+        );
+    }
+
+    #[test]
+    fn test_banded_stats_band_wrapping_midnight() {
+        let midnight = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let rates = half_hourly(midnight, SLOTS_PER_DAY, |_| 10.0);
+        let today = chrono::NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let at = |h| chrono::NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+
+        // 22:00 to 06:00 covers the first twelve slots and the last four
+        let stats = rates.banded_stats_on(today, &[(at(22), at(6), "Night")]);
+
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].count, 16);
+        assert!(
+            rates
+                .banded_stats_on(today.succ_opt().unwrap(), &default_bands())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_cost_for_counts_unmatched_readings() {
+        use crate::models::consumption::ConsumptionProfile;
+
+        let rates = half_hourly(
+            Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap(),
+            2,
+            |i| [20.0, 40.0][i],
+        );
+        let profile = ConsumptionProfile::from_csv(
+            "2024-01-15T00:00:00Z,1.0
+2024-01-15T00:30:00Z,0.5
+2024-01-15T01:00:00Z,2.0",
+        )
+        .unwrap();
+
+        let breakdown = rates.cost_for(&profile).unwrap();
+        assert!((breakdown.total_kwh - 1.5).abs() < 1e-9);
+        assert!((breakdown.total_cost - 0.4).abs() < 1e-9);
+        assert_eq!(breakdown.unmatched, 1);
+        assert_eq!(breakdown.days, 1);
+        assert!((breakdown.avg_p_per_kwh().unwrap() - 80.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cost_for_errors_when_nothing_matches() {
+        use crate::models::consumption::ConsumptionProfile;
+
+        let rates = half_hourly(
+            Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap(),
+            2,
+            |_| 20.0,
+        );
+        let profile = ConsumptionProfile::from_csv("2024-02-01T00:00:00Z,1.0").unwrap();
+
+        assert!(matches!(
+            rates.cost_for(&profile),
+            Err(AppError::DataError(_))
+        ));
+    }
+
+    #[test]
+    fn test_cost_for_autumn_clock_change_day() {
+        use crate::models::consumption::ConsumptionProfile;
+
+        // 2024-10-27 runs 50 half-hours from 23:00 UTC on the 26th; 01:00 local happens twice
+        let midnight = Utc.with_ymd_and_hms(2024, 10, 26, 23, 0, 0).unwrap();
+        let rates = half_hourly(midnight, 50, |i| i as f64);
+        let csv = (0..50)
+            .map(|i| {
+                let start = midnight + chrono::Duration::minutes(30 * i);
+                let offset = if start < Utc.with_ymd_and_hms(2024, 10, 27, 1, 0, 0).unwrap() {
+                    chrono::FixedOffset::east_opt(3600).unwrap()
+                } else {
+                    chrono::FixedOffset::east_opt(0).unwrap()
+                };
+                format!("{},1.0", start.with_timezone(&offset).to_rfc3339())
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let profile = ConsumptionProfile::from_csv(&csv).unwrap();
+
+        let breakdown = rates.cost_for(&profile).unwrap();
+        assert_eq!(breakdown.unmatched, 0);
+        assert!((breakdown.total_kwh - 50.0).abs() < 1e-9);
+        // Each repeated local hour is priced at its own slot: sum of 0..50 pence
+        assert!((breakdown.total_cost - 12.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cost_for_spring_clock_change_day() {
+        use crate::models::consumption::ConsumptionProfile;
+
+        // 2024-03-31 has 46 half-hours from midnight GMT
+        let midnight = Utc.with_ymd_and_hms(2024, 3, 31, 0, 0, 0).unwrap();
+        let rates = half_hourly(midnight, 46, |_| 10.0);
+        let csv = "Consumption (kWh), Start
+                   1.0, 2024-03-31T00:30:00+00:00
+                   1.0, 2024-03-31T02:00:00+01:00
+";
+        let profile = ConsumptionProfile::from_csv(csv).unwrap();
+
+        let breakdown = rates.cost_for(&profile).unwrap();
+        assert_eq!(breakdown.unmatched, 0);
+        assert!((breakdown.total_cost - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_tomorrow_status_late_boundary_in_local_time() {
+        // Today's prices only: 2024-07-15 in BST runs 23:00 UTC to 23:00 UTC
+        let today = half_hourly(
+            Utc.with_ymd_and_hms(2024, 7, 14, 23, 0, 0).unwrap(),
+            48,
+            |_| 20.0,
+        );
+        assert_eq!(
+            today.latest_available(),
+            Some(Utc.with_ymd_and_hms(2024, 7, 15, 23, 0, 0).unwrap())
+        );
+
+        // 15:59 UTC is 16:59 BST, just before the late hour
+        let before = Utc.with_ymd_and_hms(2024, 7, 15, 15, 59, 0).unwrap();
+        assert_eq!(today.tomorrow_status(before, 17), TomorrowStatus::Pending);
+        let at = Utc.with_ymd_and_hms(2024, 7, 15, 16, 0, 0).unwrap();
+        assert_eq!(today.tomorrow_status(at, 17), TomorrowStatus::Late);
+    }
+
+    #[test]
+    fn test_tomorrow_status_available_once_published() {
+        let both = half_hourly(
+            Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap(),
+            70,
+            |_| 20.0,
+        );
+        let evening = Utc.with_ymd_and_hms(2024, 1, 15, 18, 0, 0).unwrap();
+
+        assert_eq!(both.tomorrow_status(evening, 17), TomorrowStatus::Available);
+        assert_eq!(
+            Rates::new(vec![]).tomorrow_status(evening, 17),
+            TomorrowStatus::Late
+        );
+    }
+
+    #[test]
+    fn test_merge_appends_new_slots_in_order() {
+        let mut rates = Rates::new(vec![make_rate(10, 4.0), make_rate(11, 8.0)]);
+
+        rates.merge(vec![make_rate(13, 16.0), make_rate(12, 12.0)]);
+
+        assert_eq!(rates.all_values(), vec![4.0, 8.0, 12.0, 16.0]);
+    }
+
+    #[test]
+    fn test_merge_prefers_newer_fetch_on_overlap() {
+        let mut rates = Rates::new(vec![make_rate(10, 4.0), make_rate(11, 8.0)]);
+
+        rates.merge(vec![make_rate(11, 9.5), make_rate(12, 12.0)]);
+
+        assert_eq!(rates.all_values(), vec![4.0, 9.5, 12.0]);
+        rates.merge(vec![]);
+        assert_eq!(rates.all_values(), vec![4.0, 9.5, 12.0]);
+    }
+
+    #[test]
+    fn test_new_prefers_later_duplicate() {
+        let rates = Rates::new(vec![
+            make_rate(11, 8.0),
+            make_rate(10, 4.0),
+            make_rate(11, 9.5),
+        ]);
+
+        assert_eq!(rates.all_values(), vec![4.0, 9.5]);
+    }
+
+    #[test]
+    fn test_new_truncates_overlapping_slot() {
+        // An hour-long slot overlapping the next half-hour
+        let mut long = make_rate(10, 4.0);
+        long.valid_to = Utc.with_ymd_and_hms(2024, 1, 15, 11, 0, 0).unwrap();
+        let mut rates = vec![long, make_rate(10, 6.0)];
+        rates[1].valid_from = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap();
+        rates[1].valid_to = Utc.with_ymd_and_hms(2024, 1, 15, 11, 0, 0).unwrap();
+
+        let rates = Rates::new(rates);
+
+        let at = |h, m| {
+            rates
+                .rate_at(Utc.with_ymd_and_hms(2024, 1, 15, h, m, 0).unwrap())
+                .map(|r| r.value_inc_vat)
+        };
+        assert_eq!(at(10, 15), Some(4.0));
+        assert_eq!(at(10, 45), Some(6.0));
+        assert!(rates.gaps().is_empty());
+    }
+
+    #[test]
+    fn test_normalise_reports_changes() {
+        let mut clean = vec![make_rate(10, 4.0), make_rate(11, 8.0)];
+        assert!(!normalise(&mut clean));
+
+        let mut duplicated = vec![make_rate(10, 4.0), make_rate(10, 4.0)];
+        assert!(normalise(&mut duplicated));
+        assert_eq!(duplicated.len(), 1);
+    }
+
+    #[test]
+    fn test_rate_at_correct_after_shuffled_duplicated_input() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let slots = half_hourly(start, 48, |i| i as f64).into_vec();
+
+        for seed in 1..=20_u64 {
+            // Every slot twice, in a seeded pseudo-random order
+            let mut input: Vec<(u64, Rate)> = slots
+                .iter()
+                .chain(&slots)
+                .cloned()
+                .enumerate()
+                .map(|(i, r)| {
+                    let key = (i as u64 + 1)
+                        .wrapping_mul(6_364_136_223_846_793_005)
+                        .wrapping_add(seed.wrapping_mul(1_442_695_040_888_963_407));
+                    (key.rotate_left(17), r)
+                })
+                .collect();
+            input.sort_by_key(|(key, _)| *key);
+
+            let rates = Rates::new(input.into_iter().map(|(_, r)| r).collect());
+
+            assert_eq!(rates.data.len(), 48, "seed {seed}");
+            assert!(rates.gaps().is_empty(), "seed {seed}");
+            for (i, slot) in slots.iter().enumerate() {
+                let mid = slot.valid_from + chrono::Duration::minutes(15);
+                assert_eq!(rates.rate_at(slot.valid_from), Some(slot), "seed {seed}");
+                assert_eq!(
+                    rates.rate_at(mid).map(|r| r.value_inc_vat),
+                    Some(i as f64),
+                    "seed {seed}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_avg_over_full_window() {
+        let rates = Rates::new(vec![
+            make_rate(10, 4.0),
+            make_rate(11, 8.0),
+            make_rate(12, 12.0),
+            make_rate(13, 100.0),
+        ]);
+        let from = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+
+        assert_eq!(rates.avg_over(from, chrono::Duration::hours(3)), Some(8.0));
+    }
+
+    #[test]
+    fn test_avg_over_partial_window_uses_available_slots() {
+        let rates = Rates::new(vec![make_rate(10, 4.0), make_rate(11, 8.0)]);
+        let from = Utc.with_ymd_and_hms(2024, 1, 15, 11, 0, 0).unwrap();
+
+        assert_eq!(rates.avg_over(from, chrono::Duration::hours(4)), Some(8.0));
+        let later = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+        assert_eq!(rates.avg_over(later, chrono::Duration::hours(4)), None);
+    }
+
+    #[test]
+    fn test_cheapest_between_orders_by_price_then_time() {
+        let rates = Rates::new(vec![
+            make_rate(13, 9.0),
+            make_rate(12, 5.0),
+            make_rate(11, 9.0),
+            make_rate(10, 5.0),
+            make_rate(14, 1.0),
+        ]);
+        let from = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let until = Utc.with_ymd_and_hms(2024, 1, 15, 14, 0, 0).unwrap();
+
+        let hours: Vec<u32> = rates
+            .cheapest_between(from, until)
+            .iter()
+            .map(|r| london_time(r.valid_from).hour())
+            .collect();
+
+        // 14:00 is outside the window; ties resolve to the earlier slot
+        assert_eq!(hours, vec![10, 12, 11, 13]);
+    }
+
+    #[test]
+    fn test_weekday_typical_groups_by_weekday() {
+        // 2024-01-15 and 2024-01-22 are Mondays, 2024-01-16 and 2024-01-23 Tuesdays
+        let slot = |day: u32, hour: u32, value: f64| Rate {
+            value_inc_vat: value,
+            value_exc_vat: value / 1.2,
+            valid_from: Utc.with_ymd_and_hms(2024, 1, day, hour, 0, 0).unwrap(),
+            valid_to: Utc.with_ymd_and_hms(2024, 1, day, hour, 30, 0).unwrap(),
+        };
+
+        let history = Rates::new(vec![
+            slot(15, 8, 10.0),
+            slot(15, 18, 30.0),
+            slot(16, 8, 50.0),
+            slot(22, 8, 14.0),
+            slot(22, 18, 26.0),
+            slot(23, 8, 70.0),
+        ]);
+        let today = Rates::new(vec![]);
+
+        assert_eq!(today.weekday_typical(&history, Weekday::Mon), Some(20.0));
+        assert_eq!(today.weekday_typical(&history, Weekday::Tue), Some(60.0));
+        assert_eq!(today.weekday_typical(&history, Weekday::Wed), None);
+    }
+
+    #[test]
+    fn test_weekday_typical_uses_london_date() {
+        // 23:30 UTC on Monday 2024-07-15 is 00:30 BST on Tuesday
+        let history = Rates::new(vec![Rate {
+            value_inc_vat: 12.0,
+            value_exc_vat: 10.0,
+            valid_from: Utc.with_ymd_and_hms(2024, 7, 15, 23, 30, 0).unwrap(),
+            valid_to: Utc.with_ymd_and_hms(2024, 7, 16, 0, 0, 0).unwrap(),
+        }]);
+        let today = Rates::new(vec![]);
+
+        assert_eq!(today.weekday_typical(&history, Weekday::Tue), Some(12.0));
+        assert_eq!(today.weekday_typical(&history, Weekday::Mon), None);
+    }
+
+    #[test]
+    fn test_weekday_typical_skips_slots_shared_with_today() {
+        // 2024-01-22, a Monday, is in both the history and the rates being compared
+        let slot = |day: u32, value: f64| Rate {
+            value_inc_vat: value,
+            value_exc_vat: value / 1.2,
+            valid_from: Utc.with_ymd_and_hms(2024, 1, day, 8, 0, 0).unwrap(),
+            valid_to: Utc.with_ymd_and_hms(2024, 1, day, 8, 30, 0).unwrap(),
+        };
+        let history = Rates::new(vec![slot(15, 10.0), slot(22, 40.0)]);
+        let today = Rates::new(vec![slot(22, 40.0)]);
+
+        assert_eq!(today.weekday_typical(&history, Weekday::Mon), Some(10.0));
+    }
+
+    #[test]
+    fn test_daily_averages_handles_partial_days() {
+        use chrono::NaiveDate;
+
+        let slot = |day: u32, hour: u32, value: f64| Rate {
+            value_inc_vat: value,
+            value_exc_vat: value / 1.2,
+            valid_from: Utc.with_ymd_and_hms(2024, 1, day, hour, 0, 0).unwrap(),
+            valid_to: Utc.with_ymd_and_hms(2024, 1, day, hour, 30, 0).unwrap(),
+        };
+
+        let history = Rates::new(vec![
+            slot(16, 9, 40.0),
+            slot(15, 8, 10.0),
+            slot(15, 12, 20.0),
+            slot(15, 18, 30.0),
+        ]);
+
+        assert_eq!(
+            history.daily_averages(),
+            vec![
+                (NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(), 20.0),
+                (NaiveDate::from_ymd_opt(2024, 1, 16).unwrap(), 40.0),
+            ]
+        );
+        assert!(Rates::new(vec![]).daily_averages().is_empty());
+    }
+
+    #[test]
+    fn test_daily_matrix_shape() {
+        // A full day, then half of the next
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let rates = half_hourly(start, SLOTS_PER_DAY + 24, |i| i as f64);
+
+        let matrix = rates.to_daily_matrix();
+
+        assert_eq!(matrix.len(), 2);
+        assert!(matrix.iter().all(|(_, row)| row.len() == SLOTS_PER_DAY));
+        assert!(matrix[0].1.iter().all(Option::is_some));
+        assert_eq!(matrix[1].1[0], Some(48.0));
+        assert_eq!(matrix[1].1.iter().filter(|p| p.is_some()).count(), 24);
+    }
+
+    #[test]
+    fn test_daily_matrix_caps_autumn_day() {
+        // 50 slots on the day the clocks go back
+        let start = london_midnight_utc(chrono::NaiveDate::from_ymd_opt(2024, 10, 27).unwrap());
+        let rates = half_hourly(start, 50, |_| 10.0);
+
+        let matrix = rates.to_daily_matrix();
+
+        assert_eq!(matrix.len(), 1);
+        assert_eq!(matrix[0].1.len(), SLOTS_PER_DAY);
+        assert!(Rates::new(vec![]).to_daily_matrix().is_empty());
+    }
+
+    #[test]
+    fn test_daily_matrix_leaves_missing_slots_empty() {
+        use chrono::NaiveDate;
+
+        let slot = |month: u32, day: u32, hour: u32, minute: u32, value: f64| Rate {
+            value_inc_vat: value,
+            value_exc_vat: value / 1.2,
+            valid_from: Utc
+                .with_ymd_and_hms(2024, month, day, hour, minute, 0)
+                .unwrap(),
+            valid_to: Utc
+                .with_ymd_and_hms(2024, month, day, hour, minute, 0)
+                .unwrap()
+                + chrono::Duration::minutes(30),
+        };
+
+        // 2024-03-31 is the spring change: 01:00 UTC is already 02:00 BST
+        let history = Rates::new(vec![
+            slot(3, 30, 0, 30, 12.0),
+            slot(3, 31, 0, 30, 14.0),
+            slot(3, 31, 1, 0, 16.0),
+        ]);
+
+        let matrix = history.to_daily_matrix();
+        assert_eq!(matrix.len(), 2);
+
+        let (date, row) = &matrix[0];
+        assert_eq!(*date, NaiveDate::from_ymd_opt(2024, 3, 30).unwrap());
+        assert_eq!(row.len(), SLOTS_PER_DAY);
+        assert_eq!(row[1], Some(12.0));
+        assert_eq!(row.iter().flatten().count(), 1);
+
+        let (_, row) = &matrix[1];
+        assert_eq!(row[1], Some(14.0));
+        // 01:00–02:00 local never happens, so those slots stay empty
+        assert_eq!(row[2], None);
+        assert_eq!(row[3], None);
+        assert_eq!(row[4], Some(16.0));
+    }
+
+    #[test]
+    fn test_is_flat_detects_flat_day() {
+        use chrono::NaiveDate;
+
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let rates = Rates::new(vec![
+            make_rate(10, 15.0),
+            make_rate(11, 15.25),
+            make_rate(12, 15.5),
+        ]);
+
+        assert!(rates.is_flat_on(date, 1.0));
+        // Exactly at the tolerance still counts as flat
+        assert!(rates.is_flat_on(date, 0.5));
+        assert!(!rates.is_flat_on(date, 0.25));
+    }
+
+    #[test]
+    fn test_is_flat_rejects_volatile_day() {
+        use chrono::NaiveDate;
+
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let rates = Rates::new(vec![
+            make_rate(10, 5.0),
+            make_rate(11, 35.0),
+            make_rate(12, 15.0),
+        ]);
+
+        assert!(!rates.is_flat_on(date, 1.0));
+        assert!(!rates.is_flat_on(date.succ_opt().unwrap(), 1.0));
+    }
+
+    #[test]
+    fn test_daily_stats_csv_rows_per_day_and_skips_missing() {
+        use chrono::NaiveDate;
+
+        let rates = make_midnight_rates();
+        let from = NaiveDate::from_ymd_opt(2024, 1, 14).unwrap();
+        let to = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
+
+        // 2024-01-14 has no rates and is left out
+        assert_eq!(
+            rates.daily_stats_csv(from, to),
+            "date,min,max,avg,slots\n\
+             2024-01-15,10.00,12.00,11.00,3\n\
+             2024-01-16,20.00,21.00,20.50,2\n"
+        );
+    }
+
+    #[test]
+    fn test_to_csv_rows_in_chronological_order() {
+        // Constructed out of order; Rates::new sorts
+        let rates = Rates::new(vec![make_rate(11, -1.5), make_rate(10, 20.25)]);
+
+        assert_eq!(
+            rates.to_csv(),
+            "valid_from,valid_to,value_inc_vat\n\
+             2024-01-15T10:00:00Z,2024-01-15T10:30:00Z,20.25\n\
+             2024-01-15T11:00:00Z,2024-01-15T11:30:00Z,-1.5\n"
+        );
+    }
+
+    #[test]
+    fn test_to_csv_fields_need_no_escaping() {
+        let csv = make_midnight_rates().to_csv();
+
+        assert!(!csv.contains('"'));
+        assert!(csv.lines().all(|line| line.split(',').count() == 3));
+        assert_eq!(
+            Rates::new(vec![]).to_csv(),
+            "valid_from,valid_to,value_inc_vat\n"
+        );
+    }
+
+    #[test]
+    fn test_daily_stats_csv_header_only_when_empty() {
+        use chrono::NaiveDate;
+
+        let rates = make_midnight_rates();
+        let date = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+
+        assert_eq!(
+            rates.daily_stats_csv(date, date),
+            "date,min,max,avg,slots\n"
+        );
+    }
+
+    fn make_half_hourly(start: DateTime<Utc>, prices: &[f64]) -> Rates {
+        Rates::new(
+            prices
+                .iter()
+                .zip(0..)
+                .map(|(&value, i)| {
+                    let valid_from = start + chrono::Duration::minutes(30 * i);
+                    Rate {
+                        value_inc_vat: value,
+                        value_exc_vat: value / 1.2,
+                        valid_from,
+                        valid_to: valid_from + chrono::Duration::minutes(30),
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let rates = make_midnight_rates();
+
+        let json = rates.to_json().unwrap();
+        assert!(json.starts_with("{\"data\":["));
+        assert_eq!(Rates::from_json(&json).unwrap(), rates);
+    }
+
+    #[test]
+    fn test_from_json_accepts_unsorted_api_response() {
+        let json = r#"{
+            "count": 2,
+            "next": null,
+            "results": [
+                {
+                    "value_exc_vat": 20.0,
+                    "value_inc_vat": 21.0,
+                    "valid_from": "2024-01-15T10:30:00Z",
+                    "valid_to": "2024-01-15T11:00:00Z"
+                },
+                {
+                    "value_exc_vat": 10.0,
+                    "value_inc_vat": 10.5,
+                    "valid_from": "2024-01-15T10:00:00Z",
+                    "valid_to": "2024-01-15T10:30:00Z"
+                }
+            ]
+        }"#;
+
+        let rates = Rates::from_json(json).unwrap();
+        assert_eq!(rates.all_values(), vec![10.5, 21.0]);
+    }
+
+    #[test]
+    fn test_from_json_rejects_invalid_input() {
+        assert!(matches!(
+            Rates::from_json("{\"data\": [{\"value_inc_vat\": \"cheap\"}]}"),
+            Err(AppError::DataError(_))
+        ));
+        assert!(matches!(
+            Rates::from_json("not json"),
+            Err(AppError::DataError(_))
+        ));
+    }
+
+    #[test]
+    fn test_cheapest_window_finds_price_valley() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let rates = make_half_hourly(start, &[20.0, 18.0, 9.0, 5.0, 7.0, 6.0, 15.0, 22.0]);
+        let search_to = start + chrono::Duration::hours(4);
+
+        // Two hours: 01:00-03:00 (9, 5, 7, 6) beats every other block
+        let (window_start, mean) = rates
+            .cheapest_window(chrono::Duration::hours(2), start, search_to)
+            .unwrap();
+        assert_eq!(window_start, start + chrono::Duration::hours(1));
+        assert!((mean - 6.75).abs() < 1e-9);
+
+        // One hour: 01:30-02:30 (5, 7) averages 6.0, just below 02:00-03:00 at 6.5
+        let (window_start, mean) = rates
+            .cheapest_window(chrono::Duration::hours(1), start, search_to)
+            .unwrap();
+        assert_eq!(window_start, start + chrono::Duration::minutes(90));
+        assert!((mean - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cheapest_block_searches_to_end_of_data() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 16, 0, 0).unwrap();
+        let rates = make_half_hourly(start, &[30.0, 2.0, 25.0, 12.0, 11.0, 10.0]);
+        let now = start + chrono::Duration::minutes(45);
+
+        // The 2p slot has started, so the cheapest hour left is the final one
+        let (block_start, block_end, mean) = rates
+            .cheapest_block(chrono::Duration::hours(1), now)
+            .unwrap();
+        assert_eq!(block_start, start + chrono::Duration::hours(2));
+        assert_eq!(block_end, start + chrono::Duration::hours(3));
+        assert!((mean - 10.5).abs() < 1e-9);
+
+        // Four hours no longer fits in the remaining data
+        assert!(
+            rates
+                .cheapest_block(chrono::Duration::hours(4), now)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_cheapest_window_spans_midnight() {
+        let rates = make_midnight_rates();
+        let from = Utc.with_ymd_and_hms(2024, 1, 15, 23, 30, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2024, 1, 16, 1, 0, 0).unwrap();
+
+        let (start, mean) = rates
+            .cheapest_window(chrono::Duration::hours(1), from, to)
+            .unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2024, 1, 15, 23, 30, 0).unwrap());
+        assert!((mean - 16.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cheapest_window_requires_full_slot_coverage() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let rates = make_half_hourly(start, &[10.0, 1.0, 1.0, 10.0]);
+
+        // 40 minutes needs two whole slots, which must fit before search_to
+        let (window_start, mean) = rates
+            .cheapest_window(
+                chrono::Duration::minutes(40),
+                start,
+                start + chrono::Duration::hours(2),
+            )
+            .unwrap();
+        assert_eq!(window_start, start + chrono::Duration::minutes(30));
+        assert!((mean - 1.0).abs() < 1e-9);
+        assert!(
+            rates
+                .cheapest_window(
+                    chrono::Duration::minutes(40),
+                    start,
+                    start + chrono::Duration::minutes(45),
+                )
+                .is_none()
+        );
+        assert!(
+            rates
+                .cheapest_window(
+                    chrono::Duration::zero(),
+                    start,
+                    start + chrono::Duration::hours(2)
+                )
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_cheapest_window_respects_gaps() {
+        // 10:00, 11:00 (gap at 10:30), 11:30: only 11:00-12:00 is contiguous
+        let rates = Rates::new(vec![
+            make_rate(10, 1.0),
+            make_rate(11, 20.0),
+            Rate {
+                value_inc_vat: 30.0,
+                value_exc_vat: 25.0,
+                valid_from: Utc.with_ymd_and_hms(2024, 1, 15, 11, 30, 0).unwrap(),
+                valid_to: Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap(),
+            },
+        ]);
+        let from = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+
+        let (start, mean) = rates
+            .cheapest_window(chrono::Duration::hours(1), from, to)
+            .unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2024, 1, 15, 11, 0, 0).unwrap());
+        assert!((mean - 25.0).abs() < 1e-9);
+        assert!(
+            rates
+                .cheapest_window(chrono::Duration::minutes(90), from, to)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_cost_for_schedule_sums_multiple_slots() {
+        let rates = Rates::new(vec![make_rate(10, 20.0), make_rate(11, 10.0)]);
+        let schedule = [
+            (Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap(), 2.0),
+            // Mid-slot timestamps resolve to the covering slot
+            (Utc.with_ymd_and_hms(2024, 1, 15, 11, 10, 0).unwrap(), 3.0),
+        ];
+
+        let cost = rates.cost_for_schedule(&schedule).unwrap();
+        assert!((cost - 0.7).abs() < 1e-9);
+        assert!(rates.cost_for_schedule(&[]).unwrap().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_cost_for_schedule_errors_on_gap() {
+        // 10:30 falls in the gap between the 10:00 and 11:00 slots
+        let rates = Rates::new(vec![make_rate(10, 20.0), make_rate(11, 10.0)]);
+        let schedule = [
+            (Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap(), 1.0),
+            (Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap(), 1.0),
+        ];
+
+        assert!(matches!(
+            rates.cost_for_schedule(&schedule),
+            Err(AppError::DataError(_))
+        ));
+    }
+
+    #[test]
+    fn test_median_with_odd_and_even_slot_counts() {
+        // 2024-01-15 has three slots (10, 11, 12), 2024-01-16 has two (20, 21)
+        let rates = make_midnight_rates();
+        let odd = rates
+            .stats_for_date(chrono::NaiveDate::from_ymd_opt(2024, 1, 15).unwrap())
+            .unwrap();
+        let even = rates
+            .stats_for_date(chrono::NaiveDate::from_ymd_opt(2024, 1, 16).unwrap())
+            .unwrap();
+
+        assert!((odd.median - 11.0).abs() < 1e-9);
+        assert!((even.median - 20.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_median_is_not_skewed_by_peak() {
+        let rates = Rates::new(vec![
+            make_rate(10, 10.0),
+            make_rate(11, 12.0),
+            make_rate(12, 11.0),
+            make_rate(17, 60.0),
+        ]);
+        let stats = rates
+            .stats_for_date(chrono::NaiveDate::from_ymd_opt(2024, 1, 15).unwrap())
+            .unwrap();
+
+        assert!((stats.median - 11.5).abs() < 1e-9);
+        assert!(stats.avg > stats.median);
+    }
+
+    #[test]
+    fn test_percentile_rank() {
+        let values = [10.0, 20.0, 30.0, 40.0];
+
+        assert!((percentile_rank(&values, 5.0)).abs() < 1e-9);
+        assert!((percentile_rank(&values, 20.0) - 37.5).abs() < 1e-9);
+        assert!((percentile_rank(&values, 50.0) - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_percentile_on_ranks_against_that_day_only() {
+        let mut day = make_rate(0, 99.0);
+        day.valid_from -= chrono::Duration::days(1);
+        day.valid_to -= chrono::Duration::days(1);
+        let rates = Rates::new(vec![
+            day,
+            make_rate(10, 10.0),
+            make_rate(11, 20.0),
+            make_rate(12, 30.0),
+            make_rate(13, 40.0),
+        ]);
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        assert!((rates.percentile_on(date, 20.0) - 37.5).abs() < 1e-9);
+        assert!((rates.percentile_on(date, 50.0) - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_percentile_rank_flat_day_is_not_nan() {
+        let rank = percentile_rank(&[15.0, 15.0, 15.0], 15.0);
+
+        assert!(!rank.is_nan());
+        assert!((rank - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_gaps_finds_hole_between_slots() {
+        // 10:30-12:00 is missing
+        let rates = Rates::new(vec![make_rate(10, 15.0), make_rate(12, 18.0)]);
+
+        assert_eq!(
+            rates.gaps(),
+            vec![(
+                Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap(),
+            )]
+        );
+    }
+
+    #[test]
+    fn test_gaps_empty_for_contiguous_rates() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        assert!(half_hourly(start, 6, |_| 10.0).gaps().is_empty());
+        assert!(Rates::new(vec![]).gaps().is_empty());
+    }
+
+    #[test]
+    fn test_fill_gaps_inserts_placeholders() {
+        let rates = Rates::new(vec![make_rate(10, 15.0), make_rate(12, 18.0)]);
+
+        let filled = rates.fill_gaps(0.0);
+
+        assert!(filled.gaps().is_empty());
+        assert_eq!(filled.data.len(), 5);
+        assert_eq!(filled.all_values(), vec![15.0, 0.0, 0.0, 0.0, 18.0]);
+        assert_eq!(
+            filled.data[1].valid_from,
+            Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap()
+        );
+        // The original is untouched
+        assert_eq!(rates.gaps().len(), 1);
+    }
+
+    #[test]
+    fn test_fill_gaps_clips_last_placeholder() {
+        let mut late = make_rate(11, 18.0);
+        late.valid_from = Utc.with_ymd_and_hms(2024, 1, 15, 11, 15, 0).unwrap();
+        let rates = Rates::new(vec![make_rate(10, 15.0), late]);
+
+        let filled = rates.fill_gaps(9.0);
+
+        assert!(filled.gaps().is_empty());
+        assert_eq!(filled.data.len(), 4);
+        assert_eq!(
+            filled.data[2].valid_to,
+            Utc.with_ymd_and_hms(2024, 1, 15, 11, 15, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_negative_periods_with_mixed_prices() {
+        let rates = Rates::new(vec![
+            make_rate(12, 3.0),
+            make_rate(14, -0.5),
+            make_rate(13, -2.1),
+            make_rate(15, 0.0),
+        ]);
+
+        let negative: Vec<f64> = rates
+            .negative_periods()
+            .iter()
+            .map(|r| r.value_inc_vat)
+            .collect();
+        // Chronological, and a zero price is not a plunge
+        assert_eq!(negative, vec![-2.1, -0.5]);
+    }
+
+    #[test]
+    fn test_has_negative_on_date() {
+        use chrono::NaiveDate;
+
+        let rates = Rates::new(vec![make_rate(12, 3.0), make_rate(13, -2.1)]);
+        let day = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        assert!(rates.has_negative_on(day));
+        assert!(!rates.has_negative_on(day.succ_opt().unwrap()));
+        assert!(!make_midnight_rates().has_negative_on(day));
+    }
+
+    #[test]
+    fn test_daily_cost_adds_standing_charge() {
+        use chrono::NaiveDate;
+
+        // Average of 10, 20 and 30 is 20p/kWh
+        let rates = Rates::new(vec![
+            make_rate(10, 10.0),
+            make_rate(11, 20.0),
+            make_rate(12, 30.0),
+        ]);
+        let day = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        let cost = rates.daily_cost_on(day, 8.0, 48.5).unwrap();
+        assert!((cost - 208.5).abs() < 1e-9);
+        assert!(matches!(
+            rates.daily_cost_on(day.succ_opt().unwrap(), 8.0, 48.5),
+            Err(AppError::DataError(_))
+        ));
+    }
+
+    #[test]
+    fn test_savings_vs_flat_either_side_of_average() {
+        use chrono::NaiveDate;
+
+        // Average of 10, 20 and 30 is 20p/kWh
+        let rates = Rates::new(vec![
+            make_rate(10, 10.0),
+            make_rate(11, 20.0),
+            make_rate(12, 30.0),
+        ]);
+        let day = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        let saved = rates.savings_vs_flat_on(day, 24.5, 8.0).unwrap();
+        assert!((saved - 36.0).abs() < 1e-9);
+        let lost = rates.savings_vs_flat_on(day, 15.0, 8.0).unwrap();
+        assert!((lost + 40.0).abs() < 1e-9);
+        assert!(matches!(
+            rates.savings_vs_flat_on(day.succ_opt().unwrap(), 24.5, 8.0),
+            Err(AppError::DataError(_))
+        ));
+    }
+
+    #[test]
+    fn test_cached_rates_round_trip_and_freshness() {
+        let fetched_at = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+        let cached = CachedRates::new(make_midnight_rates(), fetched_at);
+
+        let json = serde_json::to_string(&cached).unwrap();
+        let restored: CachedRates = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, cached);
+
+        let max_age = chrono::Duration::hours(6);
+        let fresh = fetched_at + chrono::Duration::hours(5);
+        let expired = fetched_at + chrono::Duration::hours(6);
+        assert_eq!(
+            restored.clone().into_fresh(fresh, max_age),
+            Some(make_midnight_rates())
+        );
+        assert!(restored.clone().into_fresh(expired, max_age).is_none());
+        // A timestamp from the future (clock skew) is not trusted
+        assert!(
+            restored
+                .into_fresh(fetched_at - chrono::Duration::hours(1), max_age)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_series_data_for_each_day_of_two() {
+        use chrono::NaiveDate;
+
+        let rates = make_midnight_rates();
+        let (today_x, today_y) = rates
+            .series_data_for(NaiveDate::from_ymd_opt(2024, 1, 15).unwrap())
+            .unwrap();
+        let (tomorrow_x, tomorrow_y) = rates
+            .series_data_for(NaiveDate::from_ymd_opt(2024, 1, 16).unwrap())
+            .unwrap();
+
+        assert_eq!(today_x, vec!["Mon 22:30", "Mon 23:00", "Mon 23:30"]);
+        assert_eq!(today_y, vec![10.0, 11.0, 12.0]);
+        assert_eq!(tomorrow_x, vec!["Tue 00:00", "Tue 00:30"]);
+        assert_eq!(tomorrow_y, vec![20.0, 21.0]);
+    }
+
+    #[test]
+    fn test_series_data_for_errors_without_tomorrow() {
+        use chrono::NaiveDate;
+
+        let rates = Rates::new(vec![make_rate(10, 15.0), make_rate(11, 16.0)]);
+
+        assert!(
+            rates
+                .series_data_for(NaiveDate::from_ymd_opt(2024, 1, 15).unwrap())
+                .is_ok()
+        );
+        assert!(
+            rates
+                .series_data_for(NaiveDate::from_ymd_opt(2024, 1, 16).unwrap())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_has_tomorrow_at_midnight_boundary() {
+        use chrono::NaiveDate;
+
+        let today = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let until_midnight = Rates::new(vec![
+            make_rate(23, 12.0),
+            Rate {
+                value_inc_vat: 11.0,
+                value_exc_vat: 9.17,
+                valid_from: Utc.with_ymd_and_hms(2024, 1, 15, 23, 30, 0).unwrap(),
+                valid_to: Utc.with_ymd_and_hms(2024, 1, 16, 0, 0, 0).unwrap(),
+            },
+        ]);
+
+        // The last slot ends at midnight but starts today
+        assert!(!until_midnight.has_rates_after(today));
+        assert!(make_midnight_rates().has_rates_after(today));
+        assert!(!Rates::new(vec![]).has_rates_after(today));
+    }
+
+    #[test]
+    fn test_has_tomorrow_uses_london_midnight_in_bst() {
+        use chrono::NaiveDate;
+
+        // 23:00 UTC on 1 July is 00:00 on 2 July in London
+        let rates = Rates::new(vec![Rate {
+            value_inc_vat: 9.0,
+            value_exc_vat: 7.5,
+            valid_from: Utc.with_ymd_and_hms(2024, 7, 1, 23, 0, 0).unwrap(),
+            valid_to: Utc.with_ymd_and_hms(2024, 7, 1, 23, 30, 0).unwrap(),
+        }]);
+
+        assert!(rates.has_rates_after(NaiveDate::from_ymd_opt(2024, 7, 1).unwrap()));
+    }
 }
@@ -0,0 +1,248 @@
+use super::carbon::CarbonForecast;
+use super::rates::{Rates, slots_for};
+use chrono::{DateTime, Utc};
+
+/// A half-hour where both a price and a carbon figure are known
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct AlignedSlot {
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    price: f64,
+    intensity: u32,
+}
+
+/// Price and intensity ranges used to put both on a common 0–1 scale
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreScale {
+    price_min: f64,
+    price_max: f64,
+    intensity_min: u32,
+    intensity_max: u32,
+}
+
+impl ScoreScale {
+    /// Scale spanning the given `(price, intensity)` pairs; `None` if there are none
+    pub fn from_pairs(pairs: impl IntoIterator<Item = (f64, u32)>) -> Option<Self> {
+        pairs.into_iter().fold(None, |scale, (price, intensity)| {
+            Some(match scale {
+                None => Self {
+                    price_min: price,
+                    price_max: price,
+                    intensity_min: intensity,
+                    intensity_max: intensity,
+                },
+                Some(s) => Self {
+                    price_min: s.price_min.min(price),
+                    price_max: s.price_max.max(price),
+                    intensity_min: s.intensity_min.min(intensity),
+                    intensity_max: s.intensity_max.max(intensity),
+                },
+            })
+        })
+    }
+
+    /// Blends normalised price and intensity; lower is better. `weight` (clamped to 0–1)
+    /// is the share given to carbon, so 0 ranks on price alone and 1 on carbon alone.
+    /// A flat series contributes 0.
+    pub fn combined_score(&self, rate: f64, intensity: u32, weight: f64) -> f64 {
+        let weight = weight.clamp(0.0, 1.0);
+        let price = normalise(rate, self.price_min, self.price_max);
+        let carbon = normalise(
+            f64::from(intensity),
+            f64::from(self.intensity_min),
+            f64::from(self.intensity_max),
+        );
+        weight.mul_add(carbon - price, price)
+    }
+}
+
+fn normalise(value: f64, min: f64, max: f64) -> f64 {
+    let range = max - min;
+    if range <= f64::EPSILON {
+        0.0
+    } else {
+        ((value - min) / range).clamp(0.0, 1.0)
+    }
+}
+
+/// Winning block from `best_combined_window`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BestWindow {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// Mean unit price (p/kWh) across the block
+    pub price: f64,
+    /// Mean carbon intensity (gCO₂/kWh) across the block
+    pub intensity: f64,
+    pub score: f64,
+}
+
+/// Best contiguous block of `duration` balancing price against carbon intensity.
+///
+/// The two series share the half-hourly grid, so slots are matched on exact start and
+/// end times; a slot missing or misaligned in either series is skipped, and no block
+/// spans one. Scores are normalised over the overlapping slots only. Ties go to the
+/// earliest block.
+pub fn best_combined_window(
+    rates: &Rates,
+    forecast: &CarbonForecast,
+    duration: chrono::Duration,
+    weight: f64,
+) -> Option<BestWindow> {
+    let slots = slots_for(duration)?;
+    let aligned: Vec<AlignedSlot> = forecast
+        .periods()
+        .iter()
+        .filter_map(|period| {
+            let rate = rates.rate_at(period.from)?;
+            (rate.valid_from == period.from && rate.valid_to == period.to).then(|| AlignedSlot {
+                from: period.from,
+                to: period.to,
+                price: rate.value_inc_vat,
+                intensity: period.best_intensity(),
+            })
+        })
+        .collect();
+    let scale = ScoreScale::from_pairs(aligned.iter().map(|s| (s.price, s.intensity)))?;
+
+    aligned
+        .windows(slots)
+        .filter(|window| window.windows(2).all(|pair| pair[0].to == pair[1].from))
+        .map(|window| {
+            let n = slots as f64;
+            let mean = |f: fn(&AlignedSlot) -> f64| window.iter().map(f).sum::<f64>() / n;
+            BestWindow {
+                start: window[0].from,
+                end: window[slots - 1].to,
+                price: mean(|s| s.price),
+                intensity: mean(|s| f64::from(s.intensity)),
+                score: window
+                    .iter()
+                    .map(|s| scale.combined_score(s.price, s.intensity, weight))
+                    .sum::<f64>()
+                    / n,
+            }
+        })
+        .min_by(|a, b| a.score.total_cmp(&b.score))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::carbon::{CarbonIntensityData, Intensity, IntensityIndex};
+    use crate::models::rates::Rate;
+    use chrono::TimeZone;
+
+    fn slot(i: i64) -> (DateTime<Utc>, DateTime<Utc>) {
+        let start = Utc.with_ymd_and_hms(2026, 1, 12, 0, 0, 0).unwrap();
+        let from = start + chrono::Duration::minutes(30 * i);
+        (from, from + chrono::Duration::minutes(30))
+    }
+
+    fn make_rates(first: i64, prices: &[f64]) -> Rates {
+        Rates::new(
+            prices
+                .iter()
+                .zip(first..)
+                .map(|(&value, i)| {
+                    let (valid_from, valid_to) = slot(i);
+                    Rate {
+                        value_inc_vat: value,
+                        value_exc_vat: value / 1.05,
+                        valid_from,
+                        valid_to,
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    fn make_forecast(first: i64, intensities: &[u32]) -> CarbonForecast {
+        CarbonForecast::new(
+            intensities
+                .iter()
+                .zip(first..)
+                .map(|(&forecast, i)| {
+                    let (from, to) = slot(i);
+                    CarbonIntensityData {
+                        from,
+                        to,
+                        intensity: Intensity {
+                            forecast,
+                            actual: None,
+                            index: IntensityIndex::Moderate,
+                        },
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_combined_score_blends_by_weight() {
+        let scale = ScoreScale::from_pairs([(10.0, 100), (30.0, 300)]).unwrap();
+
+        assert!(scale.combined_score(10.0, 300, 0.0).abs() < 1e-9);
+        assert!((scale.combined_score(10.0, 300, 1.0) - 1.0).abs() < 1e-9);
+        assert!((scale.combined_score(20.0, 100, 0.5) - 0.25).abs() < 1e-9);
+        // Out-of-range weights are clamped
+        assert!((scale.combined_score(10.0, 300, 7.0) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_combined_score_flat_series() {
+        let scale = ScoreScale::from_pairs([(15.0, 200), (15.0, 200)]).unwrap();
+        assert!(scale.combined_score(15.0, 200, 0.5).abs() < 1e-9);
+        assert!(ScoreScale::from_pairs([]).is_none());
+    }
+
+    #[test]
+    fn test_weight_switches_winner() {
+        // Slot 0 is cheap but dirty, slot 2 is green but pricey
+        let rates = make_rates(0, &[5.0, 20.0, 25.0]);
+        let forecast = make_forecast(0, &[300, 200, 50]);
+        let half_hour = chrono::Duration::minutes(30);
+
+        let cheapest = best_combined_window(&rates, &forecast, half_hour, 0.0).unwrap();
+        let greenest = best_combined_window(&rates, &forecast, half_hour, 1.0).unwrap();
+        assert_eq!(cheapest.start, slot(0).0);
+        assert_eq!(greenest.start, slot(2).0);
+        assert!((greenest.price - 25.0).abs() < 1e-9);
+        assert!((greenest.intensity - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_partially_overlapping_ranges_use_overlap_only() {
+        // Rates cover slots 0–3, the forecast slots 2–5; only 2 and 3 overlap
+        let rates = make_rates(0, &[1.0, 1.0, 10.0, 20.0]);
+        let forecast = make_forecast(2, &[100, 100, 10, 10]);
+
+        let best =
+            best_combined_window(&rates, &forecast, chrono::Duration::hours(1), 0.5).unwrap();
+        assert_eq!((best.start, best.end), (slot(2).0, slot(3).1));
+        assert!((best.price - 15.0).abs() < 1e-9);
+
+        // No two-hour block fits in the overlap
+        assert!(best_combined_window(&rates, &forecast, chrono::Duration::hours(2), 0.5).is_none());
+    }
+
+    #[test]
+    fn test_misaligned_and_missing_periods_are_skipped() {
+        let rates = make_rates(0, &[10.0, 12.0, 8.0]);
+        let mut periods = make_forecast(0, &[100, 90, 80]).periods().to_vec();
+        // Shift the middle period off the half-hour grid
+        periods[1].from += chrono::Duration::minutes(15);
+        periods[1].to += chrono::Duration::minutes(15);
+        let forecast = CarbonForecast::new(periods);
+
+        assert!(best_combined_window(&rates, &forecast, chrono::Duration::hours(1), 0.5).is_none());
+        let best =
+            best_combined_window(&rates, &forecast, chrono::Duration::minutes(30), 0.5).unwrap();
+        assert_eq!(best.start, slot(2).0);
+
+        let disjoint = make_forecast(10, &[50]);
+        assert!(
+            best_combined_window(&rates, &disjoint, chrono::Duration::minutes(30), 0.5).is_none()
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Daily standing charge for a tariff, in pence per day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StandingCharge {
+    pub value_inc_vat: f64,
+    pub valid_from: DateTime<Utc>,
+    /// `None` for the charge currently in force with no announced end
+    pub valid_to: Option<DateTime<Utc>>,
+}
+
+impl StandingCharge {
+    /// Whether this charge applies at `time`
+    pub fn covers(&self, time: DateTime<Utc>) -> bool {
+        self.valid_from <= time && self.valid_to.is_none_or(|to| time < to)
+    }
+
+    /// The charge in force at `time`, preferring the most recently started if several overlap
+    pub fn in_force_at(charges: &[Self], time: DateTime<Utc>) -> Option<&Self> {
+        charges
+            .iter()
+            .filter(|c| c.covers(time))
+            .max_by_key(|c| c.valid_from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn make_charge(from_month: u32, to_month: Option<u32>, value: f64) -> StandingCharge {
+        let date = |month| Utc.with_ymd_and_hms(2025, month, 1, 0, 0, 0).unwrap();
+        StandingCharge {
+            value_inc_vat: value,
+            valid_from: date(from_month),
+            valid_to: to_month.map(date),
+        }
+    }
+
+    #[test]
+    fn test_in_force_at_picks_covering_charge() {
+        let charges = vec![make_charge(4, None, 47.85), make_charge(1, Some(4), 45.12)];
+
+        let march = Utc.with_ymd_and_hms(2025, 3, 15, 12, 0, 0).unwrap();
+        let june = Utc.with_ymd_and_hms(2025, 6, 15, 12, 0, 0).unwrap();
+        assert_eq!(
+            StandingCharge::in_force_at(&charges, march).map(|c| c.value_inc_vat),
+            Some(45.12)
+        );
+        assert_eq!(
+            StandingCharge::in_force_at(&charges, june).map(|c| c.value_inc_vat),
+            Some(47.85)
+        );
+    }
+
+    #[test]
+    fn test_in_force_at_none_before_first_charge() {
+        let charges = vec![make_charge(4, None, 47.85)];
+        let before = Utc.with_ymd_and_hms(2025, 3, 31, 23, 59, 0).unwrap();
+
+        assert!(StandingCharge::in_force_at(&charges, before).is_none());
+    }
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// Theme enum representing user's theme preference
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Theme {
+    Light,
+    Dark,
+    Auto, // Follow system preference
+}
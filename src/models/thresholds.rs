@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+/// User-chosen price limits (p/kWh) that mark slots as cheap or expensive
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct PriceThresholds {
+    pub cheap: Option<f64>,
+    pub expensive: Option<f64>,
+}
+
+/// Where a price falls relative to the user's thresholds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThresholdBand {
+    Cheap,
+    Normal,
+    Expensive,
+}
+
+impl PriceThresholds {
+    /// Classifies `value`: strictly under `cheap` is cheap, strictly over `expensive`
+    /// is expensive, and a price exactly on either threshold is normal.
+    pub fn classify(&self, value: f64) -> ThresholdBand {
+        if self.cheap.is_some_and(|cheap| value < cheap) {
+            ThresholdBand::Cheap
+        } else if self.expensive.is_some_and(|expensive| value > expensive) {
+            ThresholdBand::Expensive
+        } else {
+            ThresholdBand::Normal
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thresholds() -> PriceThresholds {
+        PriceThresholds {
+            cheap: Some(10.0),
+            expensive: Some(30.0),
+        }
+    }
+
+    #[test]
+    fn test_classify_exact_boundaries_are_normal() {
+        let t = thresholds();
+
+        assert_eq!(t.classify(10.0), ThresholdBand::Normal);
+        assert_eq!(t.classify(30.0), ThresholdBand::Normal);
+        assert_eq!(t.classify(9.99), ThresholdBand::Cheap);
+        assert_eq!(t.classify(30.01), ThresholdBand::Expensive);
+    }
+
+    #[test]
+    fn test_classify_negative_price_is_cheap() {
+        assert_eq!(thresholds().classify(-2.5), ThresholdBand::Cheap);
+    }
+
+    #[test]
+    fn test_classify_without_thresholds() {
+        let unset = PriceThresholds::default();
+        assert_eq!(unset.classify(-5.0), ThresholdBand::Normal);
+        assert_eq!(unset.classify(100.0), ThresholdBand::Normal);
+
+        let cheap_only = PriceThresholds {
+            cheap: Some(5.0),
+            expensive: None,
+        };
+        assert_eq!(cheap_only.classify(4.0), ThresholdBand::Cheap);
+        assert_eq!(cheap_only.classify(100.0), ThresholdBand::Normal);
+    }
+}
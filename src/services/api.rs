@@ -1,19 +1,31 @@
 use crate::models::{
     error::AppError,
-    rates::{Rate, Rates, TrackerRates},
+    rates::{CachedRates, Fuel, Rate, Rates, TrackerRates},
+    standing_charge::StandingCharge,
 };
+use crate::services::limiter::request_limiter;
+use crate::services::retry::{retry_after_ms, retry_with_backoff};
+use crate::utils::abort::{AbortSignal, abortable};
 use crate::utils::time::{london_date, london_midnight_utc};
 use chrono::{DateTime, Days, Utc};
+use futures::FutureExt;
+use futures::future::{LocalBoxFuture, Shared};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
 
 // CONSTANTS
 const BASE_URL: &str = "https://api.octopus.energy/v1/products";
-const DEFAULT_AGILE_PRODUCT: &str = "AGILE-24-10-01";
 const DEFAULT_TRACKER_PRODUCT: &str = "SILVER-24-10-01";
+const DEFAULT_EXPORT_PRODUCT: &str = "AGILE-OUTGOING-19-05-13";
+const GRID_SUPPLY_POINTS_URL: &str = "https://api.octopus.energy/v1/industry/grid-supply-points/";
 
 /// UK electricity distribution regions used by Octopus Energy.
 /// Each region corresponds to a Distribution Network Operator (DNO) area.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum Region {
     /// Eastern England
     A,
@@ -87,6 +99,35 @@ impl Region {
         }
     }
 
+    /// Region id used by the Carbon Intensity API's regional endpoints.
+    pub const fn carbon_region_id(&self) -> u8 {
+        match self {
+            Self::P => 1,
+            Self::N => 2,
+            Self::G => 3,
+            Self::F => 4,
+            Self::M => 5,
+            Self::D => 6,
+            Self::K => 7,
+            Self::E => 8,
+            Self::B => 9,
+            Self::A => 10,
+            Self::L => 11,
+            Self::H => 12,
+            Self::C => 13,
+            Self::J => 14,
+        }
+    }
+
+    /// Parses a grid supply point group id such as `_C`.
+    pub fn from_group_id(group_id: &str) -> Result<Self, AppError> {
+        group_id
+            .strip_prefix('_')
+            .filter(|code| code.len() == 1)
+            .and_then(|code| code.parse().ok())
+            .ok_or_else(|| AppError::DataError(format!("Unknown grid supply point: {group_id}")))
+    }
+
     /// All available regions.
     pub const fn all() -> &'static [Self] {
         &[
@@ -138,14 +179,84 @@ impl std::str::FromStr for Region {
     }
 }
 
+/// Agile product versions. Each release of the tariff has its own product code,
+/// and customers stay on the version they signed up to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Product {
+    /// Agile Flex, November 2022
+    AgileFlex2211,
+    /// Agile, December 2023
+    Agile2312,
+    /// Agile, April 2024
+    Agile2404,
+    /// Agile, October 2024
+    #[default]
+    Agile2410,
+}
+
+impl Product {
+    /// Returns the product code used in API URLs.
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::AgileFlex2211 => "AGILE-FLEX-22-11-25",
+            Self::Agile2312 => "AGILE-23-12-06",
+            Self::Agile2404 => "AGILE-24-04-03",
+            Self::Agile2410 => "AGILE-24-10-01",
+        }
+    }
+
+    /// Returns a human-readable name for the product.
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::AgileFlex2211 => "Agile Flex (Nov 2022)",
+            Self::Agile2312 => "Agile (Dec 2023)",
+            Self::Agile2404 => "Agile (Apr 2024)",
+            Self::Agile2410 => "Agile (Oct 2024)",
+        }
+    }
+
+    /// All available products, newest first.
+    pub const fn all() -> &'static [Self] {
+        &[
+            Self::Agile2410,
+            Self::Agile2404,
+            Self::Agile2312,
+            Self::AgileFlex2211,
+        ]
+    }
+}
+
+impl std::fmt::Display for Product {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl std::str::FromStr for Product {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::all()
+            .iter()
+            .find(|p| p.code().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| AppError::ConfigError(format!("Invalid product code: {s}")))
+    }
+}
+
 // API CONFIGURATION
 /// Configuration for the Octopus Energy API client.
 #[derive(Debug, Clone)]
 pub struct ApiConfig {
     base_url: String,
     agile_product: String,
+    /// Agile Outgoing product used for export prices
+    export_product: String,
     tracker_product: String,
     region: Region,
+    /// Explicit request window; when unset each URL uses its default period
+    period_from: Option<DateTime<Utc>>,
+    period_to: Option<DateTime<Utc>>,
 }
 
 impl ApiConfig {
@@ -155,44 +266,75 @@ impl ApiConfig {
     }
 
     /// Constructs the full URL for Agile tariff rates.
+    /// Defaults to London midnight today until midnight in two days.
     pub fn agile_url(&self, now: DateTime<Utc>) -> String {
         let base = self.build_tariff_url(&self.agile_product);
-        let (from, to) = Self::calculate_period(now);
-        format!(
-            "{}?period_from={}&period_to={}",
-            base,
-            from.format("%Y-%m-%dT%H:%M:%SZ"),
-            to.format("%Y-%m-%dT%H:%M:%SZ")
-        )
+        let (from, to) = self.period_or(|| Self::calculate_period(now));
+        format!("{base}?{}", Self::period_query(from, to))
     }
 
     /// Constructs the full URL for historical Agile tariff rates.
+    /// Defaults to the `n_days` before London midnight today.
     pub fn agile_url_historical(&self, now: DateTime<Utc>, n_days: i64) -> String {
         let base = self.build_tariff_url(&self.agile_product);
-        let (from, to) = Self::calculate_historical_period(now, n_days);
-        format!(
-            "{}?period_from={}&period_to={}",
-            base,
-            from.format("%Y-%m-%dT%H:%M:%SZ"),
-            to.format("%Y-%m-%dT%H:%M:%SZ")
-        )
+        let (from, to) = self.period_or(|| Self::calculate_historical_period(now, n_days));
+        format!("{base}?{}", Self::period_query(from, to))
+    }
+
+    /// Constructs the full URL for Agile Outgoing (export) rates.
+    /// Defaults to London midnight today until midnight in two days.
+    pub fn export_url(&self, now: DateTime<Utc>) -> String {
+        let base = self.build_tariff_url(&self.export_product);
+        let (from, to) = self.period_or(|| Self::calculate_period(now));
+        format!("{base}?{}", Self::period_query(from, to))
     }
 
     /// Constructs the full URL for Tracker tariff rates with date period.
     pub fn tracker_url(&self, now: DateTime<Utc>) -> String {
-        let base = self.build_tariff_url(&self.tracker_product);
-        let (from, to) = Self::calculate_period(now);
-        format!(
-            "{}?period_from={}&period_to={}",
-            base,
-            from.format("%Y-%m-%dT%H:%M:%SZ"),
-            to.format("%Y-%m-%dT%H:%M:%SZ")
-        )
+        self.tracker_url_for(Fuel::Electricity, now)
+    }
+
+    /// Constructs the full URL for the given fuel's Tracker rates with date period.
+    pub fn tracker_url_for(&self, fuel: Fuel, now: DateTime<Utc>) -> String {
+        let base = self.build_fuel_resource_url(fuel, &self.tracker_product, "standard-unit-rates");
+        let (from, to) = self.period_or(|| Self::calculate_period(now));
+        format!("{base}?{}", Self::period_query(from, to))
+    }
+
+    /// The configured period, or `default` when none was set.
+    fn period_or(
+        &self,
+        default: impl FnOnce() -> (DateTime<Utc>, DateTime<Utc>),
+    ) -> (DateTime<Utc>, DateTime<Utc>) {
+        self.period_from.zip(self.period_to).unwrap_or_else(default)
+    }
+
+    /// ISO-8601 `period_from`/`period_to` query parameters with colons percent-encoded.
+    fn period_query(from: DateTime<Utc>, to: DateTime<Utc>) -> String {
+        let encode = |dt: DateTime<Utc>| dt.format("%Y-%m-%dT%H%%3A%M%%3A%SZ").to_string();
+        format!("period_from={}&period_to={}", encode(from), encode(to))
+    }
+
+    /// Constructs the URL for the Agile tariff's standing charges.
+    pub fn standing_charges_url(&self) -> String {
+        self.build_tariff_resource_url(&self.agile_product, "standing-charges")
     }
 
     fn build_tariff_url(&self, product: &str) -> String {
+        self.build_tariff_resource_url(product, "standard-unit-rates")
+    }
+
+    fn build_tariff_resource_url(&self, product: &str, resource: &str) -> String {
+        self.build_fuel_resource_url(Fuel::Electricity, product, resource)
+    }
+
+    fn build_fuel_resource_url(&self, fuel: Fuel, product: &str, resource: &str) -> String {
+        let (tariffs, prefix) = match fuel {
+            Fuel::Electricity => ("electricity-tariffs", "E-1R"),
+            Fuel::Gas => ("gas-tariffs", "G-1R"),
+        };
         format!(
-            "{}/{product}/electricity-tariffs/E-1R-{product}-{}/standard-unit-rates/",
+            "{}/{product}/{tariffs}/{prefix}-{product}-{}/{resource}/",
             self.base_url,
             self.region.code()
         )
@@ -236,8 +378,10 @@ impl Default for ApiConfig {
 pub struct ApiConfigBuilder {
     base_url: Option<String>,
     agile_product: Option<String>,
+    export_product: Option<String>,
     tracker_product: Option<String>,
     region: Option<Region>,
+    period: Option<(DateTime<Utc>, DateTime<Utc>)>,
 }
 
 impl ApiConfigBuilder {
@@ -247,17 +391,40 @@ impl ApiConfigBuilder {
         self
     }
 
+    /// Sets the Agile product version.
+    pub fn product(mut self, product: Product) -> Self {
+        self.agile_product = Some(product.code().to_string());
+        self
+    }
+
+    /// Sets the Agile Outgoing product code used for export prices.
+    pub fn export_product(mut self, code: &str) -> Self {
+        self.export_product = Some(code.to_string());
+        self
+    }
+
+    /// Sets an explicit `period_from`/`period_to` request window.
+    pub const fn period(mut self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        self.period = Some((from, to));
+        self
+    }
+
     /// Builds the `ApiConfig`.
     pub fn build(self) -> ApiConfig {
         ApiConfig {
             base_url: self.base_url.unwrap_or_else(|| BASE_URL.to_string()),
             agile_product: self
                 .agile_product
-                .unwrap_or_else(|| DEFAULT_AGILE_PRODUCT.to_string()),
+                .unwrap_or_else(|| Product::default().code().to_string()),
+            export_product: self
+                .export_product
+                .unwrap_or_else(|| DEFAULT_EXPORT_PRODUCT.to_string()),
             tracker_product: self
                 .tracker_product
                 .unwrap_or_else(|| DEFAULT_TRACKER_PRODUCT.to_string()),
             region: self.region.unwrap_or_default(),
+            period_from: self.period.map(|(from, _)| from),
+            period_to: self.period.map(|(_, to)| to),
         }
     }
 }
@@ -273,6 +440,13 @@ struct ApiResponse<T> {
     count: Option<usize>,
 }
 
+impl ApiResponse<ApiRate> {
+    /// Converts the page results into domain rates.
+    fn into_rates(self) -> Vec<Rate> {
+        self.results.into_iter().map(Into::into).collect()
+    }
+}
+
 #[derive(Deserialize, Debug)]
 struct ApiRate {
     value_exc_vat: f64,
@@ -281,6 +455,31 @@ struct ApiRate {
     valid_to: DateTime<Utc>,
 }
 
+#[derive(Deserialize, Debug)]
+struct ApiStandingCharge {
+    value_inc_vat: f64,
+    valid_from: DateTime<Utc>,
+    #[serde(default)]
+    valid_to: Option<DateTime<Utc>>,
+    #[serde(default)]
+    payment_method: Option<String>,
+}
+
+impl From<ApiStandingCharge> for StandingCharge {
+    fn from(c: ApiStandingCharge) -> Self {
+        Self {
+            value_inc_vat: c.value_inc_vat,
+            valid_from: c.valid_from,
+            valid_to: c.valid_to,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct ApiGridSupplyPoint {
+    group_id: String,
+}
+
 impl From<ApiRate> for Rate {
     fn from(r: ApiRate) -> Self {
         Self {
@@ -318,17 +517,58 @@ impl OctopusClient {
     pub async fn fetch_agile_rates(&self) -> Result<Rates, AppError> {
         let url = self.config.agile_url(Utc::now());
 
-        let rates = self.fetch(&url).await?;
-        Ok(Rates::new(rates))
+        let rates: Vec<ApiRate> = self.fetch_paginated(&url).await?;
+        Ok(Rates::new(rates.into_iter().map(Into::into).collect()))
     }
 
-    /// Fetches historical Agile tariff rates (31 days).
+    /// Fetches historical Agile tariff rates (`Config::HISTORY_DAYS` days).
     pub async fn fetch_agile_rates_historical(&self) -> Result<Rates, AppError> {
-        let url = self.config.agile_url_historical(Utc::now(), 31);
+        let url = self
+            .config
+            .agile_url_historical(Utc::now(), crate::config::Config::HISTORY_DAYS);
 
         // Use paginated fetch to get all historical data
-        let rates = self.fetch_paginated(&url).await?;
-        Ok(Rates::new(rates))
+        let rates: Vec<ApiRate> = self.fetch_paginated(&url).await?;
+        Ok(Rates::new(rates.into_iter().map(Into::into).collect()))
+    }
+
+    /// Fetches Agile Outgoing export rates.
+    pub async fn fetch_export_rates(&self) -> Result<Rates, AppError> {
+        let url = self.config.export_url(Utc::now());
+
+        let rates: Vec<ApiRate> = self.fetch_paginated(&url).await?;
+        Ok(Rates::new(rates.into_iter().map(Into::into).collect()))
+    }
+
+    /// Fetches the Agile standing charges for direct-debit customers.
+    pub async fn fetch_standing_charges(&self) -> Result<Vec<StandingCharge>, AppError> {
+        let url = self.config.standing_charges_url();
+
+        let charges: Vec<ApiStandingCharge> = self.fetch_paginated(&url).await?;
+        Ok(charges
+            .into_iter()
+            .filter(|c| {
+                c.payment_method
+                    .as_deref()
+                    .is_none_or(|m| m == "DIRECT_DEBIT")
+            })
+            .map(Into::into)
+            .collect())
+    }
+
+    /// Looks up the region serving a postcode via its grid supply point.
+    pub async fn lookup_region(&self, postcode: &str) -> Result<Region, AppError> {
+        let postcode = normalize_postcode(postcode)
+            .ok_or_else(|| AppError::ConfigError(format!("Invalid postcode: {postcode}")))?;
+        let url = format!("{GRID_SUPPLY_POINTS_URL}?postcode={postcode}");
+
+        let (points, _) = self
+            .fetch_page_with_retry::<ApiGridSupplyPoint>(&url)
+            .await?;
+        let point = points
+            .first()
+            .ok_or_else(|| AppError::NotFound(format!("No region found for {postcode}")))?;
+        Region::from_group_id(&point.group_id)
     }
 
     /// Fetches Tracker tariff rates.
@@ -341,148 +581,118 @@ impl OctopusClient {
         &self,
         now: DateTime<Utc>,
     ) -> Result<TrackerRates, AppError> {
-        let url = self.config.tracker_url(now);
+        self.fetch_fuel_tracker_rates_at(Fuel::Electricity, now)
+            .await
+    }
+
+    /// Fetches gas Tracker tariff rates.
+    pub async fn fetch_tracker_gas_rates(&self) -> Result<TrackerRates, AppError> {
+        self.fetch_fuel_tracker_rates_at(Fuel::Gas, Utc::now())
+            .await
+    }
+
+    async fn fetch_fuel_tracker_rates_at(
+        &self,
+        fuel: Fuel,
+        now: DateTime<Utc>,
+    ) -> Result<TrackerRates, AppError> {
+        let url = self.config.tracker_url_for(fuel, now);
 
-        let rates = self.fetch(&url).await?;
-        Ok(TrackerRates::new(rates))
+        let rates = retry_with_backoff(
+            || self.fetch(&url),
+            crate::config::Config::MAX_RETRY_ATTEMPTS,
+        )
+        .await?;
+        Ok(TrackerRates::for_fuel(fuel, rates))
     }
 
     /// Executes a single fetch attempt.
     async fn fetch(&self, url: &str) -> Result<Vec<Rate>, AppError> {
-        let response = self
-            .http
-            .get(url)
-            .send()
-            .await
-            .map_err(|e| self.classify_error(e))?;
+        let _permit = request_limiter().acquire().await;
+
+        let response = self.http.get(url).send().await.map_err(AppError::from)?;
 
         let status = response.status();
         if !status.is_success() {
+            let retry_after = retry_after_ms(response.headers());
             let body = response.text().await.unwrap_or_default();
-            return Err(self.error_for_status(status, &body));
+            return Err(self.error_for_status(status, retry_after, &body));
         }
 
         let api_response: ApiResponse<ApiRate> = response
             .json()
             .await
-            .map_err(|e| AppError::ApiError(format!("Failed to parse response: {e}")))?;
+            .map_err(|e| AppError::ParseError(format!("Failed to parse response: {e}")))?;
 
-        Ok(api_response.results.into_iter().map(Into::into).collect())
+        Ok(api_response.into_rates())
     }
 
-    /// Fetches a single page with retry logic for 429 rate limit errors.
-    /// Returns the rates and the next page URL if available.
-    async fn fetch_page_with_retry(
+    /// Fetches a single page, retrying rate-limited responses with backoff.
+    /// Returns the results and the next page URL if available.
+    async fn fetch_page_with_retry<T: DeserializeOwned>(
         &self,
         url: &str,
-    ) -> Result<(Vec<Rate>, Option<String>), AppError> {
-        use gloo_timers::future::TimeoutFuture;
-
-        let mut retry_delay_ms = 100u32;
-        let max_retries = crate::config::Config::MAX_RETRY_ATTEMPTS;
-
-        for attempt in 0..max_retries {
-            let response = self
-                .http
-                .get(url)
-                .send()
-                .await
-                .map_err(|e| self.classify_error(e))?;
-
-            let status = response.status();
-
-            // Handle rate limiting with exponential backoff
-            if status.as_u16() == 429 && attempt < max_retries - 1 {
-                gloo::console::warn!(format!(
-                    "Rate limited, retrying in {}ms (attempt {}/{})",
-                    retry_delay_ms,
-                    attempt + 1,
-                    max_retries
-                ));
-                TimeoutFuture::new(retry_delay_ms).await;
-                retry_delay_ms *= 5; // Exponential backoff: 100ms, 500ms, 2500ms
-                continue;
-            }
+    ) -> Result<(Vec<T>, Option<String>), AppError> {
+        retry_with_backoff(
+            || self.fetch_page(url),
+            crate::config::Config::MAX_RETRY_ATTEMPTS,
+        )
+        .await
+    }
 
-            // Handle other error statuses
-            if !status.is_success() {
-                let body = response.text().await.unwrap_or_default();
-                return Err(self.error_for_status(status, &body));
-            }
+    /// Executes a single page fetch attempt. The request slot is released before any
+    /// backoff, since the permit is dropped with this future.
+    async fn fetch_page<T: DeserializeOwned>(
+        &self,
+        url: &str,
+    ) -> Result<(Vec<T>, Option<String>), AppError> {
+        let _permit = request_limiter().acquire().await;
 
-            // Parse successful response
-            let api_response: ApiResponse<ApiRate> = response
-                .json()
-                .await
-                .map_err(|e| AppError::ApiError(format!("Failed to parse response: {e}")))?;
+        let response = self.http.get(url).send().await.map_err(AppError::from)?;
 
-            let rates: Vec<Rate> = api_response.results.into_iter().map(Into::into).collect();
-            return Ok((rates, api_response.next));
+        let status = response.status();
+        if !status.is_success() {
+            let retry_after = retry_after_ms(response.headers());
+            let body = response.text().await.unwrap_or_default();
+            return Err(self.error_for_status(status, retry_after, &body));
         }
 
-        Err(AppError::RateLimited)
+        let api_response: ApiResponse<T> = response
+            .json()
+            .await
+            .map_err(|e| AppError::ParseError(format!("Failed to parse response: {e}")))?;
+
+        Ok((api_response.results, api_response.next))
     }
 
     /// Fetches data across multiple pages, following `next` links.
     /// Returns accumulated data even if later pages fail (partial success).
-    async fn fetch_paginated(&self, initial_url: &str) -> Result<Vec<Rate>, AppError> {
-        use gloo_timers::future::TimeoutFuture;
-
-        let mut all_rates = Vec::new();
-        let mut next_url = Some(initial_url.to_string());
-        let mut page = 1;
-
-        while let Some(url) = next_url {
-            // Fetch current page with retry logic
-            match self.fetch_page_with_retry(&url).await {
-                Ok((rates, next)) => {
-                    all_rates.extend(rates);
-                    next_url = next;
-
-                    // Rate limiting delay between pages (except on last page)
-                    if next_url.is_some() {
-                        TimeoutFuture::new(crate::config::Config::PAGINATION_DELAY_MS).await;
-                    }
-                    page += 1;
-                }
-                Err(e) => {
-                    // Return partial data if we have some, otherwise propagate error
-                    if all_rates.is_empty() {
-                        return Err(e);
-                    }
-                    gloo::console::warn!(format!(
-                        "Pagination stopped at page {} with error: {}. Returning {} records.",
-                        page,
-                        e,
-                        all_rates.len()
-                    ));
-                    break;
-                }
-            }
-        }
-
-        Ok(all_rates)
-    }
-
-    /// Converts a reqwest error into an appropriate `AppError`.
-    fn classify_error(&self, error: reqwest::Error) -> AppError {
-        if error.is_timeout() {
-            AppError::ApiError(format!("Request timeout: {error}"))
-        } else if error.is_request() {
-            AppError::ApiError(format!("Request error: {error}"))
-        } else {
-            AppError::ApiError(format!("Network error: {error}"))
-        }
+    async fn fetch_paginated<T: DeserializeOwned>(
+        &self,
+        initial_url: &str,
+    ) -> Result<Vec<T>, AppError> {
+        collect_pages(
+            initial_url,
+            |url| async move { self.fetch_page_with_retry(&url).await },
+            || gloo_timers::future::TimeoutFuture::new(crate::config::Config::PAGINATION_DELAY_MS),
+        )
+        .await
     }
 
     /// Creates an error based on HTTP status code.
-    fn error_for_status(&self, status: reqwest::StatusCode, body: &str) -> AppError {
+    /// `retry_after` is the server's suggested wait (ms) for rate-limited responses.
+    fn error_for_status(
+        &self,
+        status: reqwest::StatusCode,
+        retry_after: Option<u32>,
+        body: &str,
+    ) -> AppError {
         match status.as_u16() {
-            429 => AppError::RateLimited,
+            429 => AppError::RateLimited(retry_after),
             401 | 403 => AppError::AuthError(format!("Authentication failed: {status}")),
             404 => AppError::NotFound(format!("Resource not found: {body}")),
             400..=499 => AppError::ApiError(format!("Client error {status}: {body}")),
-            500..=599 => AppError::ApiError(format!("Server error {status}: {body}")),
+            500..=599 => AppError::ServerError(status.as_u16(), body.to_string()),
             _ => AppError::ApiError(format!("Unexpected status {status}: {body}")),
         }
     }
@@ -495,19 +705,379 @@ impl Default for OctopusClient {
 }
 
 // CONVENIENCE FUNCTIONS
-/// Fetches historical Agile rates (31 days) using default configuration.
-pub async fn fetch_historical_rates() -> Result<Rates, AppError> {
-    OctopusClient::new()?.fetch_agile_rates_historical().await
+/// Drives pagination: fetches `initial_url`, then each `next` URL in turn, waiting on
+/// `delay` between pages. Stops at `Config::MAX_PAGES` and keeps earlier pages if a
+/// later one fails.
+async fn collect_pages<T, F, Fut, D, DFut>(
+    initial_url: &str,
+    mut fetch_page: F,
+    mut delay: D,
+) -> Result<Vec<T>, AppError>
+where
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, Option<String>), AppError>>,
+    D: FnMut() -> DFut,
+    DFut: Future<Output = ()>,
+{
+    let mut all_results = Vec::new();
+    let mut next_url = Some(initial_url.to_string());
+    let mut page = 1;
+
+    while let Some(url) = next_url {
+        if page > crate::config::Config::MAX_PAGES {
+            gloo::console::warn!(format!(
+                "Pagination stopped after {} pages. Returning {} records.",
+                crate::config::Config::MAX_PAGES,
+                all_results.len()
+            ));
+            break;
+        }
+
+        // Fetch current page with retry logic
+        match fetch_page(url).await {
+            Ok((results, next)) => {
+                all_results.extend(results);
+                next_url = next;
+
+                // Rate limiting delay between pages (except on last page)
+                if next_url.is_some() {
+                    delay().await;
+                }
+                page += 1;
+            }
+            Err(e) => {
+                // Return partial data if we have some, otherwise propagate error
+                if all_results.is_empty() {
+                    return Err(e);
+                }
+                gloo::console::warn!(format!(
+                    "Pagination stopped at page {} with error: {}. Returning {} records.",
+                    page,
+                    e,
+                    all_results.len()
+                ));
+                break;
+            }
+        }
+    }
+
+    Ok(all_results)
 }
 
-/// Fetches Agile rates for a specific region.
-pub async fn fetch_rates_for_region(region: Region) -> Result<Rates, AppError> {
-    let config = ApiConfig::builder().region(region).build();
+/// Fetches the Agile standing charge currently in force for a region and product.
+pub async fn fetch_standing_charge(
+    region: Region,
+    product: Product,
+) -> Result<StandingCharge, AppError> {
+    let config = ApiConfig::builder().region(region).product(product).build();
+    let charges = OctopusClient::with_config(config)?
+        .fetch_standing_charges()
+        .await?;
+    StandingCharge::in_force_at(&charges, Utc::now())
+        .cloned()
+        .ok_or_else(|| AppError::DataError("No standing charge currently in force".to_string()))
+}
+
+/// Fetches historical Agile rates (`Config::HISTORY_DAYS` days) for a region and product.
+pub async fn fetch_historical_rates(region: Region, product: Product) -> Result<Rates, AppError> {
+    let config = ApiConfig::builder().region(region).product(product).build();
+    OctopusClient::with_config(config)?
+        .fetch_agile_rates_historical()
+        .await
+}
+
+/// A fetch in progress, awaitable by any number of callers
+type SharedFetch<T> = Shared<LocalBoxFuture<'static, Result<T, AppError>>>;
+
+/// One key's fetch in flight and how many callers are waiting on it
+struct InFlight<T: Clone> {
+    /// Tells this fetch apart from a later one for the same key
+    id: u64,
+    waiters: usize,
+    fetch: SharedFetch<T>,
+}
+
+/// Coalesces concurrent fetches: while one for a key is in flight, later callers with the
+/// same key await its result instead of starting another.
+///
+/// The entry is cleared once its last waiter finishes or is dropped, so the next call
+/// fetches afresh, and dropping every waiter (e.g. on abort) drops the fetch itself.
+struct SingleFlight<K, T: Clone> {
+    in_flight: Rc<RefCell<HashMap<K, InFlight<T>>>>,
+    next_id: Cell<u64>,
+}
+
+impl<K, T> SingleFlight<K, T>
+where
+    K: Hash + Eq + Clone + 'static,
+    T: Clone + 'static,
+{
+    fn new() -> Self {
+        Self {
+            in_flight: Rc::new(RefCell::new(HashMap::new())),
+            next_id: Cell::new(0),
+        }
+    }
+
+    /// Joins the fetch in flight for `key`, or starts one with `fetch`
+    async fn run<F, Fut>(&self, key: K, fetch: F) -> Result<T, AppError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, AppError>> + 'static,
+    {
+        let joined = self.in_flight.borrow_mut().get_mut(&key).map(|entry| {
+            entry.waiters += 1;
+            (entry.id, entry.fetch.clone())
+        });
+        let (id, shared) = joined.unwrap_or_else(|| {
+            let id = self.next_id.get();
+            self.next_id.set(id + 1);
+            let shared = fetch().boxed_local().shared();
+            self.in_flight.borrow_mut().insert(
+                key.clone(),
+                InFlight {
+                    id,
+                    waiters: 1,
+                    fetch: shared.clone(),
+                },
+            );
+            (id, shared)
+        });
+
+        let _waiter = Waiter {
+            in_flight: Rc::clone(&self.in_flight),
+            key,
+            id,
+        };
+        shared.await
+    }
+}
+
+/// A caller's place in a `SingleFlight` entry, given up when the caller finishes or is
+/// dropped
+struct Waiter<K: Hash + Eq, T: Clone> {
+    in_flight: Rc<RefCell<HashMap<K, InFlight<T>>>>,
+    key: K,
+    id: u64,
+}
+
+impl<K: Hash + Eq, T: Clone> Drop for Waiter<K, T> {
+    fn drop(&mut self) {
+        let mut in_flight = self.in_flight.borrow_mut();
+        if let Some(entry) = in_flight.get_mut(&self.key)
+            && entry.id == self.id
+        {
+            entry.waiters -= 1;
+            if entry.waiters == 0 {
+                in_flight.remove(&self.key);
+            }
+        }
+    }
+}
+
+thread_local! {
+    static RATES_IN_FLIGHT: Rc<SingleFlight<(Region, Product), Rates>> =
+        Rc::new(SingleFlight::new());
+}
+
+/// Fetches Agile rates for a specific region and product, from London midnight today
+/// to midnight in two days.
+///
+/// Concurrent calls for the same region and product share one request.
+pub async fn fetch_rates_for_region(region: Region, product: Product) -> Result<Rates, AppError> {
+    let in_flight = RATES_IN_FLIGHT.with(Rc::clone);
+    in_flight
+        .run((region, product), move || {
+            let (from, to) = ApiConfig::calculate_period(Utc::now());
+            fetch_rates_in_range(region, product, from, to)
+        })
+        .await
+}
+
+/// Like `fetch_rates_for_region`, but cancelled by `signal`: an abort drops the pending
+/// HTTP request (which aborts it in the browser) unless another caller shares it, and
+/// resolves as `AppError::Aborted`.
+pub async fn fetch_rates_for_region_abortable(
+    region: Region,
+    product: Product,
+    signal: &AbortSignal,
+) -> Result<Rates, AppError> {
+    abortable(fetch_rates_for_region(region, product), signal).await
+}
+
+/// Fetches Agile rates for a specific region and product over an arbitrary `[from, to)`
+/// window.
+pub async fn fetch_rates_in_range(
+    region: Region,
+    product: Product,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Rates, AppError> {
+    let config = range_config(region, product, from, to)?;
     OctopusClient::with_config(config)?
         .fetch_agile_rates()
         .await
 }
 
+/// Brings `previous` up to date by fetching only the slots after it.
+///
+/// Fetches the full window instead when there is nothing to build on or its last full
+/// fetch is older than `Config::RATES_CACHE_MAX_AGE_HOURS`.
+pub async fn fetch_rates_update(
+    region: Region,
+    product: Product,
+    previous: Option<CachedRates>,
+) -> Result<CachedRates, AppError> {
+    let now = Utc::now();
+    let (from, to) = ApiConfig::calculate_period(now);
+
+    let Some((mut previous, since)) =
+        previous.and_then(|p| incremental_since(&p, now).map(|since| (p, since)))
+    else {
+        let rates = fetch_rates_for_region(region, product).await?;
+        return Ok(CachedRates::new(rates, now));
+    };
+
+    if since < to {
+        let newer = fetch_rates_in_range(region, product, since, to).await?;
+        previous.rates.merge(newer.into_vec());
+    }
+    // After midnight yesterday's slots have left the window
+    previous.rates.drop_before(from);
+    Ok(previous)
+}
+
+/// Where an incremental fetch on top of `previous` would start, or `None` if a full
+/// fetch is due
+fn incremental_since(previous: &CachedRates, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let age = now - previous.fetched_at;
+    let max_age = chrono::Duration::hours(crate::config::Config::RATES_CACHE_MAX_AGE_HOURS);
+    if age < chrono::Duration::zero() || age >= max_age {
+        return None;
+    }
+    previous.rates.latest_available()
+}
+
+/// Fetches the price of `product` in force now for every region, concurrently.
+/// Regions that fail are skipped; an error is returned only if every region fails.
+pub async fn fetch_current_prices_all_regions(
+    product: Product,
+) -> Result<Vec<(Region, f64)>, AppError> {
+    let now = Utc::now();
+    collect_region_prices(Region::all(), |region| {
+        fetch_current_price(region, product, now)
+    })
+    .await
+}
+
+/// Price of `product` for `region` in the slot covering `now`
+async fn fetch_current_price(
+    region: Region,
+    product: Product,
+    now: DateTime<Utc>,
+) -> Result<f64, AppError> {
+    let half_hour = chrono::Duration::minutes(30);
+    let rates = fetch_rates_in_range(region, product, now - half_hour, now + half_hour).await?;
+    rates
+        .rate_at(now)
+        .map(|rate| rate.value_inc_vat)
+        .ok_or_else(|| AppError::DataError(format!("No current rate for region {region}")))
+}
+
+/// Runs `fetch` for every region at once, keeping the successes in region order.
+/// Fails with the first error only when no region succeeded.
+async fn collect_region_prices<F, Fut>(
+    regions: &[Region],
+    fetch: F,
+) -> Result<Vec<(Region, f64)>, AppError>
+where
+    F: Fn(Region) -> Fut,
+    Fut: Future<Output = Result<f64, AppError>>,
+{
+    let results = futures::future::join_all(regions.iter().map(|&region| {
+        let price = fetch(region);
+        async move { (region, price.await) }
+    }))
+    .await;
+
+    let mut first_error = None;
+    let prices: Vec<(Region, f64)> = results
+        .into_iter()
+        .filter_map(|(region, result)| match result {
+            Ok(price) => Some((region, price)),
+            Err(e) => {
+                first_error.get_or_insert(e);
+                None
+            }
+        })
+        .collect();
+
+    match first_error {
+        Some(e) if prices.is_empty() => Err(e),
+        _ => Ok(prices),
+    }
+}
+
+/// Builds a config for a requested window, rejecting empty or inverted ranges.
+fn range_config(
+    region: Region,
+    product: Product,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<ApiConfig, AppError> {
+    if from >= to {
+        return Err(AppError::DataError(format!(
+            "Invalid period: {from} is not before {to}"
+        )));
+    }
+    Ok(ApiConfig::builder()
+        .region(region)
+        .product(product)
+        .period(from, to)
+        .build())
+}
+
+/// Fetches Agile Outgoing export rates for a specific region, configured alongside the
+/// Agile import `product` they are shown against.
+pub async fn fetch_export_rates_for_region(
+    region: Region,
+    product: Product,
+) -> Result<Rates, AppError> {
+    let config = ApiConfig::builder().region(region).product(product).build();
+    OctopusClient::with_config(config)?
+        .fetch_export_rates()
+        .await
+}
+
+/// Upper-cases a UK postcode and strips its spaces, or `None` if it is not shaped like one:
+/// a letter-led outward code of two to four characters, then a digit and two letters.
+pub fn normalize_postcode(input: &str) -> Option<String> {
+    let compact: String = input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase();
+    if !(5..=7).contains(&compact.len()) || !compact.is_ascii() {
+        return None;
+    }
+
+    let (outward, inward) = compact.split_at(compact.len() - 3);
+    let inward = inward.as_bytes();
+    let outward_ok = outward.starts_with(|c: char| c.is_ascii_alphabetic())
+        && outward.chars().all(|c| c.is_ascii_alphanumeric())
+        && outward.chars().any(|c| c.is_ascii_digit());
+    let inward_ok = inward[0].is_ascii_digit()
+        && inward[1].is_ascii_alphabetic()
+        && inward[2].is_ascii_alphabetic();
+
+    (outward_ok && inward_ok).then_some(compact)
+}
+
+/// Looks up the region serving a UK postcode.
+pub async fn lookup_region_for_postcode(postcode: &str) -> Result<Region, AppError> {
+    OctopusClient::new()?.lookup_region(postcode).await
+}
+
 /// Fetches Tracker rates for a specific region.
 pub async fn fetch_tracker_rates_for_region(region: Region) -> Result<TrackerRates, AppError> {
     let config = ApiConfig::builder().region(region).build();
@@ -516,6 +1086,14 @@ pub async fn fetch_tracker_rates_for_region(region: Region) -> Result<TrackerRat
         .await
 }
 
+/// Fetches gas Tracker rates for a specific region.
+pub async fn fetch_tracker_gas_rates_for_region(region: Region) -> Result<TrackerRates, AppError> {
+    let config = ApiConfig::builder().region(region).build();
+    OctopusClient::with_config(config)?
+        .fetch_tracker_gas_rates()
+        .await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -528,6 +1106,50 @@ mod tests {
         assert!("X".parse::<Region>().is_err());
     }
 
+    #[test]
+    fn test_region_from_group_id() {
+        assert_eq!(Region::from_group_id("_C").unwrap(), Region::C);
+        assert_eq!(Region::from_group_id("_P").unwrap(), Region::P);
+        assert!(matches!(
+            Region::from_group_id("_X"),
+            Err(AppError::DataError(_))
+        ));
+        assert!(Region::from_group_id("C").is_err());
+        assert!(Region::from_group_id("_CC").is_err());
+    }
+
+    #[test]
+    fn test_grid_supply_point_response_parses() {
+        let json =
+            r#"{"count": 1, "next": null, "previous": null, "results": [{"group_id": "_C"}]}"#;
+
+        let response: ApiResponse<ApiGridSupplyPoint> = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            Region::from_group_id(&response.results[0].group_id).unwrap(),
+            Region::C
+        );
+    }
+
+    #[test]
+    fn test_normalize_postcode() {
+        assert_eq!(normalize_postcode("sw1a 1aa").as_deref(), Some("SW1A1AA"));
+        assert_eq!(normalize_postcode(" M1 1AE ").as_deref(), Some("M11AE"));
+        assert_eq!(normalize_postcode("EC1A1BB").as_deref(), Some("EC1A1BB"));
+        assert_eq!(normalize_postcode("SW1A"), None);
+        assert_eq!(normalize_postcode("12345"), None);
+        assert_eq!(normalize_postcode("SW1A 1A1"), None);
+        assert_eq!(normalize_postcode("ABCDEFG"), None);
+    }
+
+    #[test]
+    fn test_carbon_region_ids_are_unique() {
+        let mut ids: Vec<u8> = Region::all().iter().map(Region::carbon_region_id).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, (1..=14).collect::<Vec<_>>());
+        assert_eq!(Region::P.carbon_region_id(), 1);
+        assert_eq!(Region::C.carbon_region_id(), 13);
+    }
+
     #[test]
     fn test_region_code() {
         assert_eq!(Region::C.code(), "C");
@@ -556,6 +1178,246 @@ mod tests {
         assert!(url.contains("-M/"));
     }
 
+    #[test]
+    fn test_agile_url_substitutes_product_and_region() {
+        let config = ApiConfig::builder()
+            .region(Region::K)
+            .product(Product::Agile2312)
+            .build();
+
+        let url = config.agile_url(Utc::now());
+        assert!(url.starts_with(
+            "https://api.octopus.energy/v1/products/AGILE-23-12-06/electricity-tariffs/E-1R-AGILE-23-12-06-K/standard-unit-rates/?"
+        ));
+        assert!(!url.contains("AGILE-24-10-01"));
+    }
+
+    #[test]
+    fn test_export_url_differs_from_import_url() {
+        let config = ApiConfig::builder().region(Region::C).build();
+        let now = Utc.with_ymd_and_hms(2026, 3, 2, 12, 0, 0).unwrap();
+
+        let export = config.export_url(now);
+        assert_ne!(export, config.agile_url(now));
+        assert!(export.contains(
+            "/AGILE-OUTGOING-19-05-13/electricity-tariffs/E-1R-AGILE-OUTGOING-19-05-13-C/"
+        ));
+    }
+
+    #[test]
+    fn test_export_url_uses_configured_export_product() {
+        let config = ApiConfig::builder()
+            .region(Region::N)
+            .export_product("AGILE-OUTGOING-BB-23-02-28")
+            .build();
+        let now = Utc.with_ymd_and_hms(2026, 3, 2, 12, 0, 0).unwrap();
+
+        let export = config.export_url(now);
+        assert!(export.contains(
+            "/AGILE-OUTGOING-BB-23-02-28/electricity-tariffs/E-1R-AGILE-OUTGOING-BB-23-02-28-N/"
+        ));
+        // The import product is unaffected
+        assert!(config.agile_url(now).contains("/AGILE-24-10-01/"));
+    }
+
+    #[test]
+    fn test_collect_region_prices_skips_failures() {
+        let regions = [Region::A, Region::B, Region::C];
+        let prices = futures::executor::block_on(collect_region_prices(&regions, |region| {
+            std::future::ready(match region {
+                Region::B => Err(AppError::NetworkError("timeout".to_string())),
+                _ => Ok(f64::from(region.carbon_region_id())),
+            })
+        }))
+        .unwrap();
+
+        assert_eq!(prices, vec![(Region::A, 10.0), (Region::C, 13.0)]);
+    }
+
+    #[test]
+    fn test_collect_region_prices_errors_when_all_fail() {
+        let result =
+            futures::executor::block_on(collect_region_prices(&[Region::A, Region::B], |region| {
+                std::future::ready(Err(AppError::NotFound(format!("region {}", region.code()))))
+            }));
+
+        assert_eq!(result, Err(AppError::NotFound("region A".to_string())));
+        assert_eq!(
+            futures::executor::block_on(collect_region_prices(&[], |_| std::future::ready(Ok(
+                1.0
+            )))),
+            Ok(vec![])
+        );
+    }
+
+    /// Resolves on its second poll, so concurrent callers overlap
+    async fn yield_once() {
+        let mut yielded = false;
+        std::future::poll_fn(|cx| {
+            if yielded {
+                std::task::Poll::Ready(())
+            } else {
+                yielded = true;
+                cx.waker().wake_by_ref();
+                std::task::Poll::Pending
+            }
+        })
+        .await;
+    }
+
+    #[test]
+    fn test_single_flight_shares_concurrent_fetches() {
+        let flight = SingleFlight::new();
+        let calls = Rc::new(std::cell::Cell::new(0));
+        let fetch = || {
+            let calls = Rc::clone(&calls);
+            move || async move {
+                calls.set(calls.get() + 1);
+                yield_once().await;
+                Ok(calls.get())
+            }
+        };
+
+        let (first, second) = futures::executor::block_on(futures::future::join(
+            flight.run((Region::C, Product::default()), fetch()),
+            flight.run((Region::C, Product::default()), fetch()),
+        ));
+
+        assert_eq!(calls.get(), 1);
+        assert_eq!((first, second), (Ok(1), Ok(1)));
+        assert!(flight.in_flight.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_single_flight_drops_fetch_with_its_last_caller() {
+        /// Flags when the fetch future holding it is dropped
+        struct DropFlag(Rc<Cell<bool>>);
+        impl Drop for DropFlag {
+            fn drop(&mut self) {
+                self.0.set(true);
+            }
+        }
+
+        let flight = SingleFlight::new();
+        let dropped = Rc::new(Cell::new(false));
+        let flag = DropFlag(Rc::clone(&dropped));
+
+        // Polled once, then dropped like an aborted caller
+        let polled = flight
+            .run(Region::C, move || async move {
+                let _flag = flag;
+                std::future::pending::<Result<u32, AppError>>().await
+            })
+            .now_or_never();
+
+        assert!(polled.is_none());
+        assert!(flight.in_flight.borrow().is_empty());
+        assert!(dropped.get());
+    }
+
+    #[test]
+    fn test_single_flight_refetches_after_settling_and_per_key() {
+        let flight = SingleFlight::new();
+        let calls = Rc::new(std::cell::Cell::new(0));
+        let fetch = || {
+            let calls = Rc::clone(&calls);
+            move || async move {
+                calls.set(calls.get() + 1);
+                yield_once().await;
+                Err::<u32, _>(AppError::NetworkError("timeout".to_string()))
+            }
+        };
+
+        let (a, c) = futures::executor::block_on(futures::future::join(
+            flight.run(Region::A, fetch()),
+            flight.run(Region::C, fetch()),
+        ));
+        assert_eq!(calls.get(), 2);
+        assert!(a.is_err() && c.is_err());
+
+        // A settled failure is not cached
+        let _ = futures::executor::block_on(flight.run(Region::A, fetch()));
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn test_product_parsing() {
+        assert_eq!(
+            "agile-flex-22-11-25".parse::<Product>().unwrap(),
+            Product::AgileFlex2211
+        );
+        assert_eq!(Product::default().code(), "AGILE-24-10-01");
+        assert!("AGILE-99-01-01".parse::<Product>().is_err());
+    }
+
+    #[test]
+    fn test_abortable_fetch_honours_the_signal() {
+        use crate::utils::abort::AbortController;
+
+        let controller = AbortController::new();
+        let signal = controller.signal();
+        controller.abort();
+
+        // An aborted signal resolves before any request is built
+        assert_eq!(
+            futures::executor::block_on(fetch_rates_for_region_abortable(
+                Region::C,
+                Product::default(),
+                &signal
+            )),
+            Err(AppError::Aborted)
+        );
+    }
+
+    #[test]
+    fn test_incremental_since_until_full_refetch_due() {
+        let valid_from = Utc.with_ymd_and_hms(2026, 1, 12, 22, 30, 0).unwrap();
+        let valid_to = valid_from + chrono::Duration::minutes(30);
+        let rates = Rates::new(vec![Rate {
+            value_inc_vat: 20.0,
+            value_exc_vat: 20.0 / 1.2,
+            valid_from,
+            valid_to,
+        }]);
+        let fetched_at = Utc.with_ymd_and_hms(2026, 1, 12, 9, 0, 0).unwrap();
+        let previous = CachedRates::new(rates, fetched_at);
+
+        let soon = fetched_at + chrono::Duration::hours(3);
+        assert_eq!(incremental_since(&previous, soon), Some(valid_to));
+        let next_day = fetched_at + chrono::Duration::hours(24);
+        assert_eq!(incremental_since(&previous, next_day), None);
+
+        let empty = CachedRates::new(Rates::new(vec![]), fetched_at);
+        assert_eq!(incremental_since(&empty, soon), None);
+    }
+
+    #[test]
+    fn test_update_after_midnight_drops_yesterdays_slots() {
+        let slot = |h: u32, m: u32, value: f64| {
+            let valid_from = Utc.with_ymd_and_hms(2026, 1, 12, h, m, 0).unwrap();
+            Rate {
+                value_inc_vat: value,
+                value_exc_vat: value / 1.2,
+                valid_from,
+                valid_to: valid_from + chrono::Duration::minutes(30),
+            }
+        };
+        let mut cached = CachedRates::new(
+            Rates::new(vec![slot(23, 0, 10.0), slot(23, 30, 12.0)]),
+            Utc.with_ymd_and_hms(2026, 1, 12, 22, 0, 0).unwrap(),
+        );
+        let after_midnight = Utc.with_ymd_and_hms(2026, 1, 13, 0, 10, 0).unwrap();
+        let (from, _) = ApiConfig::calculate_period(after_midnight);
+
+        let mut today = slot(23, 30, 15.0);
+        today.valid_from += chrono::Duration::minutes(30);
+        today.valid_to += chrono::Duration::minutes(30);
+        cached.rates.merge(vec![today]);
+        cached.rates.drop_before(from);
+
+        assert_eq!(cached.rates.all_values(), vec![15.0]);
+    }
+
     #[test]
     fn test_tracker_url_construction() {
         let config = ApiConfig::builder().region(Region::A).build();
@@ -568,6 +1430,76 @@ mod tests {
         assert!(url.contains("period_to="));
     }
 
+    #[test]
+    fn test_tracker_gas_url_uses_gas_tariff() {
+        let config = ApiConfig::builder().region(Region::M).build();
+
+        let url = config.tracker_url_for(Fuel::Gas, Utc::now());
+        assert!(url.contains("/SILVER-24-10-01/gas-tariffs/G-1R-SILVER-24-10-01-M/"));
+        assert!(!url.contains("electricity-tariffs"));
+    }
+
+    #[test]
+    fn test_agile_url_uses_configured_period() {
+        // London midnight in BST is 23:00 UTC the previous day
+        let from = Utc.with_ymd_and_hms(2026, 6, 30, 23, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2026, 7, 2, 23, 0, 0).unwrap();
+        let config = ApiConfig::builder()
+            .region(Region::C)
+            .period(from, to)
+            .build();
+
+        let url = config.agile_url(Utc::now());
+        assert!(url.ends_with(
+            "/E-1R-AGILE-24-10-01-C/standard-unit-rates/\
+             ?period_from=2026-06-30T23%3A00%3A00Z&period_to=2026-07-02T23%3A00%3A00Z"
+        ));
+        assert!(!url.contains("T23:00"));
+    }
+
+    #[test]
+    fn test_range_config_formats_iso_timestamps() {
+        let from = Utc.with_ymd_and_hms(2025, 11, 1, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2025, 11, 8, 12, 30, 0).unwrap();
+
+        let url = range_config(Region::N, Product::default(), from, to)
+            .unwrap()
+            .agile_url(Utc::now());
+        assert!(url.contains("E-1R-AGILE-24-10-01-N"));
+        assert!(
+            url.ends_with(
+                "?period_from=2025-11-01T00%3A00%3A00Z&period_to=2025-11-08T12%3A30%3A00Z"
+            )
+        );
+    }
+
+    #[test]
+    fn test_range_config_rejects_inverted_range() {
+        let from = Utc.with_ymd_and_hms(2025, 11, 8, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2025, 11, 1, 0, 0, 0).unwrap();
+
+        assert!(matches!(
+            range_config(Region::N, Product::default(), from, to),
+            Err(AppError::DataError(_))
+        ));
+        assert!(matches!(
+            range_config(Region::N, Product::default(), from, from),
+            Err(AppError::DataError(_))
+        ));
+    }
+
+    #[test]
+    fn test_default_period_query_is_encoded() {
+        let config = ApiConfig::builder().region(Region::A).build();
+        let now = Utc.with_ymd_and_hms(2026, 1, 12, 9, 30, 0).unwrap();
+
+        assert!(
+            config.tracker_url(now).ends_with(
+                "?period_from=2026-01-12T00%3A00%3A00Z&period_to=2026-01-14T00%3A00%3A00Z"
+            )
+        );
+    }
+
     #[test]
     fn test_calculate_period_uses_london_midnight_in_bst() {
         let now = Utc.with_ymd_and_hms(2026, 3, 29, 12, 0, 0).unwrap();
@@ -647,4 +1579,199 @@ mod tests {
         assert_eq!(response.results[0].value_exc_vat, 10.5);
         assert_eq!(response.results[1].value_inc_vat, 12.6);
     }
+
+    #[test]
+    fn test_tracker_response_parses_into_tracker_rates() {
+        // Tracker publishes one rate per day rather than half-hourly slots
+        let today = london_date(Utc::now());
+        let tomorrow = today.checked_add_days(Days::new(1)).unwrap();
+        let day_after = tomorrow.checked_add_days(Days::new(1)).unwrap();
+        let fmt = |date| london_midnight_utc(date).format("%Y-%m-%dT%H:%M:%SZ");
+
+        let json = format!(
+            r#"{{
+                "count": 2,
+                "next": null,
+                "results": [
+                    {{
+                        "value_exc_vat": 21.29,
+                        "value_inc_vat": 22.3545,
+                        "valid_from": "{}",
+                        "valid_to": "{}"
+                    }},
+                    {{
+                        "value_exc_vat": 19.69,
+                        "value_inc_vat": 20.6745,
+                        "valid_from": "{}",
+                        "valid_to": "{}"
+                    }}
+                ]
+            }}"#,
+            fmt(tomorrow),
+            fmt(day_after),
+            fmt(today),
+            fmt(tomorrow)
+        );
+
+        let response: ApiResponse<ApiRate> = serde_json::from_str(&json).unwrap();
+        let tracker = TrackerRates::new(response.into_rates());
+
+        assert_eq!(tracker.current_price(), Some(20.6745));
+        assert_eq!(tracker.next_day_price(), Some(22.3545));
+        assert!((tracker.price_difference().unwrap() - 1.68).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_paginated_responses_merge_in_time_order() {
+        // Octopus returns newest first, so page 2 holds the earlier slots
+        let page_one = r#"{
+            "count": 4,
+            "next": "https://api.octopus.energy/v1/products/X/electricity-tariffs/Y/standard-unit-rates/?page=2",
+            "results": [
+                {"value_exc_vat": 20.0, "value_inc_vat": 21.0, "valid_from": "2024-01-15T01:30:00Z", "valid_to": "2024-01-15T02:00:00Z"},
+                {"value_exc_vat": 19.0, "value_inc_vat": 20.0, "valid_from": "2024-01-15T01:00:00Z", "valid_to": "2024-01-15T01:30:00Z"}
+            ]
+        }"#;
+        let page_two = r#"{
+            "count": 4,
+            "next": null,
+            "results": [
+                {"value_exc_vat": 18.0, "value_inc_vat": 19.0, "valid_from": "2024-01-15T00:30:00Z", "valid_to": "2024-01-15T01:00:00Z"},
+                {"value_exc_vat": 17.0, "value_inc_vat": 18.0, "valid_from": "2024-01-15T00:00:00Z", "valid_to": "2024-01-15T00:30:00Z"}
+            ]
+        }"#;
+
+        let first: ApiResponse<ApiRate> = serde_json::from_str(page_one).unwrap();
+        let second: ApiResponse<ApiRate> = serde_json::from_str(page_two).unwrap();
+        assert!(first.next.as_deref().unwrap().ends_with("?page=2"));
+        assert!(second.next.is_none());
+
+        let mut merged = first.into_rates();
+        merged.extend(second.into_rates());
+        let rates = Rates::new(merged);
+
+        assert_eq!(rates.all_values(), vec![18.0, 19.0, 20.0, 21.0]);
+    }
+
+    #[test]
+    fn test_error_for_status_classification() {
+        use reqwest::StatusCode;
+
+        let client = OctopusClient::new().unwrap();
+        let classify =
+            |code| client.error_for_status(StatusCode::from_u16(code).unwrap(), None, "body");
+
+        assert_eq!(classify(429), AppError::RateLimited(None));
+        assert_eq!(
+            client.error_for_status(StatusCode::TOO_MANY_REQUESTS, Some(2_000), ""),
+            AppError::RateLimited(Some(2_000))
+        );
+        assert!(matches!(classify(401), AppError::AuthError(_)));
+        assert!(matches!(classify(403), AppError::AuthError(_)));
+        assert!(matches!(classify(404), AppError::NotFound(_)));
+        assert!(
+            matches!(classify(400), AppError::ApiError(msg) if msg.starts_with("Client error"))
+        );
+        assert!(matches!(classify(503), AppError::ServerError(503, _)));
+        assert!(
+            matches!(classify(304), AppError::ApiError(msg) if msg.starts_with("Unexpected status"))
+        );
+    }
+
+    #[test]
+    fn test_standing_charges_url() {
+        let config = ApiConfig::builder().region(Region::P).build();
+
+        assert!(config.standing_charges_url().ends_with(
+            "/AGILE-24-10-01/electricity-tariffs/E-1R-AGILE-24-10-01-P/standing-charges/"
+        ));
+    }
+
+    #[test]
+    fn test_parse_standing_charges_response() {
+        let json = r#"{
+            "count": 2,
+            "next": null,
+            "previous": null,
+            "results": [
+                {
+                    "value_exc_vat": 45.57,
+                    "value_inc_vat": 47.85,
+                    "valid_from": "2025-04-01T00:00:00Z",
+                    "valid_to": null,
+                    "payment_method": "DIRECT_DEBIT"
+                },
+                {
+                    "value_exc_vat": 50.0,
+                    "value_inc_vat": 52.5,
+                    "valid_from": "2025-04-01T00:00:00Z",
+                    "valid_to": null,
+                    "payment_method": "NON_DIRECT_DEBIT"
+                }
+            ]
+        }"#;
+
+        let response: ApiResponse<ApiStandingCharge> = serde_json::from_str(json).unwrap();
+        assert_eq!(response.results.len(), 2);
+        assert_eq!(response.results[0].valid_to, None);
+        assert_eq!(
+            response.results[1].payment_method.as_deref(),
+            Some("NON_DIRECT_DEBIT")
+        );
+
+        let charge: StandingCharge = response.results.into_iter().next().unwrap().into();
+        assert!((charge.value_inc_vat - 47.85).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_collect_pages_follows_next_links() {
+        use std::cell::RefCell;
+
+        let slot = |hour, value| Rate {
+            value_inc_vat: value,
+            value_exc_vat: value / 1.2,
+            valid_from: Utc.with_ymd_and_hms(2024, 1, 15, hour, 0, 0).unwrap(),
+            valid_to: Utc.with_ymd_and_hms(2024, 1, 15, hour, 30, 0).unwrap(),
+        };
+        // Newest first, as the API serves them
+        let pages = RefCell::new(vec![
+            (
+                vec![slot(3, 30.0), slot(2, 20.0)],
+                Some("page-2".to_string()),
+            ),
+            (vec![slot(1, 10.0)], Some("page-3".to_string())),
+            (vec![slot(0, 5.0)], None),
+        ]);
+        let requested = RefCell::new(Vec::new());
+        let delays = RefCell::new(0);
+
+        let merged = futures::executor::block_on(collect_pages(
+            "page-1",
+            |url| {
+                requested.borrow_mut().push(url);
+                let page = pages.borrow_mut().remove(0);
+                std::future::ready(Ok(page))
+            },
+            || {
+                *delays.borrow_mut() += 1;
+                std::future::ready(())
+            },
+        ))
+        .unwrap();
+
+        assert_eq!(*requested.borrow(), vec!["page-1", "page-2", "page-3"]);
+        assert_eq!(*delays.borrow(), 2);
+        assert_eq!(Rates::new(merged).all_values(), vec![5.0, 10.0, 20.0, 30.0]);
+    }
+
+    #[test]
+    fn test_collect_pages_propagates_first_page_error() {
+        let result = futures::executor::block_on(collect_pages::<Rate, _, _, _, _>(
+            "page-1",
+            |_| std::future::ready(Err(AppError::RateLimited(None))),
+            || std::future::ready(()),
+        ));
+
+        assert!(matches!(result, Err(AppError::RateLimited(None))));
+    }
 }
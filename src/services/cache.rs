@@ -0,0 +1,100 @@
+use chrono::{DateTime, Utc};
+use gloo_storage::{LocalStorage, Storage};
+
+use crate::config::Config;
+use crate::models::rates::{CachedRates, Rates};
+use crate::services::api::{Product, Region};
+
+fn rates_key(region: Region, product: Product) -> String {
+    format!("rates_cache_{}_{}", product.code(), region.code())
+}
+
+/// Loads the last good rates for `region` and `product`, with when they were fetched, if
+/// they are recent enough to show.
+///
+/// Missing, corrupt or expired entries all yield `None`, so callers fall back
+/// to a normal load.
+pub fn load_rates(region: Region, product: Product) -> Option<(Rates, DateTime<Utc>)> {
+    let raw = LocalStorage::raw()
+        .get_item(&rates_key(region, product))
+        .ok()??;
+    decode_rates(&raw, Utc::now())
+}
+
+/// Stores `rates` for `region` and `product`, stamped with the current time.
+pub fn save_rates(region: Region, product: Product, rates: &Rates) {
+    let cached = CachedRates::new(rates.clone(), Utc::now());
+    if let Err(e) = LocalStorage::set(rates_key(region, product), cached) {
+        web_sys::console::warn_1(&format!("Failed to cache rates: {e:?}").into());
+    }
+}
+
+fn decode_rates(raw: &str, now: DateTime<Utc>) -> Option<(Rates, DateTime<Utc>)> {
+    let cached: CachedRates = serde_json::from_str(raw).ok()?;
+    let fetched_at = cached.fetched_at;
+    let rates = cached.into_fresh(
+        now,
+        chrono::Duration::hours(Config::RATES_CACHE_MAX_AGE_HOURS),
+    )?;
+    Some((rates, fetched_at))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::rates::Rate;
+    use chrono::TimeZone;
+
+    fn make_rate() -> Rate {
+        let valid_from = Utc.with_ymd_and_hms(2026, 1, 12, 16, 0, 0).unwrap();
+        Rate {
+            value_inc_vat: 24.5,
+            value_exc_vat: 23.33,
+            valid_from,
+            valid_to: valid_from + chrono::Duration::minutes(30),
+        }
+    }
+
+    fn make_rates() -> Rates {
+        Rates::new(vec![make_rate()])
+    }
+
+    #[test]
+    fn test_rates_key_per_product_and_region() {
+        assert_eq!(
+            rates_key(Region::C, Product::Agile2410),
+            "rates_cache_AGILE-24-10-01_C"
+        );
+        assert_ne!(
+            rates_key(Region::C, Product::Agile2404),
+            rates_key(Region::C, Product::Agile2410)
+        );
+    }
+
+    #[test]
+    fn test_rate_serde_round_trip() {
+        let rate = make_rate();
+
+        let json = serde_json::to_string(&rate).unwrap();
+        assert_eq!(serde_json::from_str::<Rate>(&json).unwrap(), rate);
+    }
+
+    #[test]
+    fn test_decode_rates_within_max_age() {
+        let fetched_at = Utc.with_ymd_and_hms(2026, 1, 12, 16, 5, 0).unwrap();
+        let raw = serde_json::to_string(&CachedRates::new(make_rates(), fetched_at)).unwrap();
+
+        let now = fetched_at + chrono::Duration::hours(23);
+        assert_eq!(decode_rates(&raw, now), Some((make_rates(), fetched_at)));
+        let expired = fetched_at + chrono::Duration::hours(24);
+        assert_eq!(decode_rates(&raw, expired), None);
+    }
+
+    #[test]
+    fn test_decode_rates_ignores_corrupt_json() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 12, 16, 5, 0).unwrap();
+
+        assert_eq!(decode_rates("{\"fetched_at\": 3", now), None);
+        assert_eq!(decode_rates("[]", now), None);
+    }
+}
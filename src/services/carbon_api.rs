@@ -1,8 +1,14 @@
 use crate::models::{
-    carbon::{CarbonIntensity, CarbonIntensityData},
+    carbon::{CarbonForecast, CarbonIntensity, CarbonIntensityData},
     error::AppError,
 };
+use crate::services::api::Region;
+use crate::services::fallback::first_ok;
+use crate::services::retry::retry_after_ms;
+use chrono::{DateTime, Utc};
+use futures::FutureExt;
 use serde::Deserialize;
+use serde::de::DeserializeOwned;
 
 const CARBON_API_BASE: &str = "https://api.carbonintensity.org.uk";
 
@@ -12,6 +18,37 @@ struct CarbonApiResponse {
     data: Vec<CarbonIntensityData>,
 }
 
+/// Response from `/regional/intensity/{from}/fw24h/regionid/{id}`, which nests periods
+/// one level deeper
+#[derive(Deserialize, Debug)]
+struct RegionalApiResponse {
+    data: RegionalPayload,
+}
+
+/// The forward-looking endpoint returns a single region object, the current-period ones
+/// a list of them
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum RegionalPayload {
+    One(RegionalData),
+    Many(Vec<RegionalData>),
+}
+
+impl RegionalPayload {
+    fn into_regions(self) -> Vec<RegionalData> {
+        match self {
+            Self::One(region) => vec![region],
+            Self::Many(regions) => regions,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct RegionalData {
+    shortname: String,
+    data: Vec<CarbonIntensityData>,
+}
+
 /// Client for the UK Carbon Intensity API
 pub struct CarbonIntensityClient {
     http: reqwest::Client,
@@ -33,91 +70,146 @@ impl CarbonIntensityClient {
 
     /// Fetches current and next period carbon intensity for the UK
     pub async fn fetch_current_and_next_intensity(&self) -> Result<CarbonIntensity, AppError> {
-        use chrono::Utc;
+        let api_response: CarbonApiResponse = self.get_json("/intensity/date").await?;
+        let now = Utc::now();
+
+        // Find most recent period with actual data
+        let latest_intensity = api_response
+            .data
+            .iter()
+            .filter(|period| period.to <= now) // Only periods that have ended
+            .filter(|period| period.intensity.actual.is_some()) // Must have actual data
+            .max_by_key(|period| period.to) // Get the most recent one
+            .ok_or_else(|| {
+                AppError::DataError("No period with actual data found in response".to_string())
+            })?
+            .clone();
+
+        // Find the current time
+        let next = api_response
+            .data
+            .iter()
+            .find(|period| {
+                // Period that follows now, or period containing now
+                period.from > now || now < period.to
+            })
+            .ok_or_else(|| AppError::DataError("No next period found in response".to_string()))?
+            .clone();
 
-        crate::services::retry::retry_with_backoff(
+        Ok(CarbonIntensity::new(
+            latest_intensity,
+            next,
+            api_response.data,
+        ))
+    }
+
+    /// Fetches the 24-hour forecast for the grid region covering `region`
+    pub async fn fetch_regional(&self, region: Region) -> Result<CarbonIntensity, AppError> {
+        let now = Utc::now();
+        let path = regional_path(now, region.carbon_region_id());
+        let api_response: RegionalApiResponse = self.get_json(&path).await?;
+        regional_intensity(api_response, now)
+    }
+
+    /// Fetches the national forecast for the 48 hours from the current half-hour
+    pub async fn fetch_forecast_48h(&self) -> Result<CarbonForecast, AppError> {
+        let api_response: CarbonApiResponse = self.get_json(&forecast_path(Utc::now())).await?;
+        Ok(CarbonForecast::new(api_response.data))
+    }
+
+    /// GETs `path` with rate limiting and retries, decoding the JSON body
+    async fn get_json<T: DeserializeOwned>(&self, path: &str) -> Result<T, AppError> {
+        let log_attempt = |attempt: u32, e: &AppError| {
+            gloo::console::log!(format!(
+                "Carbon Intensity {path} attempt {attempt} failed: {e}"
+            ));
+        };
+        crate::services::retry::retry_with_backoff_observed(
             || async {
-                let url = format!("{}/intensity/date", self.base_url);
+                let _permit = crate::services::limiter::request_limiter().acquire().await;
+                let url = format!("{}{path}", self.base_url);
 
-                let response = self
-                    .http
-                    .get(&url)
-                    .send()
-                    .await
-                    .map_err(|e| self.classify_error(e))?;
+                let response = self.http.get(&url).send().await.map_err(AppError::from)?;
 
                 let status = response.status();
                 if !status.is_success() {
+                    let retry_after = retry_after_ms(response.headers());
                     let body = response
                         .text()
                         .await
                         .unwrap_or_else(|_| "<failed to read error body>".to_string());
-                    return Err(self.error_for_status(status, &body));
+                    return Err(self.error_for_status(status, retry_after, &body));
                 }
 
-                let api_response: CarbonApiResponse = response
+                response
                     .json()
                     .await
-                    .map_err(|e| AppError::ApiError(format!("Failed to parse response: {e}")))?;
-
-                let now = Utc::now();
-
-                // Find most recent period with actual data
-                let latest_intensity = api_response
-                    .data
-                    .iter()
-                    .filter(|period| period.to <= now) // Only periods that have ended
-                    .filter(|period| period.intensity.actual.is_some()) // Must have actual data
-                    .max_by_key(|period| period.to) // Get the most recent one
-                    .ok_or_else(|| {
-                        AppError::DataError(
-                            "No period with actual data found in response".to_string(),
-                        )
-                    })?
-                    .clone();
-
-                // Find the current time
-                let next = api_response
-                    .data
-                    .iter()
-                    .find(|period| {
-                        // Period that follows now, or period containing now
-                        period.from > now || now < period.to
-                    })
-                    .ok_or_else(|| {
-                        AppError::DataError("No next period found in response".to_string())
-                    })?
-                    .clone();
-
-                Ok(CarbonIntensity::new(latest_intensity, next))
+                    .map_err(|e| AppError::ParseError(format!("Failed to parse response: {e}")))
             },
             crate::config::Config::MAX_RETRY_ATTEMPTS,
+            Some(&log_attempt),
         )
         .await
     }
 
-    /// Converts a reqwest error into an appropriate `AppError`
-    fn classify_error(&self, error: reqwest::Error) -> AppError {
-        if error.is_timeout() {
-            AppError::ApiError(format!("Request timeout: {error}"))
-        } else if error.is_request() {
-            AppError::ApiError(format!("Request error: {error}"))
-        } else {
-            AppError::ApiError(format!("Network error: {error}"))
-        }
-    }
-
     /// Creates an error based on HTTP status code
-    fn error_for_status(&self, status: reqwest::StatusCode, body: &str) -> AppError {
+    fn error_for_status(
+        &self,
+        status: reqwest::StatusCode,
+        retry_after: Option<u32>,
+        body: &str,
+    ) -> AppError {
         match status.as_u16() {
-            429 => AppError::RateLimited,
+            429 => AppError::RateLimited(retry_after),
             400..=499 => AppError::ApiError(format!("Client error {status}: {body}")),
-            500..=599 => AppError::ApiError(format!("Server error {status}: {body}")),
+            500..=599 => AppError::ServerError(status.as_u16(), body.to_string()),
             _ => AppError::ApiError(format!("Unexpected status {status}: {body}")),
         }
     }
 }
 
+/// Path for the 48-hour forward forecast starting at `from`, in the API's minute precision
+fn forecast_path(from: DateTime<Utc>) -> String {
+    format!("/intensity/{}/fw48h", from.format("%Y-%m-%dT%H:%MZ"))
+}
+
+/// Path for a region's forecast for the 24 hours from `from`
+fn regional_path(from: DateTime<Utc>, region_id: u8) -> String {
+    format!(
+        "/regional/intensity/{}/fw24h/regionid/{region_id}",
+        from.format("%Y-%m-%dT%H:%MZ")
+    )
+}
+
+/// Builds regional intensity from the first region in the response.
+/// Regional data is forecast-only, so "latest" is the period containing `now`.
+fn regional_intensity(
+    response: RegionalApiResponse,
+    now: DateTime<Utc>,
+) -> Result<CarbonIntensity, AppError> {
+    let regional = response
+        .data
+        .into_regions()
+        .into_iter()
+        .find(|region| !region.data.is_empty())
+        .ok_or_else(|| AppError::DataError("No regional carbon data in response".to_string()))?;
+
+    let periods = regional.data;
+    let latest = periods
+        .iter()
+        .filter(|period| period.from <= now)
+        .max_by_key(|period| period.from)
+        .unwrap_or(&periods[0])
+        .clone();
+    let next = periods
+        .iter()
+        .find(|period| period.from > now)
+        .unwrap_or(&latest)
+        .clone();
+
+    Ok(CarbonIntensity::new(latest, next, periods).with_region(regional.shortname))
+}
+
 /// Convenience function to fetch current and next period carbon intensity
 pub async fn fetch_carbon_intensity() -> Result<CarbonIntensity, AppError> {
     CarbonIntensityClient::new()?
@@ -125,6 +217,22 @@ pub async fn fetch_carbon_intensity() -> Result<CarbonIntensity, AppError> {
         .await
 }
 
+/// Convenience function to fetch the national 48-hour carbon forecast
+pub async fn fetch_carbon_forecast() -> Result<CarbonForecast, AppError> {
+    CarbonIntensityClient::new()?.fetch_forecast_48h().await
+}
+
+/// Fetches carbon intensity for `region`, falling back to national figures
+/// when the regional endpoint has no data or fails
+pub async fn fetch_carbon_intensity_for(region: Region) -> Result<CarbonIntensity, AppError> {
+    let client = CarbonIntensityClient::new()?;
+    first_ok(vec![
+        client.fetch_regional(region).boxed_local(),
+        client.fetch_current_and_next_intensity().boxed_local(),
+    ])
+    .await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,4 +342,114 @@ mod tests {
         assert_eq!(response.data[0].intensity.actual, Some(95));
         assert_eq!(response.data[2].intensity.actual, None);
     }
+
+    #[test]
+    fn test_forecast_path_uses_minute_precision() {
+        use chrono::TimeZone;
+
+        let from = Utc.with_ymd_and_hms(2026, 1, 12, 19, 42, 17).unwrap();
+        assert_eq!(forecast_path(from), "/intensity/2026-01-12T19:42Z/fw48h");
+    }
+
+    #[test]
+    fn test_forecast_response_with_null_actuals() {
+        let json = r#"{
+            "data": [
+                {
+                    "from": "2026-01-12T20:00Z",
+                    "to": "2026-01-12T20:30Z",
+                    "intensity": { "forecast": 120, "actual": null, "index": "moderate" }
+                },
+                {
+                    "from": "2026-01-12T19:30Z",
+                    "to": "2026-01-12T20:00Z",
+                    "intensity": { "forecast": 142, "actual": null, "index": "moderate" }
+                }
+            ]
+        }"#;
+
+        let response: CarbonApiResponse = serde_json::from_str(json).unwrap();
+        let forecast = CarbonForecast::new(response.data);
+
+        // Periods are put in time order
+        assert_eq!(forecast.periods()[0].intensity.forecast, 142);
+        assert!(
+            forecast
+                .periods()
+                .iter()
+                .all(|p| p.intensity.actual.is_none())
+        );
+    }
+
+    const REGIONAL_JSON: &str = r#"{
+        "data": {
+            "regionid": 1,
+            "dnoregion": "Scottish Hydro Electric Power Distribution",
+            "shortname": "North Scotland",
+            "data": [
+                {
+                    "from": "2026-01-12T19:30Z",
+                    "to": "2026-01-12T20:00Z",
+                    "intensity": { "forecast": 12, "index": "very low" },
+                    "generationmix": [
+                        { "fuel": "wind", "perc": 81.2 },
+                        { "fuel": "hydro", "perc": 10.4 }
+                    ]
+                },
+                {
+                    "from": "2026-01-12T20:00Z",
+                    "to": "2026-01-12T20:30Z",
+                    "intensity": { "forecast": 18, "index": "very low" },
+                    "generationmix": []
+                }
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn test_regional_path_looks_a_day_ahead() {
+        use chrono::TimeZone;
+
+        let from = Utc.with_ymd_and_hms(2026, 1, 12, 19, 42, 17).unwrap();
+        assert_eq!(
+            regional_path(from, 13),
+            "/regional/intensity/2026-01-12T19:42Z/fw24h/regionid/13"
+        );
+    }
+
+    #[test]
+    fn test_regional_response_parsing() {
+        let response: RegionalApiResponse = serde_json::from_str(REGIONAL_JSON).unwrap();
+        let regions = response.data.into_regions();
+
+        assert_eq!(regions[0].shortname, "North Scotland");
+        assert_eq!(regions[0].data.len(), 2);
+        assert_eq!(regions[0].data[0].intensity.forecast, 12);
+        assert_eq!(regions[0].data[0].intensity.actual, None);
+    }
+
+    #[test]
+    fn test_regional_intensity_uses_current_period() {
+        use chrono::TimeZone;
+
+        let response: RegionalApiResponse = serde_json::from_str(REGIONAL_JSON).unwrap();
+        let now = Utc.with_ymd_and_hms(2026, 1, 12, 19, 40, 0).unwrap();
+
+        let intensity = regional_intensity(response, now).unwrap();
+        assert_eq!(intensity.region.as_deref(), Some("North Scotland"));
+        assert_eq!(intensity.latest_intensity(), 12);
+        assert_eq!(intensity.next_intensity(), 18);
+        assert_eq!(intensity.periods.len(), 2);
+    }
+
+    #[test]
+    fn test_regional_intensity_empty_is_data_error() {
+        let json = r#"{ "data": [{ "regionid": 13, "shortname": "London", "data": [] }] }"#;
+        let response: RegionalApiResponse = serde_json::from_str(json).unwrap();
+
+        assert!(matches!(
+            regional_intensity(response, Utc::now()),
+            Err(AppError::DataError(_))
+        ));
+    }
 }
@@ -0,0 +1,92 @@
+use crate::models::error::AppError;
+use futures::future::LocalBoxFuture;
+
+/// Awaits each operation in order and returns the first success.
+///
+/// Later operations are only polled once every earlier one has failed, so
+/// the list reads as a preference order (e.g. regional, then national).
+/// Futures are local (non-`Send`) because fetches run on the WASM main thread.
+///
+/// # Errors
+///
+/// If every operation fails, their messages are joined into a single
+/// `AppError::ApiError`. An empty list yields `AppError::ConfigError`.
+pub async fn first_ok<T>(ops: Vec<LocalBoxFuture<'_, Result<T, AppError>>>) -> Result<T, AppError> {
+    if ops.is_empty() {
+        return Err(AppError::ConfigError(
+            "No operations provided to first_ok".to_string(),
+        ));
+    }
+
+    let mut errors = Vec::with_capacity(ops.len());
+
+    for op in ops {
+        match op.await {
+            Ok(result) => return Ok(result),
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+
+    Err(AppError::ApiError(format!(
+        "All fallbacks failed: {}",
+        errors.join("; ")
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+    use futures::executor::block_on;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_first_succeeds() {
+        let second_polled = Cell::new(false);
+        let ops = vec![
+            async { Ok(1) }.boxed_local(),
+            async {
+                second_polled.set(true);
+                Ok(2)
+            }
+            .boxed_local(),
+        ];
+
+        assert_eq!(block_on(first_ok(ops)), Ok(1));
+        assert!(!second_polled.get());
+    }
+
+    #[test]
+    fn test_second_succeeds() {
+        let ops = vec![
+            async { Err(AppError::NotFound("regional".to_string())) }.boxed_local(),
+            async { Ok(2) }.boxed_local(),
+        ];
+
+        assert_eq!(block_on(first_ok(ops)), Ok(2));
+    }
+
+    #[test]
+    fn test_all_fail_aggregates_errors() {
+        let ops: Vec<LocalBoxFuture<'_, Result<u32, AppError>>> = vec![
+            async { Err(AppError::RateLimited(None)) }.boxed_local(),
+            async { Err(AppError::DataError("empty".to_string())) }.boxed_local(),
+        ];
+
+        assert_eq!(
+            block_on(first_ok(ops)),
+            Err(AppError::ApiError(
+                "All fallbacks failed: Rate limited; Data Error: empty".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_no_operations() {
+        let ops: Vec<LocalBoxFuture<'_, Result<u32, AppError>>> = vec![];
+        assert!(matches!(
+            block_on(first_ok(ops)),
+            Err(AppError::ConfigError(_))
+        ));
+    }
+}
@@ -0,0 +1,256 @@
+use crate::config::Config;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+thread_local! {
+    static REQUEST_LIMITER: ConcurrencyLimiter =
+        ConcurrencyLimiter::new(Config::MAX_CONCURRENT_REQUESTS);
+}
+
+/// Returns the shared limiter that all outbound API requests go through
+pub fn request_limiter() -> ConcurrencyLimiter {
+    REQUEST_LIMITER.with(Clone::clone)
+}
+
+#[derive(Debug)]
+struct LimiterState {
+    max: usize,
+    active: usize,
+    next_id: u64,
+    waiters: VecDeque<(u64, Waker)>,
+}
+
+impl LimiterState {
+    /// Wakes the longest-waiting acquirer, if any
+    fn wake_next(&mut self) {
+        if let Some((_, waker)) = self.waiters.pop_front() {
+            waker.wake();
+        }
+    }
+}
+
+/// Semaphore-style limiter for single-threaded WASM.
+///
+/// At most `max` permits are held at once; further `acquire` calls queue
+/// and are woken in FIFO order as permits are dropped.
+#[derive(Debug, Clone)]
+pub struct ConcurrencyLimiter {
+    state: Rc<RefCell<LimiterState>>,
+}
+
+impl ConcurrencyLimiter {
+    /// Creates a limiter allowing `max` concurrent permits (at least one)
+    pub fn new(max: usize) -> Self {
+        Self {
+            state: Rc::new(RefCell::new(LimiterState {
+                max: max.max(1),
+                active: 0,
+                next_id: 0,
+                waiters: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// Waits for a free slot; the slot is released when the permit is dropped
+    pub fn acquire(&self) -> Acquire {
+        Acquire {
+            state: self.state.clone(),
+            waiter_id: None,
+        }
+    }
+
+    /// Runs `future` while holding a permit
+    pub async fn run<F: Future>(&self, future: F) -> F::Output {
+        let _permit = self.acquire().await;
+        future.await
+    }
+
+    /// Number of permits currently held
+    pub fn active(&self) -> usize {
+        self.state.borrow().active
+    }
+}
+
+/// Future returned by `ConcurrencyLimiter::acquire`
+#[derive(Debug)]
+pub struct Acquire {
+    state: Rc<RefCell<LimiterState>>,
+    waiter_id: Option<u64>,
+}
+
+impl Future for Acquire {
+    type Output = Permit;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+
+        if state.active < state.max {
+            state.active += 1;
+            if let Some(id) = self.waiter_id {
+                state.waiters.retain(|(waiter, _)| *waiter != id);
+            }
+            drop(state);
+            self.waiter_id = None;
+            return Poll::Ready(Permit {
+                state: self.state.clone(),
+            });
+        }
+
+        // Refresh our waker in place if still queued, otherwise (re-)join the queue
+        let queued = self
+            .waiter_id
+            .and_then(|id| state.waiters.iter_mut().find(|(waiter, _)| *waiter == id));
+        if let Some((_, waker)) = queued {
+            waker.clone_from(cx.waker());
+        } else {
+            let id = state.next_id;
+            state.next_id += 1;
+            state.waiters.push_back((id, cx.waker().clone()));
+            drop(state);
+            self.waiter_id = Some(id);
+        }
+
+        Poll::Pending
+    }
+}
+
+impl Drop for Acquire {
+    fn drop(&mut self) {
+        let Some(id) = self.waiter_id else {
+            return;
+        };
+
+        let mut state = self.state.borrow_mut();
+        let before = state.waiters.len();
+        state.waiters.retain(|(waiter, _)| *waiter != id);
+
+        // We were woken for a free slot but gave up; pass the wake-up on
+        if state.waiters.len() == before && state.active < state.max {
+            state.wake_next();
+        }
+    }
+}
+
+/// A held concurrency slot, released on drop
+#[derive(Debug)]
+pub struct Permit {
+    state: Rc<RefCell<LimiterState>>,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        let mut state = self.state.borrow_mut();
+        state.active -= 1;
+        state.wake_next();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::channel::oneshot;
+    use futures::executor::LocalPool;
+    use futures::task::LocalSpawnExt;
+    use std::cell::Cell;
+
+    /// Spawns `count` tasks that each hold a permit until their channel fires
+    fn spawn_tasks(
+        pool: &LocalPool,
+        limiter: &ConcurrencyLimiter,
+        count: usize,
+    ) -> (Rc<Cell<usize>>, Vec<oneshot::Sender<()>>) {
+        let started = Rc::new(Cell::new(0));
+        let mut releases = Vec::new();
+
+        for _ in 0..count {
+            let (tx, rx) = oneshot::channel::<()>();
+            releases.push(tx);
+            let limiter = limiter.clone();
+            let started = started.clone();
+            pool.spawner()
+                .spawn_local(async move {
+                    limiter
+                        .run(async {
+                            started.set(started.get() + 1);
+                            let _ = rx.await;
+                        })
+                        .await;
+                })
+                .unwrap();
+        }
+
+        (started, releases)
+    }
+
+    #[test]
+    fn test_allows_up_to_max_concurrent() {
+        let mut pool = LocalPool::new();
+        let limiter = ConcurrencyLimiter::new(2);
+        let (started, _releases) = spawn_tasks(&pool, &limiter, 2);
+
+        pool.run_until_stalled();
+
+        assert_eq!(started.get(), 2);
+        assert_eq!(limiter.active(), 2);
+    }
+
+    #[test]
+    fn test_queues_beyond_max_and_releases_in_turn() {
+        let mut pool = LocalPool::new();
+        let limiter = ConcurrencyLimiter::new(2);
+        let (started, mut releases) = spawn_tasks(&pool, &limiter, 4);
+
+        pool.run_until_stalled();
+        assert_eq!(started.get(), 2);
+
+        releases.remove(0).send(()).unwrap();
+        pool.run_until_stalled();
+        assert_eq!(started.get(), 3);
+        assert_eq!(limiter.active(), 2);
+
+        for release in releases {
+            let _ = release.send(());
+        }
+        pool.run_until_stalled();
+        assert_eq!(started.get(), 4);
+        assert_eq!(limiter.active(), 0);
+    }
+
+    #[test]
+    fn test_dropped_waiter_passes_slot_on() {
+        let mut pool = LocalPool::new();
+        let limiter = ConcurrencyLimiter::new(1);
+        let (started, mut releases) = spawn_tasks(&pool, &limiter, 1);
+        pool.run_until_stalled();
+
+        // A waiter that gives up must not strand the task queued behind it
+        let abandoned = Rc::new(RefCell::new(Some(limiter.acquire())));
+        {
+            let abandoned = abandoned.clone();
+            pool.spawner()
+                .spawn_local(futures::future::poll_fn(move |cx| {
+                    if let Some(acquire) = abandoned.borrow_mut().as_mut() {
+                        let _ = Pin::new(acquire).poll(cx);
+                    }
+                    Poll::Ready(())
+                }))
+                .unwrap();
+        }
+        let (later_started, _later_releases) = spawn_tasks(&pool, &limiter, 1);
+        pool.run_until_stalled();
+
+        // The freed slot's wake-up goes to the abandoned waiter first
+        releases.remove(0).send(()).unwrap();
+        pool.run_until_stalled();
+        assert_eq!(started.get(), 1);
+        assert_eq!(later_started.get(), 0);
+
+        abandoned.borrow_mut().take();
+        pool.run_until_stalled();
+        assert_eq!(later_started.get(), 1);
+    }
+}
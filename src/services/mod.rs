@@ -1,3 +1,9 @@
 pub mod api;
+pub mod cache;
 pub mod carbon_api;
+pub mod fallback;
+pub mod limiter;
+pub mod notifications;
 pub mod retry;
+pub mod settings;
+pub mod storage;
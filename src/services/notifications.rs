@@ -0,0 +1,186 @@
+use chrono::{DateTime, Utc};
+use gloo_storage::{LocalStorage, Storage};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Notification, NotificationOptions, NotificationPermission};
+
+use crate::models::rates::{Rate, Rates};
+use crate::services::storage;
+
+const NOTIFIED_KEY: &str = "notified_slots";
+
+/// How far ahead price alerts look for cheap slots
+const ALERT_LOOKAHEAD_HOURS: i64 = 12;
+
+/// Whether the browser exposes the Notifications API at all
+pub fn supported() -> bool {
+    web_sys::window()
+        .is_some_and(|w| web_sys::js_sys::Reflect::has(&w, &"Notification".into()).unwrap_or(false))
+}
+
+/// The user's notification choice; `Denied` when notifications are unsupported
+pub fn permission() -> NotificationPermission {
+    if supported() {
+        Notification::permission()
+    } else {
+        NotificationPermission::Denied
+    }
+}
+
+/// Asks for permission if the user has not decided yet, resolving to their choice
+pub async fn request_permission() -> NotificationPermission {
+    if permission() != NotificationPermission::Default {
+        return permission();
+    }
+    match Notification::request_permission() {
+        Ok(promise) => {
+            let _ = JsFuture::from(promise).await;
+        }
+        Err(_) => web_sys::console::warn_1(&"Notification permission request failed".into()),
+    }
+    permission()
+}
+
+/// Shows a notification if permission has been granted
+pub fn notify(title: &str, body: &str) {
+    if permission() != NotificationPermission::Granted {
+        return;
+    }
+
+    let options = NotificationOptions::new();
+    options.set_body(body);
+    if let Err(e) = Notification::new_with_options(title, &options) {
+        web_sys::console::warn_1(&format!("Failed to show notification: {e:?}").into());
+    }
+}
+
+/// Notifies about slots in the next 12 hours priced below `threshold_p`, once per slot.
+///
+/// Slots already alerted are remembered in localStorage, so a reload or re-poll does
+/// not repeat them.
+pub fn alert_cheap_slots(rates: &Rates, threshold_p: f64) {
+    let now = Utc::now();
+    let notified = prune_notified(
+        storage::safe_get::<Vec<i64>>(NOTIFIED_KEY).unwrap_or_default(),
+        now,
+    );
+    let slots = slots_to_alert(rates, now, threshold_p, &notified);
+
+    if let Some(first) = slots.first() {
+        let body = match slots.len() {
+            1 => first.slot_label(),
+            n => format!("{} and {} more", first.slot_label(), n - 1),
+        };
+        notify(&format!("Price below {threshold_p}p coming up"), &body);
+    }
+
+    let notified: Vec<i64> = notified
+        .into_iter()
+        .chain(slots.iter().map(|r| r.valid_from.timestamp()))
+        .collect();
+    if let Err(e) = LocalStorage::set(NOTIFIED_KEY, notified) {
+        web_sys::console::warn_1(&format!("Failed to save notified slots: {e:?}").into());
+    }
+}
+
+/// Slots starting within the next 12 hours that are priced below
+/// `threshold_p` and not in `notified` (slot start times as Unix seconds)
+fn slots_to_alert<'a>(
+    rates: &'a Rates,
+    now: DateTime<Utc>,
+    threshold_p: f64,
+    notified: &[i64],
+) -> Vec<&'a Rate> {
+    let horizon = now + chrono::Duration::hours(ALERT_LOOKAHEAD_HOURS);
+    rates
+        .filter_from(now)
+        .take_while(|r| r.valid_from < horizon)
+        .filter(|r| r.value_inc_vat < threshold_p)
+        .filter(|r| !notified.contains(&r.valid_from.timestamp()))
+        .collect()
+}
+
+/// Drops remembered slots that started over 12 hours ago, which can no longer be alerted
+fn prune_notified(notified: Vec<i64>, now: DateTime<Utc>) -> Vec<i64> {
+    let cutoff = (now - chrono::Duration::hours(ALERT_LOOKAHEAD_HOURS)).timestamp();
+    notified.into_iter().filter(|&t| t >= cutoff).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn make_rates(values: &[f64]) -> Rates {
+        let start = Utc.with_ymd_and_hms(2026, 1, 12, 10, 0, 0).unwrap();
+        Rates::new(
+            values
+                .iter()
+                .zip(0..)
+                .map(|(&value, i)| {
+                    let valid_from = start + chrono::Duration::minutes(30 * i);
+                    Rate {
+                        value_inc_vat: value,
+                        value_exc_vat: value / 1.05,
+                        valid_from,
+                        valid_to: valid_from + chrono::Duration::minutes(30),
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    fn starts(slots: &[&Rate]) -> Vec<u32> {
+        use chrono::Timelike;
+        slots
+            .iter()
+            .map(|r| r.valid_from.hour() * 100 + r.valid_from.minute())
+            .collect()
+    }
+
+    #[test]
+    fn test_alerts_slots_strictly_below_threshold() {
+        let rates = make_rates(&[20.0, 9.99, 10.0, 4.0]);
+        let now = Utc.with_ymd_and_hms(2026, 1, 12, 10, 0, 0).unwrap();
+
+        assert_eq!(
+            starts(&slots_to_alert(&rates, now, 10.0, &[])),
+            vec![1030, 1130]
+        );
+        assert!(slots_to_alert(&rates, now, 4.0, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_skips_notified_and_started_slots() {
+        let rates = make_rates(&[5.0, 5.0, 5.0, 5.0]);
+        let now = Utc.with_ymd_and_hms(2026, 1, 12, 10, 40, 0).unwrap();
+        let notified = [Utc
+            .with_ymd_and_hms(2026, 1, 12, 11, 0, 0)
+            .unwrap()
+            .timestamp()];
+
+        // 10:30 is already under way and 11:00 was alerted before
+        assert_eq!(
+            starts(&slots_to_alert(&rates, now, 10.0, &notified)),
+            vec![1130]
+        );
+    }
+
+    #[test]
+    fn test_looks_twelve_hours_ahead() {
+        let rates = make_rates(&[1.0; 30]);
+        let now = Utc.with_ymd_and_hms(2026, 1, 12, 10, 0, 0).unwrap();
+
+        assert_eq!(slots_to_alert(&rates, now, 10.0, &[]).len(), 24);
+    }
+
+    #[test]
+    fn test_prune_notified_keeps_recent_slots() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 12, 12, 0, 0).unwrap();
+        let hours_ago = |h| (now - chrono::Duration::hours(h)).timestamp();
+
+        assert_eq!(
+            prune_notified(vec![hours_ago(13), hours_ago(12), hours_ago(1)], now),
+            vec![hours_ago(12), hours_ago(1)]
+        );
+    }
+}
@@ -2,7 +2,36 @@ use crate::models::error::AppError;
 use gloo_timers::future::TimeoutFuture;
 use std::future::Future;
 
-/// Retries an async operation with exponential backoff for rate-limited requests.
+/// Observer called with the attempt number and its error before each retry
+pub type OnAttempt<'a> = &'a dyn Fn(u32, &AppError);
+
+/// How `retry_with_policy` spaces out and limits attempts
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first
+    pub max_attempts: u32,
+    pub initial_delay_ms: u32,
+    /// Factor applied to the delay after each retry
+    pub multiplier: u32,
+    /// Upper bound on the computed delay (a server's `Retry-After` is not capped)
+    pub max_delay_ms: u32,
+    /// Which errors are worth another attempt
+    pub retryable: fn(&AppError) -> bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: crate::config::Config::MAX_RETRY_ATTEMPTS,
+            initial_delay_ms: 100,
+            multiplier: 5,
+            max_delay_ms: 30_000,
+            retryable: AppError::is_transient,
+        }
+    }
+}
+
+/// Retries an async operation with exponential backoff for transient failures.
 ///
 /// # Arguments
 ///
@@ -13,36 +42,298 @@ use std::future::Future;
 ///
 /// The successful result, or the last error encountered
 ///
+/// Thin wrapper over `retry_with_policy` using `RetryPolicy::default()`.
+pub async fn retry_with_backoff<F, Fut, T>(operation: F, max_attempts: u32) -> Result<T, AppError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, AppError>>,
+{
+    retry_with_backoff_observed(operation, max_attempts, None).await
+}
+
+/// Like `retry_with_backoff`, calling `on_attempt` with the attempt number and its error
+/// before each retry's wait, e.g. to log how often a service is rate limiting.
+pub async fn retry_with_backoff_observed<F, Fut, T>(
+    operation: F,
+    max_attempts: u32,
+    on_attempt: Option<OnAttempt<'_>>,
+) -> Result<T, AppError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, AppError>>,
+{
+    let policy = RetryPolicy {
+        max_attempts,
+        ..RetryPolicy::default()
+    };
+    retry_observed(operation, &policy, on_attempt).await
+}
+
+/// Retries an async operation according to `policy`.
+///
 /// # Behavior
 ///
-/// - Initial delay: 100ms
-/// - Backoff multiplier: 5x (100ms → 500ms → 2500ms → ...)
-/// - Only retries on `AppError::RateLimited`
-/// - All other errors immediately propagate
-pub async fn retry_with_backoff<F, Fut, T>(
+/// - Delay starts at `initial_delay_ms` and grows by `multiplier`, capped at `max_delay_ms`
+/// - Each computed delay is jittered between half and all of its value, so tabs that
+///   hit a limit together do not retry in lockstep
+/// - A `RateLimited` error carrying a suggested delay waits exactly that long instead
+/// - Errors rejected by `policy.retryable` propagate immediately
+pub async fn retry_with_policy<F, Fut, T>(operation: F, policy: &RetryPolicy) -> Result<T, AppError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, AppError>>,
+{
+    retry_observed(operation, policy, None).await
+}
+
+async fn retry_observed<F, Fut, T>(
+    operation: F,
+    policy: &RetryPolicy,
+    on_attempt: Option<OnAttempt<'_>>,
+) -> Result<T, AppError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, AppError>>,
+{
+    run_with_retries(
+        operation,
+        policy,
+        on_attempt,
+        web_sys::js_sys::Math::random,
+        |attempt, wait_ms| {
+            gloo::console::warn!(&format!(
+                "Request failed, retrying in {}ms (attempt {}/{})",
+                wait_ms, attempt, policy.max_attempts
+            ));
+            TimeoutFuture::new(wait_ms)
+        },
+    )
+    .await
+}
+
+/// The retry loop with its randomness and sleeping injected, so it can run in tests
+async fn run_with_retries<F, Fut, T, R, S, SFut>(
     mut operation: F,
-    max_attempts: u32,
+    policy: &RetryPolicy,
+    on_attempt: Option<OnAttempt<'_>>,
+    mut random: R,
+    mut sleep: S,
 ) -> Result<T, AppError>
 where
     F: FnMut() -> Fut,
     Fut: Future<Output = Result<T, AppError>>,
+    R: FnMut() -> f64,
+    S: FnMut(u32, u32) -> SFut,
+    SFut: Future<Output = ()>,
 {
-    let mut delay_ms = 100;
+    let mut delay_ms = policy.initial_delay_ms.min(policy.max_delay_ms);
+    let mut attempt = 1;
 
-    for attempt in 1..=max_attempts {
+    loop {
         match operation().await {
             Ok(result) => return Ok(result),
-            Err(AppError::RateLimited) if attempt < max_attempts => {
-                gloo::console::warn!(&format!(
-                    "Rate limited, retrying in {}ms (attempt {}/{})",
-                    delay_ms, attempt, max_attempts
-                ));
-                TimeoutFuture::new(delay_ms).await;
-                delay_ms *= 5; // Exponential backoff: 100ms, 500ms, 2500ms, ...
+            Err(e) if attempt < policy.max_attempts && (policy.retryable)(&e) => {
+                let suggested = match e {
+                    AppError::RateLimited(suggested) => suggested,
+                    _ => None,
+                };
+                let wait_ms = backoff_delay(suggested, with_jitter(delay_ms, random()));
+                if let Some(on_attempt) = on_attempt {
+                    on_attempt(attempt, &e);
+                }
+                sleep(attempt, wait_ms).await;
+                delay_ms = delay_ms
+                    .saturating_mul(policy.multiplier)
+                    .min(policy.max_delay_ms);
+                attempt += 1;
             }
             Err(e) => return Err(e),
         }
     }
+}
 
-    Err(AppError::RateLimited)
+/// Scales `delay_ms` into `[delay_ms / 2, delay_ms]` using `random` in `[0, 1)`
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // result is within 0..=delay_ms
+fn with_jitter(delay_ms: u32, random: f64) -> u32 {
+    let half = f64::from(delay_ms) / 2.0;
+    random.clamp(0.0, 1.0).mul_add(half, half).round() as u32
+}
+
+/// Delay before the next attempt: the server's suggestion when present, else `computed_ms`
+pub fn backoff_delay(suggested_ms: Option<u32>, computed_ms: u32) -> u32 {
+    suggested_ms.unwrap_or(computed_ms)
+}
+
+/// Reads a `Retry-After` header as milliseconds. Only the delay-seconds form is
+/// supported; an HTTP-date yields `None` so the computed backoff applies.
+pub fn retry_after_ms(headers: &reqwest::header::HeaderMap) -> Option<u32> {
+    parse_retry_after(headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?)
+}
+
+fn parse_retry_after(value: &str) -> Option<u32> {
+    value.trim().parse::<u32>().ok()?.checked_mul(1000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::executor::block_on;
+    use std::cell::{Cell, RefCell};
+
+    /// Runs `run_with_retries` with fixed jitter, recording each sleep instead of waiting
+    fn run<T>(
+        policy: &RetryPolicy,
+        mut outcome: impl FnMut(u32) -> Result<T, AppError>,
+    ) -> (Result<T, AppError>, u32, Vec<u32>) {
+        let attempts = Cell::new(0);
+        let sleeps = RefCell::new(Vec::new());
+        let result = block_on(run_with_retries(
+            || {
+                attempts.set(attempts.get() + 1);
+                std::future::ready(outcome(attempts.get()))
+            },
+            policy,
+            None,
+            || 1.0,
+            |_, ms| {
+                sleeps.borrow_mut().push(ms);
+                std::future::ready(())
+            },
+        ));
+        (result, attempts.get(), sleeps.into_inner())
+    }
+
+    fn policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            ..RetryPolicy::default()
+        }
+    }
+
+    #[test]
+    fn test_retries_until_success() {
+        let (result, attempts, sleeps) = run(&policy(5), |n| {
+            if n < 3 {
+                Err(AppError::NetworkError("offline".to_string()))
+            } else {
+                Ok(n)
+            }
+        });
+
+        assert_eq!(result, Ok(3));
+        assert_eq!(attempts, 3);
+        assert_eq!(sleeps, vec![100, 500]);
+    }
+
+    #[test]
+    fn test_on_attempt_called_once_per_retry() {
+        let seen = RefCell::new(Vec::new());
+        let record = |attempt: u32, e: &AppError| seen.borrow_mut().push((attempt, e.clone()));
+        let calls = Cell::new(0);
+
+        let result = block_on(run_with_retries(
+            || {
+                calls.set(calls.get() + 1);
+                std::future::ready(if calls.get() <= 2 {
+                    Err(AppError::RateLimited(None))
+                } else {
+                    Ok(calls.get())
+                })
+            },
+            &policy(5),
+            Some(&record),
+            || 1.0,
+            |_, _| std::future::ready(()),
+        ));
+
+        assert_eq!(result, Ok(3));
+        assert_eq!(
+            seen.into_inner(),
+            vec![
+                (1, AppError::RateLimited(None)),
+                (2, AppError::RateLimited(None))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_non_retryable_error_returns_immediately() {
+        let (result, attempts, sleeps) = run(&policy(5), |_| {
+            Err::<(), _>(AppError::NotFound("missing".to_string()))
+        });
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+        assert_eq!(attempts, 1);
+        assert!(sleeps.is_empty());
+    }
+
+    #[test]
+    fn test_gives_up_after_max_attempts_with_last_error() {
+        let (result, attempts, _) = run(&policy(4), |_| {
+            Err::<(), _>(AppError::ServerError(503, "busy".to_string()))
+        });
+
+        assert!(matches!(result, Err(AppError::ServerError(503, _))));
+        assert_eq!(attempts, 4);
+    }
+
+    #[test]
+    fn test_delay_is_capped() {
+        let capped = RetryPolicy {
+            max_attempts: 6,
+            max_delay_ms: 1_000,
+            ..RetryPolicy::default()
+        };
+        let (_, _, sleeps) = run(&capped, |_| Err::<(), _>(AppError::RateLimited(None)));
+
+        assert_eq!(sleeps, vec![100, 500, 1_000, 1_000, 1_000]);
+    }
+
+    #[test]
+    fn test_suggested_delay_is_used_as_is() {
+        let (_, _, sleeps) = run(&policy(2), |_| {
+            Err::<(), _>(AppError::RateLimited(Some(60_000)))
+        });
+
+        assert_eq!(sleeps, vec![60_000]);
+    }
+
+    #[test]
+    fn test_with_jitter_stays_within_half_to_full_delay() {
+        assert_eq!(with_jitter(1_000, 0.0), 500);
+        assert_eq!(with_jitter(1_000, 0.5), 750);
+        assert_eq!(with_jitter(1_000, 1.0), 1_000);
+        assert_eq!(with_jitter(0, 0.7), 0);
+    }
+
+    #[test]
+    fn test_backoff_delay_prefers_suggestion() {
+        assert_eq!(backoff_delay(Some(3_000), 100), 3_000);
+        assert_eq!(backoff_delay(None, 500), 500);
+    }
+
+    #[test]
+    fn test_parse_retry_after_seconds() {
+        assert_eq!(parse_retry_after("120"), Some(120_000));
+        assert_eq!(parse_retry_after(" 3 "), Some(3_000));
+    }
+
+    #[test]
+    fn test_parse_retry_after_rejects_other_forms() {
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2026 07:28:00 GMT"), None);
+        assert_eq!(parse_retry_after("-1"), None);
+        assert_eq!(parse_retry_after(""), None);
+    }
+
+    #[test]
+    fn test_retry_after_header_lookup() {
+        use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after_ms(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        assert_eq!(retry_after_ms(&headers), Some(7_000));
+    }
 }
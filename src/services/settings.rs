@@ -0,0 +1,200 @@
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::config::Config;
+use crate::models::chart_type::ChartType;
+use crate::models::palette::Palette;
+use crate::models::price_unit::PriceUnit;
+use crate::models::theme::Theme;
+use crate::models::thresholds::PriceThresholds;
+use crate::services::api::Region;
+use crate::services::storage;
+
+const STORAGE_KEY: &str = "settings";
+
+/// Schema version written with every save. Bump it, and teach `migrate` to upgrade the
+/// previous shape, whenever a stored field changes meaning.
+pub const SETTINGS_VERSION: u64 = 1;
+
+/// Every persisted user preference, stored together under one versioned key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub version: u64,
+    pub theme: Theme,
+    pub region: Region,
+    pub thresholds: PriceThresholds,
+    /// Block length (hours) the cheapest-block picker opens on
+    pub block_hours: i64,
+    pub chart_type: ChartType,
+    pub palette: Palette,
+    /// Whether Agile Outgoing export prices are fetched to compare against import
+    pub show_export: bool,
+    /// Regions listed first in the region selector, in the order they were starred
+    pub favourite_regions: Vec<Region>,
+    pub price_unit: PriceUnit,
+    /// Price (pence) below which upcoming slots raise a notification; `None` is off
+    pub alert_threshold_p: Option<f64>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            theme: Theme::Auto,
+            region: Region::default(),
+            thresholds: PriceThresholds::default(),
+            block_hours: Config::DEFAULT_BLOCK_HOURS,
+            chart_type: ChartType::default(),
+            palette: Palette::default(),
+            show_export: false,
+            favourite_regions: Vec::new(),
+            price_unit: PriceUnit::default(),
+            alert_threshold_p: None,
+        }
+    }
+}
+
+/// Preferences as stored before `Settings`, one localStorage key each
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct LegacySettings {
+    theme: Option<Theme>,
+    region: Option<Region>,
+    thresholds: Option<PriceThresholds>,
+    chart_type: Option<ChartType>,
+    palette: Option<Palette>,
+}
+
+impl From<LegacySettings> for Settings {
+    fn from(legacy: LegacySettings) -> Self {
+        let defaults = Self::default();
+        Self {
+            theme: legacy.theme.unwrap_or(defaults.theme),
+            region: legacy.region.unwrap_or(defaults.region),
+            thresholds: legacy.thresholds.unwrap_or(defaults.thresholds),
+            chart_type: legacy.chart_type.unwrap_or(defaults.chart_type),
+            palette: legacy.palette.unwrap_or(defaults.palette),
+            ..defaults
+        }
+    }
+}
+
+/// Loads the stored settings.
+///
+/// Without a readable entry (first load, or one that is corrupt or from a newer version),
+/// the legacy per-preference keys are read instead. Nothing is written here, so an entry
+/// this build can't read survives until a preference is changed.
+pub fn load() -> Settings {
+    LocalStorage::raw()
+        .get_item(STORAGE_KEY)
+        .ok()
+        .flatten()
+        .and_then(|raw| decode_settings(&raw))
+        .unwrap_or_else(|| Settings::from(load_legacy()))
+}
+
+/// Stores `settings`, replacing every saved preference
+pub fn save(settings: &Settings) {
+    if let Err(e) = LocalStorage::set(STORAGE_KEY, settings) {
+        web_sys::console::warn_1(&format!("Failed to save settings: {e:?}").into());
+    }
+}
+
+fn load_legacy() -> LegacySettings {
+    LegacySettings {
+        theme: storage::safe_get("theme"),
+        region: storage::safe_get("region"),
+        thresholds: storage::safe_get("price_thresholds"),
+        chart_type: storage::safe_get("chart_type"),
+        palette: storage::safe_get("palette"),
+    }
+}
+
+fn decode_settings(raw: &str) -> Option<Settings> {
+    migrate(serde_json::from_str(raw).ok()?)
+}
+
+/// Brings a stored settings object up to `SETTINGS_VERSION`. Unversioned objects and
+/// versions this build doesn't know are discarded.
+fn migrate(value: Value) -> Option<Settings> {
+    match value.get("version").and_then(Value::as_u64)? {
+        SETTINGS_VERSION => serde_json::from_value(value).ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_round_trip() {
+        let settings = Settings {
+            theme: Theme::Dark,
+            region: Region::P,
+            thresholds: PriceThresholds {
+                cheap: Some(10.0),
+                expensive: None,
+            },
+            block_hours: 2,
+            chart_type: ChartType::Line,
+            palette: Palette::ColorBlindSafe,
+            show_export: true,
+            favourite_regions: vec![Region::C, Region::M],
+            price_unit: PriceUnit::Pounds,
+            alert_threshold_p: Some(8.5),
+            ..Settings::default()
+        };
+
+        let raw = serde_json::to_string(&settings).unwrap();
+        assert_eq!(decode_settings(&raw), Some(settings));
+    }
+
+    #[test]
+    fn test_missing_fields_take_defaults() {
+        let settings = decode_settings(r#"{"version": 1, "region": "M"}"#).unwrap();
+
+        assert_eq!(
+            settings,
+            Settings {
+                region: Region::M,
+                ..Settings::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_unknown_versions_and_corrupt_json_are_discarded() {
+        assert_eq!(decode_settings(r#"{"region": "M"}"#), None);
+        assert_eq!(decode_settings(r#"{"version": 99, "region": "M"}"#), None);
+        assert_eq!(decode_settings(r#"{"version": "1"}"#), None);
+        assert_eq!(
+            decode_settings(r#"{"version": 1, "block_hours": "four"}"#),
+            None
+        );
+        assert_eq!(decode_settings("{\"version\": 1"), None);
+    }
+
+    #[test]
+    fn test_legacy_keys_fill_in_over_defaults() {
+        let legacy = LegacySettings {
+            theme: Some(Theme::Light),
+            region: Some(Region::A),
+            ..LegacySettings::default()
+        };
+
+        assert_eq!(
+            Settings::from(legacy),
+            Settings {
+                theme: Theme::Light,
+                region: Region::A,
+                ..Settings::default()
+            }
+        );
+        assert_eq!(
+            Settings::from(LegacySettings::default()),
+            Settings::default()
+        );
+    }
+}
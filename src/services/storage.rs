@@ -0,0 +1,109 @@
+use gloo_storage::{LocalStorage, Storage};
+use serde::de::DeserializeOwned;
+
+/// The parts of a key-value store `safe_get` needs, so it can be tested without a browser
+trait KeyValueStore {
+    fn get_raw(&self, key: &str) -> Option<String>;
+    fn remove(&self, key: &str);
+}
+
+struct BrowserStorage;
+
+impl KeyValueStore for BrowserStorage {
+    fn get_raw(&self, key: &str) -> Option<String> {
+        LocalStorage::raw().get_item(key).ok().flatten()
+    }
+
+    fn remove(&self, key: &str) {
+        LocalStorage::delete(key);
+    }
+}
+
+/// Reads `key` from localStorage as JSON.
+///
+/// A value that no longer parses (e.g. written before a schema change) is removed with a
+/// console warning, so it falls back to the default once rather than on every load.
+pub fn safe_get<T: DeserializeOwned>(key: &str) -> Option<T> {
+    safe_get_from(&BrowserStorage, key)
+}
+
+fn safe_get_from<T: DeserializeOwned>(store: &impl KeyValueStore, key: &str) -> Option<T> {
+    let raw = store.get_raw(key)?;
+    match serde_json::from_str(&raw) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            if cfg!(target_arch = "wasm32") {
+                web_sys::console::warn_1(
+                    &format!("Removing unreadable stored value for {key}: {e}").into(),
+                );
+            }
+            store.remove(key);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::api::Region;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct MemoryStore(RefCell<HashMap<String, String>>);
+
+    impl MemoryStore {
+        fn with(key: &str, raw: &str) -> Self {
+            let store = Self::default();
+            store
+                .0
+                .borrow_mut()
+                .insert(key.to_string(), raw.to_string());
+            store
+        }
+    }
+
+    impl KeyValueStore for MemoryStore {
+        fn get_raw(&self, key: &str) -> Option<String> {
+            self.0.borrow().get(key).cloned()
+        }
+
+        fn remove(&self, key: &str) {
+            self.0.borrow_mut().remove(key);
+        }
+    }
+
+    #[test]
+    fn test_safe_get_reads_valid_value() {
+        let store = MemoryStore::with("region", r#""M""#);
+
+        assert_eq!(safe_get_from::<Region>(&store, "region"), Some(Region::M));
+        assert!(store.get_raw("region").is_some());
+    }
+
+    #[test]
+    fn test_safe_get_removes_malformed_json() {
+        let store = MemoryStore::with("region", r#"{"code": "#);
+
+        assert_eq!(safe_get_from::<Region>(&store, "region"), None);
+        assert_eq!(store.get_raw("region"), None);
+    }
+
+    #[test]
+    fn test_safe_get_removes_value_of_wrong_shape() {
+        // Valid JSON, but not a region
+        let store = MemoryStore::with("region", r#""Z""#);
+
+        assert_eq!(safe_get_from::<Region>(&store, "region"), None);
+        assert_eq!(store.get_raw("region"), None);
+    }
+
+    #[test]
+    fn test_safe_get_missing_key() {
+        assert_eq!(
+            safe_get_from::<Region>(&MemoryStore::default(), "region"),
+            None
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use crate::models::error::AppError;
+use crate::utils::timeout::race;
+use futures::FutureExt;
+use futures::channel::oneshot;
+use futures::future::Shared;
+use std::future::Future;
+
+/// Cancels the futures run under its `AbortSignal`. Aborting, or dropping the controller,
+/// drops them unfinished; for a reqwest fetch that aborts the browser request itself.
+pub struct AbortController {
+    trigger: oneshot::Sender<()>,
+    signal: AbortSignal,
+}
+
+/// Cheap, clonable view of an `AbortController`
+#[derive(Clone)]
+pub struct AbortSignal {
+    aborted: Shared<oneshot::Receiver<()>>,
+}
+
+impl Default for AbortController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AbortController {
+    pub fn new() -> Self {
+        let (trigger, aborted) = oneshot::channel();
+        Self {
+            trigger,
+            signal: AbortSignal {
+                aborted: aborted.shared(),
+            },
+        }
+    }
+
+    pub fn signal(&self) -> AbortSignal {
+        self.signal.clone()
+    }
+
+    pub fn abort(self) {
+        // The signal resolves whether or not anyone is still listening
+        let _ = self.trigger.send(());
+    }
+}
+
+impl AbortSignal {
+    /// Whether the controller has aborted or gone away
+    pub fn is_aborted(&self) -> bool {
+        self.aborted.clone().now_or_never().is_some()
+    }
+
+    /// Resolves once aborted
+    pub async fn aborted(&self) {
+        let _ = self.aborted.clone().await;
+    }
+}
+
+/// Runs `fut` until it finishes or `signal` aborts, whichever is first. An abort drops
+/// `fut` and yields `AppError::Aborted`; an already-aborted signal never starts it.
+pub async fn abortable<T>(
+    fut: impl Future<Output = Result<T, AppError>>,
+    signal: &AbortSignal,
+) -> Result<T, AppError> {
+    if signal.is_aborted() {
+        return Err(AppError::Aborted);
+    }
+    race(fut, async {
+        signal.aborted().await;
+        Err(AppError::Aborted)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use std::cell::Cell;
+    use std::future::{pending, ready};
+
+    #[test]
+    fn test_signal_reports_abort_and_drop() {
+        let controller = AbortController::new();
+        let signal = controller.signal();
+        assert!(!signal.is_aborted());
+        controller.abort();
+        assert!(signal.is_aborted());
+
+        let dropped = AbortController::new();
+        let signal = dropped.signal();
+        drop(dropped);
+        assert!(signal.is_aborted());
+    }
+
+    #[test]
+    fn test_abortable_passes_through_until_aborted() {
+        let controller = AbortController::new();
+        let signal = controller.signal();
+
+        assert_eq!(block_on(abortable(ready(Ok(3)), &signal)), Ok(3));
+
+        let pending_fetch = abortable(pending::<Result<(), AppError>>(), &signal);
+        controller.abort();
+        assert_eq!(block_on(pending_fetch), Err(AppError::Aborted));
+    }
+
+    #[test]
+    fn test_aborted_signal_never_starts_the_future() {
+        let controller = AbortController::new();
+        let signal = controller.signal();
+        controller.abort();
+
+        let started = Cell::new(false);
+        let result = block_on(abortable(
+            async {
+                started.set(true);
+                Ok(())
+            },
+            &signal,
+        ));
+        assert_eq!(result, Err(AppError::Aborted));
+        assert!(!started.get());
+    }
+}
@@ -0,0 +1,34 @@
+use crate::models::price_unit::PriceUnit;
+
+/// A price given in pence, in the chosen unit: "18.70p" or "£0.1870".
+///
+/// Pounds keep four decimal places so no precision is lost from the pence figure.
+pub fn format_price(value_p: f64, unit: PriceUnit) -> String {
+    match unit {
+        PriceUnit::Pence => format!("{value_p:.2}p"),
+        PriceUnit::Pounds => {
+            let sign = if value_p < 0.0 { "-" } else { "" };
+            format!("{sign}£{:.4}", value_p.abs() / 100.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_price_in_pence() {
+        assert_eq!(format_price(18.7, PriceUnit::Pence), "18.70p");
+        assert_eq!(format_price(0.0, PriceUnit::Pence), "0.00p");
+        assert_eq!(format_price(-3.456, PriceUnit::Pence), "-3.46p");
+    }
+
+    #[test]
+    fn test_format_price_in_pounds() {
+        assert_eq!(format_price(18.7, PriceUnit::Pounds), "£0.1870");
+        assert_eq!(format_price(123.456, PriceUnit::Pounds), "£1.2346");
+        assert_eq!(format_price(0.0, PriceUnit::Pounds), "£0.0000");
+        assert_eq!(format_price(-3.5, PriceUnit::Pounds), "-£0.0350");
+    }
+}
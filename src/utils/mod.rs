@@ -1,2 +1,5 @@
+pub mod abort;
 pub mod debounce;
+pub mod format;
 pub mod time;
+pub mod timeout;
@@ -14,6 +14,31 @@ pub fn london_today() -> NaiveDate {
     london_date(Utc::now())
 }
 
+/// Formats a slot as a London-time window, e.g. "01:00–01:30"
+pub fn format_slot_window(from: DateTime<Utc>, to: DateTime<Utc>) -> String {
+    format!(
+        "{}–{}",
+        london_time(from).format("%H:%M"),
+        london_time(to).format("%H:%M")
+    )
+}
+
+/// Formats a remaining duration as "MM:SS", clamping negatives to zero
+pub fn format_countdown(remaining: chrono::Duration) -> String {
+    let seconds = remaining.num_seconds().max(0);
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Relative "updated ..." label for a past fetch time
+pub fn format_updated_ago(updated: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let minutes = (now - updated).num_minutes();
+    match minutes {
+        ..1 => "updated just now".to_string(),
+        1..60 => format!("updated {minutes} min ago"),
+        _ => format!("updated {} h ago", minutes / 60),
+    }
+}
+
 pub fn london_midnight_utc(date: NaiveDate) -> DateTime<Utc> {
     let offset_seconds = london_midnight_offset_seconds(date);
     let utc_midnight =
@@ -84,6 +109,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_slot_window_uses_london_time() {
+        let from = Utc.with_ymd_and_hms(2026, 7, 1, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2026, 7, 1, 0, 30, 0).unwrap();
+
+        assert_eq!(format_slot_window(from, to), "01:00–01:30");
+    }
+
+    #[test]
+    fn format_countdown_pads_minutes_and_seconds() {
+        assert_eq!(
+            format_countdown(chrono::Duration::seconds(23 * 60 + 14)),
+            "23:14"
+        );
+        assert_eq!(format_countdown(chrono::Duration::seconds(5)), "00:05");
+        assert_eq!(format_countdown(chrono::Duration::seconds(-3)), "00:00");
+    }
+
+    #[test]
+    fn format_updated_ago_buckets_elapsed_time() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 12, 12, 0, 0).unwrap();
+
+        assert_eq!(
+            format_updated_ago(now - chrono::Duration::seconds(30), now),
+            "updated just now"
+        );
+        assert_eq!(
+            format_updated_ago(now - chrono::Duration::minutes(3), now),
+            "updated 3 min ago"
+        );
+        assert_eq!(
+            format_updated_ago(now - chrono::Duration::minutes(125), now),
+            "updated 2 h ago"
+        );
+    }
+
     #[test]
     fn london_midnight_utc_handles_spring_forward_day() {
         let start = london_midnight_utc(NaiveDate::from_ymd_opt(2026, 3, 29).unwrap());
@@ -0,0 +1,42 @@
+use crate::models::error::AppError;
+use futures::future::{Either, select};
+use gloo_timers::future::TimeoutFuture;
+use std::future::Future;
+use std::pin::pin;
+
+/// Resolves with whichever future finishes first; the other is dropped unfinished
+pub async fn race<T>(first: impl Future<Output = T>, second: impl Future<Output = T>) -> T {
+    match select(pin!(first), pin!(second)).await {
+        Either::Left((output, _)) | Either::Right((output, _)) => output,
+    }
+}
+
+/// Runs `fut`, giving up with a network error if it takes longer than `timeout_ms`
+pub async fn with_timeout<T>(
+    fut: impl Future<Output = Result<T, AppError>>,
+    timeout_ms: u32,
+) -> Result<T, AppError> {
+    race(fut, async move {
+        TimeoutFuture::new(timeout_ms).await;
+        Err(AppError::NetworkError("Request timed out".to_string()))
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use std::future::{pending, ready};
+
+    #[test]
+    fn test_race_returns_first_to_finish() {
+        assert_eq!(block_on(race(ready(1), pending())), 1);
+        assert_eq!(block_on(race(pending(), ready(2))), 2);
+    }
+
+    #[test]
+    fn test_race_prefers_first_when_both_ready() {
+        assert_eq!(block_on(race(ready("first"), ready("second"))), "first");
+    }
+}
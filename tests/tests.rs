@@ -47,6 +47,54 @@ mod tests {
         assert_eq!(error.to_string(), "Data Error: Invalid data");
     }
 
+    #[test]
+    fn test_app_error_display_for_every_variant() {
+        let cases = [
+            (
+                AppError::ConfigError("bad region".to_string()),
+                "Configuration error: bad region",
+            ),
+            (
+                AppError::ServerError(503, "busy".to_string()),
+                "Server error 503: busy",
+            ),
+            (AppError::RateLimited(None), "Rate limited"),
+            (AppError::RateLimited(Some(5_000)), "Rate limited"),
+            (
+                AppError::AuthError("401".to_string()),
+                "Authentication error: 401",
+            ),
+            (
+                AppError::NotFound("tariff".to_string()),
+                "Not found: tariff",
+            ),
+            (
+                AppError::NetworkError("Request timeout: 30s".to_string()),
+                "Network error: Request timeout: 30s",
+            ),
+            (
+                AppError::ParseError("missing field".to_string()),
+                "Parse error: missing field",
+            ),
+        ];
+
+        for (error, expected) in cases {
+            assert_eq!(error.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_app_error_transient_classification() {
+        assert!(AppError::RateLimited(None).is_transient());
+        assert!(AppError::NetworkError("timeout".to_string()).is_transient());
+        assert!(AppError::ServerError(502, "bad gateway".to_string()).is_transient());
+
+        assert!(!AppError::ApiError("Client error 400: bad".to_string()).is_transient());
+        assert!(!AppError::ApiError("Server error 502: text only".to_string()).is_transient());
+        assert!(!AppError::AuthError("401".to_string()).is_transient());
+        assert!(!AppError::ParseError("missing field".to_string()).is_transient());
+    }
+
     // ===== Rate Model Tests =====
 
     #[test]
@@ -155,6 +203,19 @@ mod tests {
         assert!(x_data[1].contains("00:30"));
     }
 
+    #[test]
+    fn test_rates_median_and_p90() {
+        let rates = Rates::new(create_test_rates());
+        let stats = rates
+            .stats_for_date(chrono::NaiveDate::from_ymd_opt(2025, 10, 4).unwrap())
+            .unwrap();
+
+        assert!((stats.median - 18.7).abs() < 1e-9);
+        // Interpolated 80% of the way from 18.7 to the 20.3 max
+        assert!((stats.p90 - 19.98).abs() < 1e-9);
+        assert!(stats.p90 <= stats.max);
+    }
+
     // ===== DataState Tests =====
 
     #[test]
@@ -168,7 +229,7 @@ mod tests {
         let loading = DataState::Loading;
         assert!(loading.data().is_none());
 
-        let error = DataState::Error("Test error".to_string());
+        let error = DataState::Error(Rc::new(AppError::DataError("Test error".to_string())));
         assert!(error.data().is_none());
     }
 
@@ -178,8 +239,8 @@ mod tests {
         let state2 = DataState::Loading;
         assert_eq!(state1, state2);
 
-        let state3 = DataState::Error("Test error".to_string());
-        let state4 = DataState::Error("Test error".to_string());
+        let state3 = DataState::Error(Rc::new(AppError::DataError("Test error".to_string())));
+        let state4 = DataState::Error(Rc::new(AppError::DataError("Test error".to_string())));
         assert_eq!(state3, state4);
 
         let rates1 = Rc::new(Rates::new(create_test_rates()));
@@ -189,6 +250,31 @@ mod tests {
         assert_eq!(state5, state6);
     }
 
+    #[test]
+    fn test_data_state_repoll_keeps_data_visible() {
+        let rates = Rc::new(Rates::new(create_test_rates()));
+        let refreshing = DataState::Loaded(rates.clone()).begin_refresh();
+
+        assert!(refreshing.is_refreshing());
+        assert_eq!(refreshing, DataState::Refreshing(rates.clone()));
+        assert_eq!(refreshing.data(), Some(&rates));
+
+        // Repeated re-polls stay refreshing with the same data
+        assert_eq!(refreshing.begin_refresh(), refreshing);
+    }
+
+    #[test]
+    fn test_data_state_initial_fetch_is_loading_not_refreshing() {
+        let loading = DataState::Loading.begin_refresh();
+        assert_eq!(loading, DataState::Loading);
+        assert!(!loading.is_refreshing());
+
+        let after_error = DataState::Error(Rc::new(AppError::DataError("Test error".to_string())))
+            .begin_refresh();
+        assert_eq!(after_error, DataState::Loading);
+        assert!(!DataState::Loaded(Rc::new(Rates::new(create_test_rates()))).is_refreshing());
+    }
+
     // ===== TrackerRates Tests =====
 
     fn create_tracker_test_data() -> Vec<Rate> {
@@ -236,6 +322,26 @@ mod tests {
         assert!((diff - 1.7).abs() < 0.01);
     }
 
+    #[test]
+    fn test_tracker_price_difference_negative_when_falling() {
+        let mut data = create_tracker_test_data();
+        data[1].value_inc_vat = 14.0;
+
+        let diff = TrackerRates::new(data).price_difference().unwrap();
+        assert!((diff + 1.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_tracker_price_difference_none_without_current() {
+        // Only tomorrow's price is known
+        let data = create_tracker_test_data().split_off(1);
+        let rates = TrackerRates::new(data);
+
+        assert_eq!(rates.current_price(), None);
+        assert_eq!(rates.price_difference(), None);
+        assert_eq!(TrackerRates::new(vec![]).price_difference(), None);
+    }
+
     #[test]
     fn test_tracker_missing_next_day() {
         let today = Utc::now().date_naive();